@@ -13,7 +13,7 @@ fn main() -> Result<(), Box<dyn Error>> {
     let params = BillActionsParams::default().format(FormatType::Json).limit(10);
 
     // Create the endpoint
-    let endpoint = Endpoints::BillActions(118, BillType::S, 4361, params);
+    let endpoint = Endpoints::BillActions(118.into(), BillType::S, 4361, params);
 
     // Fetch the data
     let response: BillActionsResponse = client.fetch(endpoint)?;
@@ -2,9 +2,10 @@
 //!
 //! - `list_bills`        : List recent bills introduced in Congress.
 //! - `current_congress`  : Display information about the current congress session.
-//! - `list_nominations`  : List recent nominations.
-//! - `list_treaties`     : List recent treaties.
+//! - `list_nominations`  : List recent nominations (accepts `--from` and `--to` dates).
+//! - `list_treaties`     : List recent treaties (accepts `--from` and `--to` dates).
 //! - `member_details`    : Get detailed information about a specific member (requires additional argument: `bioguide_id`).
+//! - `member_details_bulk` : Get detailed information about several members concurrently (requires additional arguments: `bioguide_id`...).
 //! - `bill_details`      : Get detailed information about a specific bill (requires additional arguments: `congress`, `bill_type`, `bill_number`).
 //! - `current_members`   : Fetch and display all current members of Congress.
 //! - `list_committees`   : List all congressional committees.
@@ -19,7 +20,9 @@
 //! # Examples:
 //! cargo run -- list_bills {amount}
 //! cargo run -- current_congress
+//! cargo run -- list_nominations --from 2024-01-01 --to 2024-03-31
 //! cargo run -- member_details {bioguide_id}
+//! cargo run -- member_details_bulk {bioguide_id} {bioguide_id} ...
 //! cargo run -- bill_details {congress} {bill_type} {bill_number}
 //! cargo run -- current_members
 //! cargo run -- list_committees
@@ -27,6 +30,7 @@
 //! cargo run -- list_amendments
 //! ```
 
+use std::borrow::Cow;
 use std::env;
 use std::error::Error;
 use std::process;
@@ -40,7 +44,7 @@ use cdg_api::param_models::{
 use cdg_api::response_models::{
     AmendmentsResponse, BillActionsResponse, BillDetailsResponse, BillsResponse,
     CommitteesResponse, CongressDetailsResponse, LawsResponse, MemberDetailsResponse,
-    MembersResponse, NominationsResponse, PrimaryResponse, TreatiesResponse,
+    MembersResponse, NominationsResponse, TreatiesResponse,
 };
 use cdg_api::CongressApiClient;
 
@@ -54,12 +58,27 @@ fn main() {
 /// Runs the main application logic.
 /// Some of these can take a considerable amount of time to fetch all data depending on the amount requested.
 fn run() -> Result<(), Box<dyn Error>> {
+    // Collect command-line arguments, separating the `--no-cache` flag
+    let mut args: Vec<String> = env::args().collect();
+    let no_cache = args.iter().any(|arg| arg == "--no-cache");
+    args.retain(|arg| arg != "--no-cache");
+
+    // Date filters for the listing commands, expanded to the API's ISO 8601 timestamps
+    let from_date_time = take_flag(&mut args, "--from")?
+        .map(|date| parse_date(&date, "00:00:00"))
+        .transpose()?;
+    let to_date_time = take_flag(&mut args, "--to")?
+        .map(|date| parse_date(&date, "23:59:59"))
+        .transpose()?;
+
     // Retrieve the API key from the environment variable or use default
     let api_key = env::var("CDG_API_KEY").ok();
-    let client = CongressApiClient::new(api_key)?;
+    let mut client = CongressApiClient::new(api_key)?;
 
-    // Collect command-line arguments
-    let args: Vec<String> = env::args().collect();
+    // Persist responses in `CDG_CACHE_DIR`, if set, unless `--no-cache` was passed
+    if let (Ok(cache_dir), false) = (env::var("CDG_CACHE_DIR"), no_cache) {
+        client = client.with_response_cache_dir(cache_dir)?;
+    }
 
     if args.len() < 2 {
         print_usage();
@@ -79,8 +98,7 @@ fn run() -> Result<(), Box<dyn Error>> {
             let bill_amount = args[2].parse::<u32>().unwrap_or(10);
             println!("Searching for {} bills...", bill_amount);
             let limit = 250;
-            let all_bills = fetch_all(
-                &client,
+            let all_bills = client.fetch_all_items::<BillsResponse, _>(
                 |offset, limit| {
                     Endpoints::BillList(
                         BillListParams::default()
@@ -89,7 +107,6 @@ fn run() -> Result<(), Box<dyn Error>> {
                             .offset(offset as u32),
                     )
                 },
-                |response: &BillsResponse| response.bills.clone(),
                 bill_amount as usize,
                 limit,
             )?;
@@ -103,21 +120,21 @@ fn run() -> Result<(), Box<dyn Error>> {
         }
         "list_nominations" => {
             let limit = 250;
-            let all_nominations: Vec<cdg_api::response_models::NominationItem> = fetch_all(
-                &client,
-                |offset, limit| {
-                    Endpoints::NominationList(
-                        NominationListParams::default()
+            let all_nominations: Vec<cdg_api::response_models::NominationItem> = client
+                .fetch_all_items::<NominationsResponse, _>(
+                    |offset, limit| {
+                        let mut params = NominationListParams::default()
                             .format(FormatType::Json)
                             .limit(limit as u32)
-                            .offset(offset as u32),
-                    )
-                },
-                |response: &NominationsResponse| response.nominations.clone(),
-                results_max,
-                limit,
-            )
-            .unwrap_or_default();
+                            .offset(offset as u32);
+                        params.from_date_time = from_date_time.clone();
+                        params.to_date_time = to_date_time.clone();
+                        Endpoints::NominationList(params)
+                    },
+                    results_max,
+                    limit,
+                )
+                .unwrap_or_default();
             display_nominations(&NominationsResponse {
                 nominations: all_nominations,
                 unknown: None,
@@ -125,17 +142,16 @@ fn run() -> Result<(), Box<dyn Error>> {
         }
         "list_treaties" => {
             let limit = 250;
-            let all_treaties = fetch_all(
-                &client,
+            let all_treaties = client.fetch_all_items::<TreatiesResponse, _>(
                 |offset, limit| {
-                    Endpoints::TreatyList(
-                        TreatyListParams::default()
-                            .format(FormatType::Json)
-                            .limit(limit as u32)
-                            .offset(offset as u32),
-                    )
+                    let mut params = TreatyListParams::default()
+                        .format(FormatType::Json)
+                        .limit(limit as u32)
+                        .offset(offset as u32);
+                    params.from_date_time = from_date_time.clone();
+                    params.to_date_time = to_date_time.clone();
+                    Endpoints::TreatyList(params)
                 },
-                |response: &TreatiesResponse| response.treaties.clone(),
                 results_max,
                 limit,
             )?;
@@ -155,12 +171,31 @@ fn run() -> Result<(), Box<dyn Error>> {
             let response: MemberDetailsResponse = client.fetch(endpoint)?;
             display_member_details(&response);
         }
+        "member_details_bulk" => {
+            if args.len() < 3 {
+                eprintln!("Usage: cargo run -- member_details_bulk <bioguide_id>...");
+                return Err("Missing bioguide_ids for member_details_bulk command.".into());
+            }
+            let endpoints = args[2..]
+                .iter()
+                .map(|bioguide_id| {
+                    Endpoints::MemberDetails(bioguide_id.clone(), MemberDetailsParams::default())
+                })
+                .collect();
+            let results = client.fetch_many::<MemberDetailsResponse>(endpoints);
+            for (bioguide_id, result) in args[2..].iter().zip(results) {
+                match result {
+                    Ok(response) => display_member_details(&response),
+                    Err(e) => eprintln!("Failed to fetch member {}: {}", bioguide_id, e),
+                }
+            }
+        }
         "bill_details" => {
             if args.len() < 5 {
                 eprintln!("Usage: cargo run -- bill_details <congress> <bill_type> <bill_number>");
                 return Err("Missing arguments for bill_details command.".into());
             }
-            let congress: u32 = args[2].parse()?;
+            let congress: Congress = args[2].parse()?;
             let bill_type = BillType::from_str(&args[3]).unwrap_or_default();
             let bill_number: u32 = args[4].parse()?;
             let params = BillDetailsParams::default();
@@ -173,7 +208,7 @@ fn run() -> Result<(), Box<dyn Error>> {
                 eprintln!("Usage: cargo run -- bill_actions <congress> <bill_type> <bill_number>");
                 return Err("Missing arguments for bill_actions command.".into());
             }
-            let congress: u32 = args[2].parse()?;
+            let congress: Congress = args[2].parse()?;
             let bill_type = BillType::from_str(&args[3]).unwrap_or_default();
             let bill_number: u32 = args[4].parse()?;
             let params = BillActionsParams::default();
@@ -183,8 +218,7 @@ fn run() -> Result<(), Box<dyn Error>> {
         }
         "current_members" => {
             let limit = 250;
-            let all_members = fetch_all(
-                &client,
+            let all_members = client.fetch_all_items::<MembersResponse, _>(
                 |offset, limit| {
                     Endpoints::MemberList(
                         MemberListParams::default()
@@ -194,7 +228,6 @@ fn run() -> Result<(), Box<dyn Error>> {
                             .current_member(true),
                     )
                 },
-                |response: &MembersResponse| response.members.clone(),
                 results_max,
                 limit,
             )?;
@@ -205,8 +238,7 @@ fn run() -> Result<(), Box<dyn Error>> {
         }
         "list_committees" => {
             let limit = 250;
-            let all_committees = fetch_all(
-                &client,
+            let all_committees = client.fetch_all_items::<CommitteesResponse, _>(
                 |offset, limit| {
                     Endpoints::CommitteeList(
                         CommitteeListParams::default()
@@ -215,19 +247,18 @@ fn run() -> Result<(), Box<dyn Error>> {
                             .offset(offset as u32),
                     )
                 },
-                |response: &CommitteesResponse| response.committees.clone(),
                 results_max,
                 limit,
             )?;
             display_committees(&CommitteesResponse {
                 committees: all_committees,
+                unknown: None,
             });
         }
         "list_laws" => {
             let limit = 250;
-            let congress = 118; // Current Congress
-            let all_laws = fetch_all(
-                &client,
+            let congress = Congress::Current; // Resolved by the client
+            let all_laws = client.fetch_all_items::<LawsResponse, _>(
                 |offset, limit| {
                     Endpoints::LawByCongress(
                         congress,
@@ -237,7 +268,6 @@ fn run() -> Result<(), Box<dyn Error>> {
                             .offset(offset as u32),
                     )
                 },
-                |response: &LawsResponse| response.bills.clone(),
                 results_max,
                 limit,
             )?;
@@ -248,8 +278,7 @@ fn run() -> Result<(), Box<dyn Error>> {
         }
         "list_amendments" => {
             let limit = 250;
-            let all_amendments = fetch_all(
-                &client,
+            let all_amendments = client.fetch_all_items::<AmendmentsResponse, _>(
                 |offset, limit| {
                     Endpoints::AmendmentList(
                         AmendmentListParams::default()
@@ -258,7 +287,6 @@ fn run() -> Result<(), Box<dyn Error>> {
                             .offset(offset as u32),
                     )
                 },
-                |response: &AmendmentsResponse| response.amendments.clone(),
                 results_max,
                 limit,
             )?;
@@ -277,67 +305,72 @@ fn run() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
-/// Fetches all items from a paginated endpoint.
-///
-/// # Arguments
-///
-/// * `client` - Reference to the CongressApiClient.
-/// * `endpoint_fn` - A closure that takes `offset` and `limit` and returns an `Endpoints` enum.
-/// * `extract_fn` - A closure that takes a reference to the response and returns a vector of items.
-/// * `max` - The maximum number of items to fetch.
-/// * `page_limit` - The number of items to fetch per request.
-///
-/// # Returns
-///
-/// A `Result` containing a vector of items or an error.
-fn fetch_all<T, U, F, G>(
-    client: &CongressApiClient,
-    endpoint_fn: F,
-    extract_fn: G,
-    max: usize,
-    page_limit: usize,
-) -> Result<Vec<U>, Box<dyn Error>>
-where
-    F: Fn(usize, usize) -> Endpoints,
-    G: Fn(&T) -> Vec<U>,
-    T: serde::de::DeserializeOwned + PrimaryResponse,
-{
-    let mut all_items = Vec::new();
-    let mut offset = 0;
+/// Removes `flag` and the value following it from `args`, returning the value.
+fn take_flag(args: &mut Vec<String>, flag: &str) -> Result<Option<String>, Box<dyn Error>> {
+    let Some(index) = args.iter().position(|arg| arg == flag) else {
+        return Ok(None);
+    };
+    if index + 1 >= args.len() {
+        return Err(format!("{} requires a date in YYYY-MM-DD form.", flag).into());
+    }
 
-    loop {
-        let endpoint = endpoint_fn(offset, page_limit);
-        let response: T = client.fetch(endpoint.clone())?;
-        let items = extract_fn(&response);
-        let fetched_count = items.len();
-        all_items.extend(items);
+    let value = args.remove(index + 1);
+    args.remove(index);
+    Ok(Some(value))
+}
 
-        if all_items.len() >= max {
-            all_items.truncate(max);
-            break;
-        }
+/// Validates a `YYYY-MM-DD` date and expands it to the `YYYY-MM-DDTHH:MM:SSZ` form the API
+/// expects for `fromDateTime` and `toDateTime`.
+fn parse_date(date: &str, time: &str) -> Result<String, Box<dyn Error>> {
+    let invalid = || {
+        format!(
+            "Invalid date '{}': expected YYYY-MM-DD, e.g. 2024-01-31.",
+            date
+        )
+    };
 
-        if fetched_count < page_limit || all_items.len() >= max {
-            break;
-        }
+    let parts: Vec<&str> = date.split('-').collect();
+    let [year, month, day] = parts.as_slice() else {
+        return Err(invalid().into());
+    };
+    if year.len() != 4 || month.len() != 2 || day.len() != 2 {
+        return Err(invalid().into());
+    }
+    let year: u32 = year.parse().map_err(|_| invalid())?;
+    let month: u32 = month.parse().map_err(|_| invalid())?;
+    let day: u32 = day.parse().map_err(|_| invalid())?;
 
-        offset += fetched_count;
+    let leap = (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400);
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if leap => 29,
+        2 => 28,
+        _ => return Err(invalid().into()),
+    };
+    if day == 0 || day > days_in_month {
+        return Err(invalid().into());
     }
 
-    Ok(all_items)
+    Ok(format!("{:04}-{:02}-{:02}T{}Z", year, month, day, time))
 }
 
 /// Prints the usage instructions.
 fn print_usage() {
-    println!("Usage: cargo run -- <command> [additional arguments]");
+    println!("Usage: cargo run -- <command> [additional arguments] [--no-cache]");
+    println!("\nSet CDG_CACHE_DIR to cache responses on disk; --no-cache bypasses the cache.");
     println!("\nAvailable commands:");
     println!("  list_bills {{amount}}           : List recent bills introduced in Congress.");
     println!("  current_congress                : Display information about the current congress session.");
-    println!("  list_nominations                : List recent nominations.");
-    println!("  list_treaties                   : List recent treaties.");
+    println!("  list_nominations [--from {{date}}] [--to {{date}}]");
+    println!("                                  : List recent nominations, optionally by date.");
+    println!("  list_treaties [--from {{date}}] [--to {{date}}]");
+    println!("                                  : List recent treaties, optionally by date.");
     println!(
         "  member_details {{bioguide_id}}  : Get detailed information about a specific member."
     );
+    println!("  member_details_bulk {{bioguide_id}}...");
+    println!("                                  : Get detailed information about several members concurrently.");
     println!("  bill_details                    : Get detailed information about a specific bill.");
     println!("    {{congress}}");
     println!("     {{bill_type}}");
@@ -381,7 +414,7 @@ fn display_members(response: &MembersResponse) {
         let depiction = member.depiction.clone().unwrap_or_default();
         println!(
             "Image URL  : {}",
-            depiction.image_url.unwrap_or_else(|| "N/A".to_string())
+            depiction.image_url().unwrap_or(Cow::Borrowed("N/A"))
         );
         println!(
             "Attribution: {}",
@@ -408,9 +441,7 @@ fn display_bills(all_bills: &[cdg_api::response_models::BillSummary]) {
         println!("Congress      : {}", bill.congress.unwrap_or(0));
         println!(
             "Origin Chamber: {}",
-            bill.origin_chamber
-                .clone()
-                .unwrap_or_else(|| "N/A".to_string())
+            bill.origin_chamber.map_or("N/A", |chamber| chamber.label())
         );
         if let Some(action) = &bill.latest_action {
             println!(
@@ -467,10 +498,14 @@ fn display_congress_details(response: &CongressDetailsResponse) {
                     .start_date
                     .clone()
                     .unwrap_or_else(|| "N/A".to_string()),
-                session
-                    .end_date
-                    .clone()
-                    .unwrap_or_else(|| "N/A".to_string())
+                if session.is_ongoing() {
+                    "Ongoing".to_string()
+                } else {
+                    session
+                        .end_date
+                        .clone()
+                        .unwrap_or_else(|| "N/A".to_string())
+                }
             );
         }
     }
@@ -513,11 +548,7 @@ fn display_nominations(response: &NominationsResponse) {
                 .unwrap_or_else(|| "N/A".to_string())
         );
         if let Some(nomination_type) = &nomination.nomination_type {
-            println!(
-                "Nomination Type : is_civilian: {}, is_military: {}",
-                nomination_type.is_civilian.unwrap_or(false),
-                nomination_type.is_military.unwrap_or(false)
-            );
+            println!("Nomination Type : {}", nomination_type.kind());
         } else {
             println!("Nomination Type : N/A");
         }
@@ -594,6 +625,10 @@ fn display_treaties(response: &TreatiesResponse) {
         } else {
             println!("Parts URLs          : N/A");
         }
+        if parts.is_partitioned() {
+            let suffixes: Vec<String> = parts.suffixes().iter().map(|s| s.to_string()).collect();
+            println!("Part Suffixes       : {}", suffixes.join(", "));
+        }
     }
     println!("----------------------------------------");
     println!("Total Treaties: {}", response.treaties.len());
@@ -636,8 +671,22 @@ fn display_laws(response: &LawsResponse) {
     println!("Recent Laws:");
     for law in &response.bills {
         println!("----------------------------------------");
+        let law_numbers: Vec<String> = law
+            .laws
+            .iter()
+            .flatten()
+            .map(|law| law.to_string())
+            .collect();
         println!(
             "Law Number    : {}",
+            if law_numbers.is_empty() {
+                "N/A".to_string()
+            } else {
+                law_numbers.join(", ")
+            }
+        );
+        println!(
+            "Bill Number   : {}",
             law.number.clone().unwrap_or_else(|| "N/A".to_string())
         );
         println!(
@@ -688,7 +737,7 @@ fn display_amendments(response: &AmendmentsResponse) {
             "Type             : {}",
             amendment
                 .amendment_type
-                .clone()
+                .map(|t| t.to_string())
                 .unwrap_or_else(|| "N/A".to_string())
         );
         println!("Congress         : {}", amendment.congress.unwrap_or(0));
@@ -806,10 +855,7 @@ fn display_member_details(response: &MemberDetailsResponse) {
     if let Some(depiction) = &member.depiction {
         println!(
             "Image URL          : {}",
-            depiction
-                .image_url
-                .clone()
-                .unwrap_or_else(|| "N/A".to_string())
+            depiction.image_url().unwrap_or(Cow::Borrowed("N/A"))
         );
         println!(
             "Attribution        : {}",
@@ -1031,13 +1077,43 @@ fn display_bill_details(response: &BillDetailsResponse) {
             .unwrap_or_else(|| "N/A".to_string())
     );
     println!(
-        "Laws Associated    : {} laws",
+        "Related Bills      : {}",
         bill.related_bills
             .clone()
             .unwrap_or_default()
             .count
             .unwrap_or(0)
     );
+    match &bill.laws {
+        Some(laws) if !laws.is_empty() => {
+            let laws: Vec<String> = laws.iter().map(|law| law.to_string()).collect();
+            println!("Became Law         : {}", laws.join(", "));
+        }
+        _ => println!("Became Law         : No"),
+    }
+    match &bill.sponsors {
+        Some(sponsors) if !sponsors.is_empty() => {
+            let names: Vec<String> = sponsors
+                .iter()
+                .map(|sponsor| {
+                    sponsor
+                        .full_name
+                        .clone()
+                        .unwrap_or_else(|| "N/A".to_string())
+                })
+                .collect();
+            println!("Sponsors           : {}", names.join("; "));
+        }
+        _ => println!("Sponsors           : N/A"),
+    }
+    println!(
+        "Cosponsors         : {}",
+        bill.cosponsors
+            .clone()
+            .unwrap_or_default()
+            .count
+            .unwrap_or(0)
+    );
     println!("Latest Action:");
     if let Some(action) = &bill.latest_action {
         println!(
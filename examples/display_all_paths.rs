@@ -1,5 +1,6 @@
 use cdg_api::requests::endpoints::Endpoints::*;
 
+#[allow(deprecated)]
 fn main() {
     println!(
         "{}\n{}\n\n=====================",
@@ -14,77 +15,77 @@ fn main() {
     println!(
         "{}\n{}\n\n=====================",
         "BillByCongress",
-        BillByCongress(117, Default::default())
+        BillByCongress(117.into(), Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
         "BillByType",
-        BillByType(117, Default::default(), Default::default())
+        BillByType(117.into(), Default::default(), Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
         "BillDetails",
-        BillDetails(117, Default::default(), 1234, Default::default())
+        BillDetails(117.into(), Default::default(), 1234, Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
         "BillActions",
-        BillActions(117, Default::default(), 1234, Default::default())
+        BillActions(117.into(), Default::default(), 1234, Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
         "BillAmendments",
-        BillAmendments(117, Default::default(), 1234, Default::default())
+        BillAmendments(117.into(), Default::default(), 1234, Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
         "BillCommittees",
-        BillCommittees(117, Default::default(), 1234, Default::default())
+        BillCommittees(117.into(), Default::default(), 1234, Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
         "BillCosponsors",
-        BillCosponsors(117, Default::default(), 1234, Default::default())
+        BillCosponsors(117.into(), Default::default(), 1234, Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
         "BillRelated",
-        BillRelated(117, Default::default(), 1234, Default::default())
+        BillRelated(117.into(), Default::default(), 1234, Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
         "BillSubjects",
-        BillSubjects(117, Default::default(), 1234, Default::default())
+        BillSubjects(117.into(), Default::default(), 1234, Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
         "BillSummaries",
-        BillSummaries(117, Default::default(), 1234, Default::default())
+        BillSummaries(117.into(), Default::default(), 1234, Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
         "BillText",
-        BillText(117, Default::default(), 1234, Default::default())
+        BillText(117.into(), Default::default(), 1234, Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
         "BillTitles",
-        BillTitles(117, Default::default(), 1234, Default::default())
+        BillTitles(117.into(), Default::default(), 1234, Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
         "LawByType",
-        LawByType(117, Default::default(), Default::default())
+        LawByType(117.into(), Default::default(), Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
         "LawByCongress",
-        LawByCongress(117, Default::default())
+        LawByCongress(117.into(), Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
         "LawDetails",
-        LawDetails(117, Default::default(), 5678, Default::default())
+        LawDetails(117.into(), Default::default(), 5678, Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
@@ -94,23 +95,23 @@ fn main() {
     println!(
         "{}\n{}\n\n=====================",
         "AmendmentByCongress",
-        AmendmentByCongress(117, Default::default())
+        AmendmentByCongress(117.into(), Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
         "AmendmentByType",
-        AmendmentByType(117, Default::default(), Default::default())
+        AmendmentByType(117.into(), Default::default(), Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
         "AmendmentDetails",
-        AmendmentDetails(117, Default::default(), 567, Default::default())
+        AmendmentDetails(117.into(), Default::default(), 567, Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
         "AmendmentActions",
         AmendmentActions(
-            117,
+            117.into(),
             Default::default(),
             String::from("567"),
             Default::default()
@@ -120,7 +121,7 @@ fn main() {
         "{}\n{}\n\n=====================",
         "AmendmentCosponsors",
         AmendmentCosponsors(
-            117,
+            117.into(),
             Default::default(),
             String::from("567"),
             Default::default()
@@ -130,7 +131,7 @@ fn main() {
         "{}\n{}\n\n=====================",
         "AmendmentAmendments",
         AmendmentAmendments(
-            117,
+            117.into(),
             Default::default(),
             String::from("567"),
             Default::default()
@@ -140,7 +141,7 @@ fn main() {
         "{}\n{}\n\n=====================",
         "AmendmentText",
         AmendmentText(
-            117,
+            117.into(),
             Default::default(),
             String::from("567"),
             Default::default()
@@ -154,12 +155,12 @@ fn main() {
     println!(
         "{}\n{}\n\n=====================",
         "SummariesByCongress",
-        SummariesByCongress(117, Default::default())
+        SummariesByCongress(117.into(), Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
         "SummariesByType",
-        SummariesByType(117, Default::default(), Default::default())
+        SummariesByType(117.into(), Default::default(), Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
@@ -169,7 +170,7 @@ fn main() {
     println!(
         "{}\n{}\n\n=====================",
         "CongressDetails",
-        CongressDetails(117, Default::default())
+        CongressDetails(117.into(), Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
@@ -184,7 +185,7 @@ fn main() {
     println!(
         "{}\n{}\n\n=====================",
         "MemberByCongress",
-        MemberByCongress(117, Default::default())
+        MemberByCongress(117.into(), Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
@@ -199,7 +200,7 @@ fn main() {
     println!(
         "{}\n{}\n\n=====================",
         "MemberByCongressStateDistrict",
-        MemberByCongressStateDistrict(117, String::from("NY"), 10, Default::default())
+        MemberByCongressStateDistrict(117.into(), String::from("NY"), 10, Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
@@ -229,12 +230,12 @@ fn main() {
     println!(
         "{}\n{}\n\n=====================",
         "CommitteeByCongress",
-        CommitteeByCongress(117, Default::default())
+        CommitteeByCongress(117.into(), Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
         "CommitteeByCongressChamber",
-        CommitteeByCongressChamber(117, Default::default(), Default::default())
+        CommitteeByCongressChamber(117.into(), Default::default(), Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
@@ -274,22 +275,22 @@ fn main() {
     println!(
         "{}\n{}\n\n=====================",
         "CommitteeReportByCongress",
-        CommitteeReportByCongress(117, Default::default())
+        CommitteeReportByCongress(117.into(), Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
         "CommitteeReportByType",
-        CommitteeReportByType(117, Default::default(), Default::default())
+        CommitteeReportByType(117.into(), Default::default(), Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
         "CommitteeReportDetails",
-        CommitteeReportDetails(117, Default::default(), 42, Default::default())
+        CommitteeReportDetails(117.into(), Default::default(), 42, Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
         "CommitteeReportText",
-        CommitteeReportText(117, Default::default(), 42, Default::default())
+        CommitteeReportText(117.into(), Default::default(), 42, Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
@@ -299,22 +300,27 @@ fn main() {
     println!(
         "{}\n{}\n\n=====================",
         "CommitteePrintByCongress",
-        CommitteePrintByCongress(117, Default::default())
+        CommitteePrintByCongress(117.into(), Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
         "CommitteePrintByCongressChamber",
-        CommitteePrintByCongressChamber(117, Default::default(), Default::default())
+        CommitteePrintByCongressChamber(117.into(), Default::default(), Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
         "CommitteePrintByJacketNumber",
-        CommitteePrintByJacketNumber(117, 789, Default::default())
+        CommitteePrintByJacketNumber(117.into(), 789, Default::default())
+    );
+    println!(
+        "{}\n{}\n\n=====================",
+        "CommitteePrintDetails",
+        CommitteePrintDetails(117.into(), Default::default(), 789, Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
         "CommitteePrintText",
-        CommitteePrintText(117, String::from("H01"), 789, Default::default())
+        CommitteePrintText(117.into(), String::from("H01"), 789, Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
@@ -324,18 +330,18 @@ fn main() {
     println!(
         "{}\n{}\n\n=====================",
         "CommitteeMeetingByCongress",
-        CommitteeMeetingByCongress(117, Default::default())
+        CommitteeMeetingByCongress(117.into(), Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
         "CommitteeMeetingByChamber",
-        CommitteeMeetingByChamber(117, Default::default(), Default::default())
+        CommitteeMeetingByChamber(117.into(), Default::default(), Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
         "CommitteeMeetingByEvent",
         CommitteeMeetingByEvent(
-            117,
+            117.into(),
             Default::default(),
             String::from("EVT123"),
             Default::default()
@@ -349,17 +355,17 @@ fn main() {
     println!(
         "{}\n{}\n\n=====================",
         "HearingByCongress",
-        HearingByCongress(117, Default::default())
+        HearingByCongress(117.into(), Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
         "HearingByChamber",
-        HearingByChamber(117, Default::default(), Default::default())
+        HearingByChamber(117.into(), Default::default(), Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
         "HearingByJacketNumber",
-        HearingByJacketNumber(117, Default::default(), 100, Default::default())
+        HearingByJacketNumber(117.into(), Default::default(), 100, Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
@@ -414,17 +420,17 @@ fn main() {
     println!(
         "{}\n{}\n\n=====================",
         "HouseCommunicationByCongress",
-        HouseCommunicationByCongress(117, Default::default())
+        HouseCommunicationByCongress(117.into(), Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
         "HouseCommunicationByType",
-        HouseCommunicationByType(117, Default::default(), Default::default())
+        HouseCommunicationByType(117.into(), Default::default(), Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
         "HouseCommunicationDetails",
-        HouseCommunicationDetails(117, Default::default(), 345, Default::default())
+        HouseCommunicationDetails(117.into(), Default::default(), 345, Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
@@ -449,17 +455,17 @@ fn main() {
     println!(
         "{}\n{}\n\n=====================",
         "SenateCommunicationByCongress",
-        SenateCommunicationByCongress(117, Default::default())
+        SenateCommunicationByCongress(117.into(), Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
         "SenateCommunicationByType",
-        SenateCommunicationByType(117, Default::default(), Default::default())
+        SenateCommunicationByType(117.into(), Default::default(), Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
         "SenateCommunicationDetails",
-        SenateCommunicationDetails(117, Default::default(), 345, Default::default())
+        SenateCommunicationDetails(117.into(), Default::default(), 345, Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
@@ -469,32 +475,32 @@ fn main() {
     println!(
         "{}\n{}\n\n=====================",
         "NominationByCongress",
-        NominationByCongress(117, Default::default())
+        NominationByCongress(117.into(), Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
         "NominationDetails",
-        NominationDetails(117, String::from("NOM123"), Default::default())
+        NominationDetails(117.into(), String::from("NOM123"), Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
         "Nominees",
-        Nominees(117, String::from("NOM123"), 1, Default::default())
+        Nominees(117.into(), String::from("NOM123"), 1, Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
         "NominationActions",
-        NominationActions(117, String::from("NOM123"), Default::default())
+        NominationActions(117.into(), String::from("NOM123"), Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
         "NominationCommittees",
-        NominationCommittees(117, String::from("NOM123"), Default::default())
+        NominationCommittees(117.into(), String::from("NOM123"), Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
         "NominationHearings",
-        NominationHearings(117, String::from("NOM123"), Default::default())
+        NominationHearings(117.into(), String::from("NOM123"), Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
@@ -504,26 +510,31 @@ fn main() {
     println!(
         "{}\n{}\n\n=====================",
         "TreatyByCongress",
-        TreatyByCongress(117, Default::default())
+        TreatyByCongress(117.into(), Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
         "TreatyDetails",
-        TreatyDetails(117, 345, Default::default())
+        TreatyDetails(117.into(), 345, Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
         "TreatyPartitioned",
-        TreatyPartitioned(117, 345, String::from("suffix"), Default::default())
+        TreatyPartitioned(
+            117.into(),
+            345,
+            "A".parse().expect("Invalid treaty suffix"),
+            Default::default()
+        )
     );
     println!(
         "{}\n{}\n\n=====================",
         "TreatyCommittees",
-        TreatyCommittees(117, 345, Default::default())
+        TreatyCommittees(117.into(), 345, Default::default())
     );
     println!(
         "{}\n{}\n\n=====================",
         "TreatyActions",
-        TreatyActions(117, 345, Default::default())
+        TreatyActions(117.into(), 345, Default::default())
     );
 }
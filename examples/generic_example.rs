@@ -4,7 +4,7 @@ use cdg_api::param_models::BillListParams;
 use cdg_api::response_models::{
     parse_response, serialize_response, BillsResponse, GenericResponse,
 };
-use cdg_api::{unwrap_option_string, CongressApiClient};
+use cdg_api::{unwrap_option, CongressApiClient};
 
 use std::error::Error;
 
@@ -32,15 +32,21 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     // Print the bill list
     for bill in bill_list.bills {
-        println!("Bill: {}", unwrap_option_string(bill.bill_type));
-        println!("Title: {}", unwrap_option_string(bill.title));
-        println!("Number: {}", unwrap_option_string(bill.number));
+        println!(
+            "Bill: {}",
+            unwrap_option(bill.bill_type.map(|t| t.to_string()))
+        );
+        println!("Title: {}", unwrap_option(bill.title));
+        println!("Number: {}", unwrap_option(bill.number));
         println!(
             "Origin Chamber: {}",
-            unwrap_option_string(bill.origin_chamber)
+            unwrap_option(
+                bill.origin_chamber
+                    .map(|chamber| chamber.label().to_string())
+            )
         );
-        println!("Update Date: {}", unwrap_option_string(bill.update_date));
-        println!("URL: {}", unwrap_option_string(bill.url));
+        println!("Update Date: {}", unwrap_option(bill.update_date));
+        println!("URL: {}", unwrap_option(bill.url));
         println!();
     }
 
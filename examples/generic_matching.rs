@@ -2,7 +2,7 @@ use cdg_api::cdg_types::{BillType, FormatType};
 use cdg_api::endpoints::{Endpoints, NewEndpoint};
 use cdg_api::param_models::BillDetailsParams;
 use cdg_api::response_models::{parse_response, BillDetailsResponse, GenericResponse};
-use cdg_api::{unwrap_option, unwrap_option_string, CongressApiClient};
+use cdg_api::{unwrap_option, CongressApiClient};
 
 use std::error::Error;
 
@@ -22,8 +22,11 @@ fn main() -> Result<(), Box<dyn Error>> {
     let bill_details: BillDetailsResponse = parse_response(&response)?;
     let bill = bill_details.bill;
 
-    println!("Bill: {}", unwrap_option_string(bill.bill_type));
-    println!("Title: {}", unwrap_option_string(bill.title));
+    println!(
+        "Bill: {}",
+        unwrap_option(bill.bill_type.map(|t| t.to_string()))
+    );
+    println!("Title: {}", unwrap_option(bill.title));
     println!("Summary: {:#?}", unwrap_option(bill.summaries));
 
     Ok(())
@@ -4,7 +4,7 @@ use cdg_api::param_models::GenericParams;
 use cdg_api::response_models::{
     parse_response, serialize_response, DailyCongressionalRecordResponse, GenericResponse,
 };
-use cdg_api::{unwrap_option, unwrap_option_string, unwrap_option_u32, CongressApiClient};
+use cdg_api::{unwrap_option, CongressApiClient};
 
 use std::error::Error;
 
@@ -25,13 +25,13 @@ fn main() -> Result<(), Box<dyn Error>> {
         Ok(json) => {
             json.daily_congressional_record.iter().for_each(|records| {
                 let record = records.clone();
-                println!("Date: {}", unwrap_option_string(record.issue_date));
-                println!("Update Date: {}", unwrap_option_string(record.update_date));
-                println!("Volume: {}", unwrap_option_u32(record.volume_number));
-                println!("Issue: {}", unwrap_option_string(record.issue_number));
-                println!("Sess. #: {}", unwrap_option_u32(record.session_number));
-                println!("Congress: {}", unwrap_option_u32(record.congress));
-                println!("URL: {}", unwrap_option_string(record.url));
+                println!("Date: {}", unwrap_option(record.issue_date));
+                println!("Update Date: {}", unwrap_option(record.update_date));
+                println!("Volume: {}", unwrap_option(record.volume_number));
+                println!("Issue: {}", unwrap_option(record.issue_number));
+                println!("Sess. #: {}", unwrap_option(record.session_number));
+                println!("Congress: {}", unwrap_option(record.congress));
+                println!("URL: {}", unwrap_option(record.url));
                 println!();
                 println!("Full Issue: {:#?}", unwrap_option(record.full_issue));
             });
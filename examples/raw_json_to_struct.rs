@@ -1,7 +1,7 @@
 use cdg_api::response_models::{
     parse_response, serialize_response, BillsResponse, GenericResponse,
 };
-use cdg_api::unwrap_option_string;
+use cdg_api::unwrap_option;
 
 const RAW_BILL_DATA: &str = r#"{
     "bills": [
@@ -44,10 +44,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     for bill in bills.bills[..].iter() {
         println!(
             "{}, {}, {} -- {}\n",
-            unwrap_option_string(bill.title.clone()),
-            unwrap_option_string(bill.bill_type.clone()),
-            unwrap_option_string(bill.number.clone()),
-            unwrap_option_string(bill.url.clone())
+            unwrap_option(bill.title.clone()),
+            unwrap_option(bill.bill_type.map(|t| t.to_string())),
+            unwrap_option(bill.number.clone()),
+            unwrap_option(bill.url.clone())
         );
     }
 
@@ -69,10 +69,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     for bill in bills.bills[..].iter() {
         println!(
             "{}, {}, {} -- {}\n",
-            unwrap_option_string(bill.title.clone()),
-            unwrap_option_string(bill.bill_type.clone()),
-            unwrap_option_string(bill.number.clone()),
-            unwrap_option_string(bill.url.clone())
+            unwrap_option(bill.title.clone()),
+            unwrap_option(bill.bill_type.map(|t| t.to_string())),
+            unwrap_option(bill.number.clone()),
+            unwrap_option(bill.url.clone())
         );
     }
 
@@ -0,0 +1,163 @@
+//! # `cache` Module
+//!
+//! This module provides [`DiskCache`], the persistent response cache behind
+//! [`CongressApiClient::with_response_cache_dir`]. Each successful response body is stored as
+//! a JSON file named by a hash of the request URL (without the API key), so identical requests
+//! are answered from disk across restarts and can be replayed offline.
+//!
+//! Entries are written to a temporary file and renamed into place, so a reader never sees a
+//! half-written body, and a process killed mid-write leaves no truncated entry behind.
+//!
+//! Concurrent misses for the same key are coalesced: the first request fetches the body
+//! while the others wait for it to land in the cache, so a popular endpoint costs one
+//! request however many threads ask for it at once.
+//!
+//! [`fixture_file_name`] names the raw responses saved by
+//! [`CongressApiClient::record_to_dir`] for use as test fixtures.
+//!
+//! [`CongressApiClient::with_response_cache_dir`]: crate::CongressApiClient::with_response_cache_dir
+//! [`CongressApiClient::record_to_dir`]: crate::CongressApiClient::record_to_dir
+
+use std::collections::HashSet;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Condvar, Mutex, MutexGuard};
+
+/// A directory of cached response bodies keyed by request URL.
+#[derive(Debug, Clone)]
+pub struct DiskCache {
+    dir: PathBuf,
+}
+
+impl DiskCache {
+    /// Creates a cache stored in `dir`, creating the directory if it does not exist.
+    pub fn new(dir: impl Into<PathBuf>) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Self { dir })
+    }
+
+    /// Returns the directory the cache is stored in.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Returns the cached body for `key`, if present.
+    pub fn get(&self, key: &str) -> Option<String> {
+        fs::read_to_string(self.path_for(key)).ok()
+    }
+
+    /// Stores `body` under `key`, replacing any previous entry.
+    ///
+    /// The body is written to a temporary file in the cache directory and renamed over the
+    /// entry, so concurrent readers see either the previous entry or the complete new one.
+    pub fn put(&self, key: &str, body: &str) -> io::Result<()> {
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+        let path = self.path_for(key);
+        let tmp = path.with_extension(format!(
+            "{}.{}.tmp",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::write(&tmp, body)
+            .and_then(|()| fs::rename(&tmp, &path))
+            .inspect_err(|_| {
+                let _ = fs::remove_file(&tmp);
+            })
+    }
+
+    /// Removes the entry for `key`, e.g. when its body turns out to be unusable.
+    pub fn remove(&self, key: &str) -> io::Result<()> {
+        match fs::remove_file(self.path_for(key)) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the file path used for `key`.
+    pub fn path_for(&self, key: &str) -> PathBuf {
+        self.dir
+            .join(format!("{:016x}.json", fnv1a(key.as_bytes())))
+    }
+}
+
+/// Returns a readable file name for the response to `key`, as written by
+/// [`CongressApiClient::record_to_dir`](crate::CongressApiClient::record_to_dir).
+///
+/// The base URL is dropped and every character other than an ASCII letter, digit, `-`, or
+/// `.` becomes `_`, e.g. `bill_118_hr_3076_format_json.json` for
+/// `https://api.congress.gov/v3/bill/118/hr/3076?format=json`.
+pub fn fixture_file_name(key: &str) -> String {
+    let path = key.strip_prefix(crate::BASE_URL).unwrap_or(key);
+    let name: String = path
+        .chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '.' => c,
+            _ => '_',
+        })
+        .collect();
+    format!("{}.json", name.trim_matches('_'))
+}
+
+/// The cache keys currently being fetched, shared by clones of a client.
+#[derive(Debug, Default)]
+pub(crate) struct InFlight {
+    keys: Mutex<HashSet<String>>,
+    finished: Condvar,
+}
+
+impl InFlight {
+    /// Claims `key` for the calling thread. If another thread holds it, blocks until that
+    /// thread's request finishes and returns [`None`]; the caller should then check the
+    /// cache again.
+    pub(crate) fn claim(&self, key: &str) -> Option<Claim<'_>> {
+        let mut keys = self.keys();
+        if keys.insert(key.to_string()) {
+            return Some(Claim {
+                in_flight: self,
+                key: key.to_string(),
+            });
+        }
+
+        while keys.contains(key) {
+            keys = self
+                .finished
+                .wait(keys)
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+        }
+        None
+    }
+
+    fn keys(&self) -> MutexGuard<'_, HashSet<String>> {
+        self.keys
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// A claimed key, released (waking any waiting threads) when dropped.
+pub(crate) struct Claim<'a> {
+    in_flight: &'a InFlight,
+    key: String,
+}
+
+impl Drop for Claim<'_> {
+    fn drop(&mut self) {
+        self.in_flight.keys().remove(&self.key);
+        self.in_flight.finished.notify_all();
+    }
+}
+
+/// 64-bit FNV-1a hash. Unlike [`std::collections::hash_map::DefaultHasher`], its output is
+/// stable across Rust releases, so file names remain valid between builds.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    bytes.iter().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(PRIME)
+    })
+}
@@ -35,17 +35,137 @@
 //! }
 //! ```
 
-use crate::{endpoints::Endpoints, response_models::PrimaryResponse, url_builders::generate_url};
-use reqwest::blocking::Client;
-use serde::de::DeserializeOwned;
+use crate::{
+    cache::{fixture_file_name, DiskCache, InFlight},
+    cdg_types::{BillType, Congress, FormatType, InvalidTreatySuffix, SortType},
+    endpoints::{Endpoints, NewEndpoint},
+    pagination::{paginate, PageStats},
+    param_models::BillByTypeParams,
+    param_models::CongressCurrentParams,
+    rate_limit::{RateLimiter, ResourceBudget},
+    response_models::{
+        BillSummary, BillsResponse, CongressDetailsResponse, Paginated, Pagination,
+        PrimaryResponse, Updated,
+    },
+    retry::{RetryPolicy, Sleeper, ThreadSleeper},
+    url_builders::{generate_url, query_param, set_default_query_param, set_query_param},
+};
+use reqwest::blocking::{Client, RequestBuilder, Response};
+use reqwest::header::{ACCEPT_LANGUAGE, CONTENT_ENCODING, DATE};
+use reqwest::Method;
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::Value;
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
 use std::env;
 use std::error::Error;
 use std::fmt;
+use std::hash::BuildHasher;
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Maximum number of requests sent concurrently by the multi-request helpers.
+const MAX_CONCURRENT_REQUESTS: usize = 4;
+
+/// The largest `limit` the API accepts for a page of a list endpoint.
+const MAX_PAGE_LIMIT: u32 = 250;
+
+/// Top-level keys the API adds around every list response, outside any model's own fields.
+const ENVELOPE_KEYS: [&str; 2] = ["pagination", "request"];
+
+/// A hook applied to every outgoing request before it is sent.
+pub type RequestHook = Arc<dyn Fn(RequestBuilder) -> RequestBuilder + Send + Sync>;
+
+/// A hook called with the progress of a multi-page fetch after every page.
+pub type PageHook = Arc<dyn Fn(PageStats) + Send + Sync>;
+
+/// Generates the request IDs attached by [`CongressApiClient::with_request_id_header`].
+pub type RequestIdGenerator = Arc<dyn Fn() -> String + Send + Sync>;
+
+/// Where the client sends the API key.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum ApiKeyLocation {
+    /// As the `api_key` query parameter, as congress.gov expects.
+    #[default]
+    Query,
+
+    /// In the named header (e.g. `X-Api-Key`), for gateways that reject keys in the URL.
+    Header(String),
+}
+
+/// Transfer details of the response behind a [`CongressApiClient::fetch_with_meta`] call.
+///
+/// The HTTP fields are `None` when the body was served from the response cache.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ResponseMeta {
+    /// The request URL, without the API key.
+    pub url: String,
+
+    /// Whether the body was served from the response cache.
+    pub from_cache: bool,
+
+    /// The HTTP status code.
+    pub status: Option<u16>,
+
+    /// The `Content-Encoding` of the response (e.g. `gzip`), if the body was compressed.
+    ///
+    /// The client asks for gzip-compressed responses when the `gzip` feature is enabled.
+    pub content_encoding: Option<String>,
+
+    /// The on-wire body size in bytes, before decompression.
+    pub wire_bytes: Option<u64>,
+
+    /// The decompressed body size in bytes.
+    pub body_bytes: usize,
+
+    /// The server's `Date` header, e.g. `Tue, 15 Oct 2024 12:00:00 GMT`.
+    pub date: Option<String>,
+
+    /// The local time at which the response headers were received. Comparing it with
+    /// `date` gives the clock skew to the server.
+    pub received_at: Option<SystemTime>,
+
+    /// The request ID sent with the request, if
+    /// [`CongressApiClient::with_request_id_header`] is set.
+    pub request_id: Option<String>,
+}
 
 /// A client for interacting with the US Congress API.
+///
+/// The client is `Send + Sync`, so a single instance can be shared across threads, e.g.
+/// behind an [`Arc`] in a web server. Cloning is cheap: clones share the underlying
+/// connection pool, retry policy, sleeper, rate limiter, and hooks.
+#[derive(Clone)]
 pub struct CongressApiClient {
-    api_key: String,
+    // Shared by clones of the client and only ever borrowed as `&str` when building a
+    // request: `generate_url` copies it into the URL (or `send_once` into the header) and no
+    // request allocates a copy of its own.
+    api_key: Arc<str>,
+    api_key_location: ApiKeyLocation,
     client: Client,
+    retry_policy: Option<Arc<dyn RetryPolicy>>,
+    sleeper: Arc<dyn Sleeper>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    resource_budgets: HashMap<String, Arc<ResourceBudget>>,
+    request_hook: Option<RequestHook>,
+    page_hook: Option<PageHook>,
+    request_id_header: Option<String>,
+    request_id_generator: RequestIdGenerator,
+    accept_language: Option<String>,
+    base_url: Option<String>,
+    congress_range: Option<RangeInclusive<u32>>,
+    default_limit: Option<u32>,
+    detail_format: Option<FormatType>,
+    text_format: Option<FormatType>,
+    current_congress: OnceLock<u32>,
+    response_cache: Option<DiskCache>,
+    in_flight: Arc<InFlight>,
+    requests_sent: Arc<AtomicU64>,
+    record_dir: Option<PathBuf>,
 }
 
 impl CongressApiClient {
@@ -63,80 +183,2035 @@ impl CongressApiClient {
         let api_key = match api_key {
             Some(key) => key,
             None => env::var("CDG_API_KEY")
-                .map_err(|_| "CDG_API_KEY environment variable not set. Alternatively, provide an API key as an argument.")?,
+                .map_err(|_| ApiClientError::EnvVar("CDG_API_KEY".to_string()))?,
         };
 
         Ok(Self {
-            api_key,
+            api_key: api_key.into(),
+            api_key_location: ApiKeyLocation::default(),
             client: Client::new(),
+            retry_policy: None,
+            sleeper: Arc::new(ThreadSleeper),
+            rate_limiter: None,
+            resource_budgets: HashMap::new(),
+            request_hook: None,
+            page_hook: None,
+            request_id_header: None,
+            request_id_generator: Arc::new(random_uuid),
+            accept_language: None,
+            base_url: None,
+            congress_range: None,
+            default_limit: None,
+            detail_format: None,
+            text_format: None,
+            current_congress: OnceLock::new(),
+            response_cache: None,
+            in_flight: Arc::default(),
+            requests_sent: Arc::default(),
+            record_dir: None,
         })
     }
 
+    /// Sets the [`RetryPolicy`] consulted whenever a request fails.
+    ///
+    /// By default, failed requests are not retried.
+    ///
+    /// # Parameters
+    ///
+    /// - [`policy`]: The policy deciding whether, and after how long, to retry.
+    pub fn retry_policy<P: RetryPolicy + 'static>(mut self, policy: P) -> Self {
+        self.retry_policy = Some(Arc::new(policy));
+        self
+    }
+
+    /// Sets where the API key is sent.
+    ///
+    /// Defaults to [`ApiKeyLocation::Query`]. Use [`ApiKeyLocation::Header`] behind proxies or
+    /// gateways that expect the key in a header instead of the URL.
+    ///
+    /// # Parameters
+    ///
+    /// - [`location`]: The query parameter or header carrying the key.
+    pub fn api_key_location(mut self, location: ApiKeyLocation) -> Self {
+        self.api_key_location = location;
+        self
+    }
+
+    /// Sets the [`Sleeper`] used to wait between retry attempts and for the [`RateLimiter`].
+    ///
+    /// Defaults to [`ThreadSleeper`]. Injecting a no-op or recording sleeper keeps retry tests
+    /// fast and deterministic.
+    ///
+    /// # Parameters
+    ///
+    /// - [`sleeper`]: The sleeper applying the delays returned by the [`RetryPolicy`] and
+    ///   the [`RateLimiter`].
+    pub fn sleeper<S: Sleeper + 'static>(mut self, sleeper: S) -> Self {
+        self.sleeper = Arc::new(sleeper);
+        self
+    }
+
+    /// Restricts the congress numbers the client requests to `min..=max`, e.g. for an app
+    /// scraping only recent congresses.
+    ///
+    /// An endpoint for a congress outside the range fails with
+    /// `ApiClientError::InvalidParam` before anything is sent, instead of with a 404 from
+    /// the API. [`Congress::Current`] is checked after substituting the current congress.
+    /// By default, every congress number is accepted.
+    ///
+    /// # Parameters
+    ///
+    /// - [`min`]: The first accepted congress, e.g. `110`.
+    /// - [`max`]: The last accepted congress.
+    pub fn with_congress_range(mut self, min: u32, max: u32) -> Self {
+        self.congress_range = Some(min..=max);
+        self
+    }
+
+    /// Sets the `limit` sent to list endpoints whose params set none.
+    ///
+    /// Without a `limit`, the API returns 20 items per page, so crawling through
+    /// [`CongressApiClient::fetch`] takes up to 12 times more requests than at the API's
+    /// maximum of 250. Detail endpoints (see [`Endpoints::is_list`]) are not affected, and a
+    /// `limit` set in the params always wins.
+    ///
+    /// # Parameters
+    ///
+    /// - [`limit`]: The page size, clamped to `1..=250`.
+    pub fn default_limit(mut self, limit: u32) -> Self {
+        self.default_limit = Some(limit.clamp(1, MAX_PAGE_LIMIT));
+        self
+    }
+
+    /// Sets the format requested from detail endpoints (see [`Endpoints::is_list`]) whose
+    /// params set none.
+    ///
+    /// The format is chosen with this precedence: the format passed to a call such as
+    /// [`CongressApiClient::fetch_text_as`], then the format set in the params, then the
+    /// client's default for the endpoint's category, then JSON.
+    ///
+    /// The response models deserialize from JSON, so with an XML default
+    /// [`CongressApiClient::fetch`] fails on these endpoints; read their bodies with
+    /// [`CongressApiClient::fetch_text`] instead.
+    ///
+    /// # Parameters
+    ///
+    /// - [`format`]: The format for detail endpoints.
+    pub fn detail_format(mut self, format: FormatType) -> Self {
+        self.detail_format = Some(format);
+        self
+    }
+
+    /// Sets the format requested from text endpoints (see [`Endpoints::is_text`]) whose
+    /// params set none, e.g. to keep the XML structure of bill and report text listings.
+    ///
+    /// Takes precedence over [`CongressApiClient::detail_format`]; see it for how the
+    /// format is chosen, and read XML bodies with [`CongressApiClient::fetch_text`].
+    ///
+    /// # Parameters
+    ///
+    /// - [`format`]: The format for text endpoints.
+    pub fn text_format(mut self, format: FormatType) -> Self {
+        self.text_format = Some(format);
+        self
+    }
+
+    /// Paces requests with a [`RateLimiter`], which may be shared with other clients.
+    ///
+    /// Every request attempt, including retries, takes a token; responses served from the
+    /// response cache do not. By default, requests are not paced.
+    ///
+    /// # Parameters
+    ///
+    /// - [`limiter`]: The limiter, shared by every client using the same API key.
+    pub fn with_rate_limiter(mut self, limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = Some(limiter);
+        self
+    }
+
+    /// Caps the requests sent for one resource at `max_per_hour` within any hour, e.g. to
+    /// keep 20% of the quota for member lookups while a bill crawl runs on the same key.
+    ///
+    /// Requests are counted by [`Endpoints::resource_name`]; resources without a budget are
+    /// not limited. Once a budget is spent, requests for its resource fail with
+    /// `ApiClientError::BudgetExceeded` instead of being sent, until older requests leave the
+    /// one-hour window. Every attempt counts, including retries; responses served from the
+    /// response cache do not. Clones of the client share its budgets. Setting a budget for a
+    /// resource again replaces it and resets its count.
+    ///
+    /// # Parameters
+    ///
+    /// - [`resource`]: The resource name, e.g. `member` or `bill`.
+    /// - [`max_per_hour`]: The number of requests allowed per hour.
+    pub fn with_resource_budget(mut self, resource: impl Into<String>, max_per_hour: u32) -> Self {
+        self.resource_budgets.insert(
+            resource.into(),
+            Arc::new(ResourceBudget::per_hour(max_per_hour)),
+        );
+        self
+    }
+
+    /// Sets a hook that may modify every outgoing request before it is sent, e.g. to add
+    /// headers or tracing IDs.
+    ///
+    /// The hook runs once per attempt, so retried requests pass through it again.
+    ///
+    /// # Parameters
+    ///
+    /// - [`hook`]: Receives the prepared [`RequestBuilder`] and returns the one to send.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cdg_api::CongressApiClient;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = CongressApiClient::new(Some("YOUR_API_KEY".to_string()))?
+    ///         .on_build_request(|request| request.header("X-Request-Id", "1234"));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn on_build_request<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(RequestBuilder) -> RequestBuilder + Send + Sync + 'static,
+    {
+        self.request_hook = Some(Arc::new(hook));
+        self
+    }
+
+    /// Sets a hook called after every page fetched by [`CongressApiClient::fetch_all`] and
+    /// [`CongressApiClient::fetch_all_items`], e.g. to drive a progress bar.
+    ///
+    /// # Parameters
+    ///
+    /// - [`hook`]: Receives the [`PageStats`] of the page just fetched.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cdg_api::CongressApiClient;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = CongressApiClient::new(Some("YOUR_API_KEY".to_string()))?
+    ///         .on_page(|stats| eprintln!("{} items in {:?}", stats.total_so_far, stats.elapsed));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn on_page<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(PageStats) + Send + Sync + 'static,
+    {
+        self.page_hook = Some(Arc::new(hook));
+        self
+    }
+
+    /// Sends a request ID in the named header (e.g. `X-Request-Id`) with every request, to
+    /// correlate the client's requests with server or proxy logs.
+    ///
+    /// IDs are random UUIDs (version 4) unless replaced with
+    /// [`CongressApiClient::with_request_id_generator`]. Retries of a request reuse its ID,
+    /// and [`CongressApiClient::fetch_with_meta`] returns it in [`ResponseMeta::request_id`].
+    ///
+    /// # Parameters
+    ///
+    /// - [`name`]: The header carrying the ID.
+    pub fn with_request_id_header(mut self, name: impl Into<String>) -> Self {
+        self.request_id_header = Some(name.into());
+        self
+    }
+
+    /// Sets the function generating the IDs sent by
+    /// [`CongressApiClient::with_request_id_header`], e.g. to reuse IDs from a tracing
+    /// context.
+    ///
+    /// # Parameters
+    ///
+    /// - [`generator`]: Called once per request, not per retry attempt.
+    pub fn with_request_id_generator<F>(mut self, generator: F) -> Self
+    where
+        F: Fn() -> String + Send + Sync + 'static,
+    {
+        self.request_id_generator = Arc::new(generator);
+        self
+    }
+
+    /// Sends an `Accept-Language` header with every request, e.g. `en-US`.
+    ///
+    /// congress.gov serves English only today, so this changes nothing yet; it states the
+    /// preferred language in case localized content is ever offered. By default, no
+    /// `Accept-Language` header is sent.
+    ///
+    /// # Parameters
+    ///
+    /// - [`tag`]: The header value, a language tag or a weighted list such as
+    ///   `es, en;q=0.8`.
+    pub fn with_accept_language(mut self, tag: impl Into<String>) -> Self {
+        self.accept_language = Some(tag.into());
+        self
+    }
+
+    /// Sends requests to `base_url` instead of [`BASE_URL`](crate::BASE_URL).
+    ///
+    /// A test hook for pointing the client at a local stub server, not part of the public
+    /// API. Only the outgoing request changes: cache keys, recorded fixtures, planned URLs and
+    /// resource budgets still use the congress.gov URL.
+    ///
+    /// # Parameters
+    ///
+    /// - [`base_url`]: Replaces `https://api.congress.gov/v3/`, e.g.
+    ///   `http://127.0.0.1:8080/v3/`. A missing trailing `/` is added.
+    #[doc(hidden)]
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        let mut base_url = base_url.into();
+        if !base_url.ends_with('/') {
+            base_url.push('/');
+        }
+        self.base_url = Some(base_url);
+        self
+    }
+
+    /// Persists every successful response body in `dir` and answers identical requests from
+    /// there, across restarts and without network access.
+    ///
+    /// Files are named by a hash of the request URL without the API key. Entries never expire;
+    /// delete the directory (or don't call this method) to bypass the cache. Empty bodies are
+    /// not cached, and an entry that is empty or not valid JSON is evicted and fetched again.
+    ///
+    /// Concurrent misses for the same URL, from this client or its clones, send a single
+    /// request; the other callers wait and read its body from the cache.
+    ///
+    /// # Parameters
+    ///
+    /// - [`dir`]: The cache directory, created if it does not exist.
+    ///
+    /// # Returns
+    ///
+    /// - [`Err`]: If the directory cannot be created.
+    pub fn with_response_cache_dir(
+        mut self,
+        dir: impl Into<PathBuf>,
+    ) -> Result<Self, std::io::Error> {
+        self.response_cache = Some(DiskCache::new(dir)?);
+        Ok(self)
+    }
+
+    /// Saves every response body the client returns to `dir`, under a readable name derived
+    /// from the request URL (see [`fixture_file_name`]), to capture real payloads as test
+    /// fixtures.
+    ///
+    /// Unlike [`CongressApiClient::with_response_cache_dir`], recorded files are never read
+    /// back by the client. Bodies served from the response cache are recorded too. Failing to
+    /// write a file does not fail the request.
+    ///
+    /// # Parameters
+    ///
+    /// - [`dir`]: The directory to write to, created if it does not exist.
+    ///
+    /// # Returns
+    ///
+    /// - [`Err`]: If the directory cannot be created.
+    pub fn record_to_dir(mut self, dir: impl Into<PathBuf>) -> Result<Self, std::io::Error> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)?;
+        self.record_dir = Some(dir);
+        Ok(self)
+    }
+
+    /// Returns the number of requests this client and its clones have sent, counting every
+    /// retry attempt but no response served from the response cache.
+    pub fn requests_sent(&self) -> u64 {
+        self.requests_sent.load(Ordering::Relaxed)
+    }
+
+    /// Returns the underlying blocking [`Client`], for requests the crate does not model.
+    ///
+    /// Requests sent through it reuse the client's connection pool, but bypass the retry
+    /// policy, request hook and response cache. The API key is not added either: append
+    /// `api_key=...` to the query string yourself.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use cdg_api::CongressApiClient;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = CongressApiClient::new(Some("YOUR_API_KEY".to_string()))?;
+    ///     let body = client
+    ///         .http_client()
+    ///         .get("https://api.congress.gov/v3/bill")
+    ///         .query(&[("api_key", "YOUR_API_KEY"), ("format", "json")])
+    ///         .send()?
+    ///         .text()?;
+    ///     println!("{}", body);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn http_client(&self) -> &Client {
+        &self.client
+    }
+
     /// Fetches data from the US Congress API for a given endpoint.
     ///
+    /// `format=json` is added if the endpoint's params set no format; a format that is already
+    /// set is kept as is, so the parameter never appears twice.
+    ///
     /// # Parameters
     ///
     /// - [`endpoint`]: The API endpoint variant.
     ///
-    /// - [`T`]: The type of the response data. This type must implement [`PrimaryResponse`] and
-    /// [`DeserializeOwned`].
+    /// - [`T`]: The type of the response data. This type must implement [`PrimaryResponse`],
+    ///   [`DeserializeOwned`], and [`Default`].
     ///
     /// # Returns
     ///
     /// - Result<T, ApiClientError>: The fetched data, deserialized into the appropriate response
-    /// type.
+    ///   type. A `204 No Content` or otherwise empty response yields `T::default()`, e.g. an
+    ///   empty list for a bill without amendments.
     ///
     /// # Errors
     /// - `ApiClientError::Http`: If an HTTP error occurs.
+    /// - `ApiClientError::Unauthorized`, `NotFound`, `RateLimited`, `ServerError`, `Other`: If
+    ///   the API responds with a non-success status code.
     /// - `ApiClientError::Deserialization`: If an error occurs during deserialization.
+    /// - `ApiClientError::Schema`: If the response does not match `T`; includes the JSON path
+    ///   (e.g. `bills[3].latestAction.actionDate`) and value of the offending field, and the
+    ///   request URL.
     /// - `ApiClientError::Url`: If an error occurs while building the URL.
+    /// - `ApiClientError::InvalidParam`: If the endpoint sets `limit=0`; use
+    ///   [`CongressApiClient::count`] to get only the number of results.
+    /// - `ApiClientError::InvalidParam`: If the params contradict each other; see
+    ///   [`Endpoints::validate`].
     /// - `ApiClientError::EnvVar`: If the API key is not found in the environment.
-    pub fn fetch<T: PrimaryResponse + DeserializeOwned>(
+    pub fn fetch<T: PrimaryResponse + DeserializeOwned + Default>(
         &self,
         endpoint: Endpoints,
     ) -> Result<T, ApiClientError> {
-        let url = generate_url(endpoint, &self.api_key);
-        let response = self
-            .client
-            .get(&url)
-            .send()
-            .map_err(ApiClientError::Http)?
-            .error_for_status()
-            .map_err(|e| ApiClientError::Http(e))?; // Map HTTP errors
+        let endpoint = self.resolve(endpoint)?;
+        let url = endpoint.cache_key()?;
+        let body = self.body(endpoint)?;
 
-        let data = response
-            .json::<T>()
-            .map_err(|e: reqwest::Error| ApiClientError::Http(e))?;
-        Ok(data)
+        match parse_body(&body) {
+            Err(ApiClientError::EmptyResponse) => Ok(T::default()),
+            result => result.map_err(|err| err.with_url(url)),
+        }
     }
-}
 
-/// Custom error type for [`CongressApiClient`].
-#[derive(Debug)]
-pub enum ApiClientError {
-    Http(reqwest::Error),
-    Url(String),
-    Deserialization(serde_json::Error),
-    EnvVar(String),
-}
+    /// Fetches data like [`CongressApiClient::fetch`] into any deserializable type, such as a
+    /// minimal struct declaring only the fields the caller needs.
+    ///
+    /// Fields missing from `T` are ignored unless it uses `#[serde(deny_unknown_fields)]`,
+    /// so a partial struct parses faster and keeps less in memory than the full response
+    /// model. Unlike `fetch`, `T` needs no [`PrimaryResponse`] or [`Default`] implementation,
+    /// so an empty response is an error rather than `T::default()`.
+    ///
+    /// # Parameters
+    ///
+    /// - [`endpoint`]: The API endpoint variant.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(T)`: The deserialized data.
+    /// - `Err(ApiClientError::EmptyResponse)`: If the API returned an empty body.
+    /// - [`Err`]: The same errors as [`CongressApiClient::fetch`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use cdg_api::endpoints::{Endpoints, NewEndpoint};
+    /// use cdg_api::param_models::BillListParams;
+    /// use cdg_api::CongressApiClient;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Titles {
+    ///     bills: Vec<Title>,
+    /// }
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Title {
+    ///     title: String,
+    /// }
+    ///
+    /// let client = CongressApiClient::new(None).unwrap();
+    /// let titles: Titles = client
+    ///     .fetch_into(Endpoints::new_bill_list(BillListParams::default()))
+    ///     .unwrap();
+    /// for bill in titles.bills {
+    ///     println!("{}", bill.title);
+    /// }
+    /// ```
+    pub fn fetch_into<T: DeserializeOwned>(
+        &self,
+        endpoint: Endpoints,
+    ) -> Result<T, ApiClientError> {
+        let endpoint = self.resolve(endpoint)?;
+        let url = endpoint.cache_key()?;
+        let body = self.body(endpoint)?;
 
-impl fmt::Display for ApiClientError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            ApiClientError::Http(e) => write!(f, "HTTP error: {}", e),
-            ApiClientError::Url(e) => write!(f, "URL error: {}", e),
-            ApiClientError::Deserialization(e) => write!(f, "Deserialization error: {}", e),
-            ApiClientError::EnvVar(e) => write!(f, "Environment variable error: {}", e),
+        parse_body(&body).map_err(|err| err.with_url(url))
+    }
+
+    /// Fetches a congress.gov API URL, such as a sub-resource link from a response (a bill's
+    /// `actions.url` or `cosponsors.url`), without reconstructing its endpoint.
+    ///
+    /// The URL is parsed with [`Endpoints::from_api_url`] and fetched like any other endpoint,
+    /// so the client's API key, caching, and `format=json` default apply. An `api_key` in the
+    /// URL is ignored in favor of the client's.
+    ///
+    /// # Parameters
+    ///
+    /// - [`url`]: A URL starting with [`BASE_URL`](crate::BASE_URL).
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(T)`: The deserialized data.
+    /// - `Err(ApiClientError::InvalidParam)`: If the URL points outside the congress.gov API,
+    ///   checked before anything is sent.
+    /// - [`Err`]: The same errors as [`CongressApiClient::fetch`].
+    pub fn fetch_url<T: PrimaryResponse + DeserializeOwned + Default>(
+        &self,
+        url: &str,
+    ) -> Result<T, ApiClientError> {
+        let (endpoint, _) = Endpoints::from_api_url(url)?;
+        self.fetch(endpoint)
+    }
+
+    /// Fetches a list endpoint, passing each item to `on_item` as it is parsed from the
+    /// response stream instead of buffering the whole body first.
+    ///
+    /// Available with the `streaming` feature. Memory stays flat even for very large pages;
+    /// see [`streaming`](crate::streaming) for the response shapes that can be streamed. The
+    /// response is always requested as JSON, and it bypasses the response cache and the
+    /// recording directory, as neither can be written without buffering the body.
+    ///
+    /// # Parameters
+    ///
+    /// - [`endpoint`]: The API endpoint variant.
+    /// - [`on_item`]: Called with every item, in order.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(usize)`: The number of items passed to `on_item`.
+    /// - `Err(ApiClientError::Deserialization)`: If an item does not match `T::Item`; items
+    ///   parsed before the error have already been passed to `on_item`.
+    /// - [`Err`]: The same request errors as [`CongressApiClient::fetch`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use cdg_api::endpoints::{Endpoints, NewEndpoint};
+    /// use cdg_api::param_models::BillListParams;
+    /// use cdg_api::response_models::BillsResponse;
+    /// use cdg_api::CongressApiClient;
+    ///
+    /// let client = CongressApiClient::new(None).unwrap();
+    /// let endpoint = Endpoints::new_bill_list(BillListParams::default().limit(250));
+    /// let count = client
+    ///     .fetch_streaming::<BillsResponse, _>(endpoint, |bill| {
+    ///         println!("{}", bill.title.unwrap_or_default());
+    ///     })
+    ///     .unwrap();
+    /// println!("{} bills", count);
+    /// ```
+    #[cfg(feature = "streaming")]
+    pub fn fetch_streaming<T, F>(
+        &self,
+        endpoint: Endpoints,
+        on_item: F,
+    ) -> Result<usize, ApiClientError>
+    where
+        T: crate::response_models::ListResponse,
+        T::Item: DeserializeOwned,
+        F: FnMut(T::Item),
+    {
+        let endpoint = self.resolve(endpoint)?;
+        endpoint.validate()?;
+        let method = endpoint.method();
+        let url = self.with_default_params(
+            &endpoint,
+            self.request_url(endpoint.clone())?,
+            Some(FormatType::Json),
+        );
+        let request_id = self.new_request_id();
+        let response = self.send_url(&method, &url, request_id.as_deref())?;
+
+        crate::streaming::stream_items(std::io::BufReader::new(body_reader(response)), on_item)
+    }
+
+    /// Fetches data like [`CongressApiClient::fetch`], requesting the given format instead of
+    /// the one set in the endpoint's params.
+    ///
+    /// The response models deserialize from JSON, so an XML response cannot be parsed into
+    /// `T`; use [`CongressApiClient::fetch_text_as`] to get an XML body.
+    ///
+    /// # Parameters
+    ///
+    /// - [`endpoint`]: The API endpoint variant.
+    /// - [`format`]: The format to request, replacing the `format=` query parameter.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(T)`: The deserialized data.
+    /// - [`Err`]: The same errors as [`CongressApiClient::fetch`].
+    pub fn fetch_as<T: PrimaryResponse + DeserializeOwned + Default>(
+        &self,
+        endpoint: Endpoints,
+        format: FormatType,
+    ) -> Result<T, ApiClientError> {
+        let endpoint = self.resolve(endpoint)?;
+        let url = set_query_param(&endpoint.cache_key()?, "format", &format.to_string());
+        let body = self.fetch_text_as(endpoint, format)?;
+
+        match parse_body(&body) {
+            Err(ApiClientError::EmptyResponse) => Ok(T::default()),
+            result => result.map_err(|err| err.with_url(url)),
         }
     }
-}
 
-impl Error for ApiClientError {
-    fn source(&self) -> Option<&(dyn Error + 'static)> {
-        match self {
-            ApiClientError::Http(e) => Some(e),
-            ApiClientError::Deserialization(e) => Some(e),
-            _ => None,
+    /// Returns the raw response body for the endpoint, in the format set in its params or
+    /// else the client's default for its category (see [`CongressApiClient::text_format`]).
+    ///
+    /// # Parameters
+    ///
+    /// - [`endpoint`]: The API endpoint variant.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(String)`: The response body, from the response cache if enabled.
+    /// - [`Err`]: The request errors of [`CongressApiClient::fetch`].
+    pub fn fetch_text(&self, endpoint: Endpoints) -> Result<String, ApiClientError> {
+        self.body(endpoint)
+    }
+
+    /// Returns the raw response body for the endpoint in the given format, e.g. the XML of a
+    /// bill whose params request JSON.
+    ///
+    /// # Parameters
+    ///
+    /// - [`endpoint`]: The API endpoint variant.
+    /// - [`format`]: The format to request, replacing the `format=` query parameter.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(String)`: The response body, from the response cache if enabled.
+    /// - [`Err`]: The request errors of [`CongressApiClient::fetch`].
+    pub fn fetch_text_as(
+        &self,
+        endpoint: Endpoints,
+        format: FormatType,
+    ) -> Result<String, ApiClientError> {
+        self.body_with_meta(endpoint, Some(format))
+            .map(|(body, _)| body)
+    }
+
+    /// Fetches data like [`CongressApiClient::fetch`], additionally returning transfer details
+    /// of the response such as its on-wire and decompressed sizes.
+    ///
+    /// The sizes only differ when the response was compressed, which the client requests
+    /// with the `gzip` feature (enabled by default).
+    ///
+    /// # Parameters
+    ///
+    /// - [`endpoint`]: The API endpoint variant.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok((T, ResponseMeta))`: The deserialized data and the response metadata.
+    /// - [`Err`]: The same errors as [`CongressApiClient::fetch`].
+    pub fn fetch_with_meta<T: PrimaryResponse + DeserializeOwned + Default>(
+        &self,
+        endpoint: Endpoints,
+    ) -> Result<(T, ResponseMeta), ApiClientError> {
+        let endpoint = self.resolve(endpoint)?;
+        let url = endpoint.cache_key()?;
+        let (body, meta) = self.body_with_meta(endpoint, None)?;
+
+        match parse_body(&body) {
+            Err(ApiClientError::EmptyResponse) => Ok((T::default(), meta)),
+            result => result
+                .map(|data| (data, meta))
+                .map_err(|err| err.with_url(url)),
         }
     }
+
+    /// Fetches data like [`CongressApiClient::fetch`], additionally returning the response body
+    /// it was parsed from, e.g. to keep the raw payload for records.
+    ///
+    /// The body is that of the single request (or cache entry) the data was parsed from,
+    /// unmodified, including a byte order mark the parser skips.
+    ///
+    /// # Parameters
+    ///
+    /// - [`endpoint`]: The API endpoint variant.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok((T, String))`: The deserialized data and the raw response body.
+    /// - [`Err`]: The same errors as [`CongressApiClient::fetch`].
+    pub fn fetch_with_raw<T: PrimaryResponse + DeserializeOwned + Default>(
+        &self,
+        endpoint: Endpoints,
+    ) -> Result<(T, String), ApiClientError> {
+        let endpoint = self.resolve(endpoint)?;
+        let url = endpoint.cache_key()?;
+        let body = self.body(endpoint)?;
+
+        match parse_body(&body) {
+            Err(ApiClientError::EmptyResponse) => Ok((T::default(), body)),
+            result => result
+                .map(|data| (data, body))
+                .map_err(|err| err.with_url(url)),
+        }
+    }
+
+    /// Fetches data like [`CongressApiClient::fetch`], additionally reporting any top-level keys
+    /// in the response that the model `T` does not define.
+    ///
+    /// Unknown keys are still captured into the model's `unknown` field as usual; the warnings
+    /// simply make them visible instead of silently accepting them. The `pagination` and
+    /// `request` keys of the envelope around every list response are never reported; the
+    /// client reads `pagination` itself through [`Paginated`].
+    ///
+    /// # Parameters
+    ///
+    /// - [`endpoint`]: The API endpoint variant.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok((T, Vec<String>))`: The deserialized data and the names of the unknown keys.
+    /// - [`Err`]: The same errors as [`CongressApiClient::fetch`].
+    pub fn fetch_with_warnings<T: PrimaryResponse + DeserializeOwned + Default>(
+        &self,
+        endpoint: Endpoints,
+    ) -> Result<(T, Vec<String>), ApiClientError> {
+        let endpoint = self.resolve(endpoint)?;
+        let url = endpoint.cache_key()?;
+        let body = self.body(endpoint)?;
+        let data: T = match parse_body(&body) {
+            Err(ApiClientError::EmptyResponse) => return Ok((T::default(), Vec::new())),
+            result => result.map_err(|err| err.with_url(url))?,
+        };
+
+        let warnings = match data.unknown() {
+            Some(Value::Object(unknown)) => unknown
+                .keys()
+                .filter(|key| !ENVELOPE_KEYS.contains(&key.as_str()))
+                .cloned()
+                .collect(),
+            _ => Vec::new(),
+        };
+        Ok((data, warnings))
+    }
+
+    /// Fetches data like [`CongressApiClient::fetch`], dropping fields that do not match the
+    /// model instead of failing.
+    ///
+    /// See [`parse_body_lenient`] for how fields are recovered. This trades strictness for
+    /// resilience: a partially populated `T` is returned where [`CongressApiClient::fetch`]
+    /// would fail, so check the dropped paths before trusting missing values.
+    ///
+    /// # Parameters
+    ///
+    /// - [`endpoint`]: The API endpoint variant.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok((T, Vec<String>))`: The deserialized data and the paths of the dropped fields.
+    /// - [`Err`]: The same errors as [`CongressApiClient::fetch`]; `Schema` only when the
+    ///   response cannot be recovered.
+    pub fn fetch_lenient<T: PrimaryResponse + DeserializeOwned + Default>(
+        &self,
+        endpoint: Endpoints,
+    ) -> Result<(T, Vec<String>), ApiClientError> {
+        let endpoint = self.resolve(endpoint)?;
+        let url = endpoint.cache_key()?;
+        let body = self.body(endpoint)?;
+
+        match parse_body_lenient(&body) {
+            Err(ApiClientError::EmptyResponse) => Ok((T::default(), Vec::new())),
+            result => result.map_err(|err| err.with_url(url)),
+        }
+    }
+
+    /// Fetches consecutive pages of a list endpoint until `max` items are collected or the
+    /// results run out.
+    ///
+    /// Offsets are 0-based: the first page is requested with `offset=0` and each following
+    /// page starts right after the records already received (e.g. `250`, `500`, ... for a
+    /// `page_limit` of `250`). See [`paginate`] for details.
+    ///
+    /// # Parameters
+    ///
+    /// - [`endpoint_fn`]: Builds the endpoint for a given `offset` and `limit`.
+    /// - [`extract_fn`]: Extracts the items from a page's response.
+    /// - [`max`]: The maximum number of items to fetch.
+    /// - [`page_limit`]: The number of items to request per page.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<U>)`: The collected items, at most `max`.
+    /// - [`Err`]: The first error returned by [`CongressApiClient::fetch`].
+    pub fn fetch_all<T, U, F, G>(
+        &self,
+        endpoint_fn: F,
+        extract_fn: G,
+        max: usize,
+        page_limit: usize,
+    ) -> Result<Vec<U>, ApiClientError>
+    where
+        F: Fn(usize, usize) -> Endpoints,
+        G: Fn(&T) -> Vec<U>,
+        T: PrimaryResponse + DeserializeOwned + Default,
+    {
+        let start = Instant::now();
+        let mut total = 0;
+
+        paginate(
+            |offset, limit| {
+                let response: T = self.fetch(endpoint_fn(offset, limit))?;
+                let items = extract_fn(&response);
+                total += items.len();
+                self.report_page(offset, items.len(), start, total);
+                Ok(items)
+            },
+            max,
+            page_limit,
+        )
+    }
+
+    /// Fetches consecutive pages of a list endpoint like [`CongressApiClient::fetch_all`],
+    /// extracting the items through [`Paginated`] instead of a closure.
+    ///
+    /// The next page starts at the offset reported by the response's `pagination.next` URL;
+    /// paging stops when there is no next page, a page is empty, or `max` items are collected.
+    ///
+    /// # Parameters
+    ///
+    /// - [`endpoint_fn`]: Builds the endpoint for a given `offset` and `limit`.
+    /// - [`max`]: The maximum number of items to fetch.
+    /// - [`page_limit`]: The number of items to request per page.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<T::Item>)`: The collected items, at most `max`.
+    /// - [`Err`]: The first error returned by [`CongressApiClient::fetch`].
+    pub fn fetch_all_items<T, F>(
+        &self,
+        endpoint_fn: F,
+        max: usize,
+        page_limit: usize,
+    ) -> Result<Vec<T::Item>, ApiClientError>
+    where
+        F: Fn(usize, usize) -> Endpoints,
+        T: Paginated + PrimaryResponse + DeserializeOwned + Default,
+    {
+        self.fetch_pages::<T, _>(
+            |offset, limit| Ok(endpoint_fn(offset, limit)),
+            max,
+            page_limit,
+        )
+    }
+
+    /// Fetches the items of a list endpoint updated since the last sync, for pulling only
+    /// what changed since a previous run.
+    ///
+    /// Every page is requested with `fromDateTime` set to the watermark and sorted by
+    /// `updateDate` ascending, replacing any `sort` in the endpoint's params, so paging
+    /// through changes that arrive mid-sync never skips an item. The `fromDateTime` filter is
+    /// inclusive: items updated exactly at the watermark are returned again, so store them
+    /// by key rather than appending.
+    ///
+    /// # Parameters
+    ///
+    /// - [`endpoint_fn`]: Builds the endpoint for a given `offset` and `limit`.
+    /// - [`watermark`]: The watermark returned by the previous sync, or [`None`] to fetch
+    ///   everything. A date such as `2024-01-10` counts from midnight UTC.
+    /// - [`max`]: The maximum number of items to fetch.
+    /// - [`page_limit`]: The number of items to request per page.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok((Vec<T::Item>, Option<String>))`: The items, oldest update first, and the new
+    ///   watermark: the latest `updateDate` among them as `YYYY-MM-DDTHH:MM:SSZ`, or the
+    ///   given watermark if nothing changed.
+    /// - `Err(ApiClientError::InvalidParam)`: If the endpoint's params cannot be sorted, or
+    ///   filtered by `fromDateTime` when a watermark is given (see [`Endpoints::set_sort`]).
+    /// - [`Err`]: The first error returned by [`CongressApiClient::fetch`].
+    pub fn fetch_updated_since<T, F>(
+        &self,
+        endpoint_fn: F,
+        watermark: Option<&str>,
+        max: usize,
+        page_limit: usize,
+    ) -> Result<(Vec<T::Item>, Option<String>), ApiClientError>
+    where
+        F: Fn(usize, usize) -> Endpoints,
+        T: Paginated + PrimaryResponse + DeserializeOwned + Default,
+        T::Item: Updated,
+    {
+        let from_date_time = watermark.map(as_date_time);
+        let items = self.fetch_pages::<T, _>(
+            |offset, limit| {
+                let mut endpoint = self.resolve(endpoint_fn(offset, limit))?;
+                endpoint.set_sort(SortType::UpdateDateAsc)?;
+                if let Some(from_date_time) = &from_date_time {
+                    endpoint.set_from_date_time(from_date_time.as_str())?;
+                }
+                Ok(endpoint)
+            },
+            max,
+            page_limit,
+        )?;
+
+        let watermark = items
+            .iter()
+            .filter_map(|item| item.update_date())
+            .map(as_date_time)
+            .chain(from_date_time)
+            .max();
+        Ok((items, watermark))
+    }
+
+    /// Fetches consecutive pages for [`CongressApiClient::fetch_all_items`], building each
+    /// page's endpoint with a fallible `endpoint_fn`.
+    fn fetch_pages<T, F>(
+        &self,
+        endpoint_fn: F,
+        max: usize,
+        page_limit: usize,
+    ) -> Result<Vec<T::Item>, ApiClientError>
+    where
+        F: Fn(usize, usize) -> Result<Endpoints, ApiClientError>,
+        T: Paginated + PrimaryResponse + DeserializeOwned + Default,
+    {
+        let start = Instant::now();
+        let mut items = Vec::new();
+        let mut offset = 0;
+
+        while items.len() < max {
+            let page: T = self.fetch(endpoint_fn(offset, page_limit)?)?;
+            let next_offset = page.next_offset().map(|next| next as usize);
+            let page_items = page.into_items();
+            let fetched_count = page_items.len();
+            items.extend(page_items);
+            self.report_page(offset, fetched_count, start, items.len());
+
+            match next_offset {
+                Some(next) if fetched_count > 0 && next > offset => offset = next,
+                _ => break,
+            }
+        }
+
+        items.truncate(max);
+        Ok(items)
+    }
+
+    /// Returns the URLs that fetching the given endpoints would request, without sending
+    /// anything, e.g. to review a crawl and estimate its quota use together with
+    /// [`CongressApiClient::count`].
+    ///
+    /// The URLs never contain the API key. Endpoints are checked the same way as before a
+    /// fetch: [`Congress::Current`] is replaced with the current congress, and
+    /// the congress must lie in the range set with [`CongressApiClient::with_congress_range`].
+    /// Only a current congress this client has already looked up is used; call
+    /// [`CongressApiClient::current_congress`] first to plan such endpoints. Like the fetch methods,
+    /// the client's default format (see [`CongressApiClient::detail_format`]) is added to
+    /// endpoints that set no format, and the
+    /// [`default_limit`](CongressApiClient::default_limit) to list endpoints that set no limit.
+    ///
+    /// # Parameters
+    ///
+    /// - [`endpoints`]: The endpoints of the planned requests.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<String>)`: One URL per endpoint, in the same order.
+    /// - `Err(ApiClientError::InvalidParam)`: If an endpoint uses the current congress before
+    ///   it has been looked up, or a congress is outside the accepted range.
+    pub fn plan_urls(&self, endpoints: &[Endpoints]) -> Result<Vec<String>, ApiClientError> {
+        let cached_congress = || {
+            self.current_congress.get().copied().ok_or_else(|| {
+                ApiClientError::InvalidParam(
+                    "the current congress has not been looked up yet; call `current_congress` \
+                     before planning its URLs"
+                        .to_string(),
+                )
+            })
+        };
+        endpoints
+            .iter()
+            .map(|endpoint| {
+                let endpoint = self.resolve_with(endpoint.clone(), cached_congress)?;
+                Ok(self.with_default_params(&endpoint, endpoint.cache_key()?, None))
+            })
+            .collect()
+    }
+
+    /// Returns the total number of results available for a list endpoint without fetching them.
+    ///
+    /// The request is sent with `limit=1` and the total is read from the response's
+    /// `pagination.count`, which is far cheaper than fetching every page.
+    ///
+    /// # Parameters
+    ///
+    /// - [`endpoint`]: The API endpoint variant. Must be a list endpoint.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(u32)`: The total number of results matching the endpoint's parameters.
+    /// - `Err(ApiClientError::Deserialization)`: If the response has no `pagination` block,
+    ///   e.g. for detail endpoints.
+    pub fn count(&self, endpoint: Endpoints) -> Result<u32, ApiClientError> {
+        #[derive(Deserialize)]
+        struct CountResponse {
+            pagination: Pagination,
+        }
+
+        let endpoint = self.resolve(endpoint)?;
+        endpoint.validate()?;
+        let with_count_params =
+            |url: &str| set_query_param(&set_query_param(url, "format", "json"), "limit", "1");
+        let key = with_count_params(&endpoint.cache_key()?);
+        let method = endpoint.method();
+        let url = with_count_params(&self.request_url(endpoint)?);
+
+        let body = self.cached_body(&key, method, &url)?;
+        let data: CountResponse = parse_body(&body)?;
+        Ok(data.pagination.count.unwrap_or_default())
+    }
+
+    /// Fetches several endpoints concurrently, at most four at a time.
+    ///
+    /// Every endpoint is fetched as with [`CongressApiClient::fetch`]; a failing request does
+    /// not stop the others.
+    ///
+    /// **Quota cost:** one request per endpoint, plus any retries.
+    ///
+    /// # Parameters
+    ///
+    /// - [`endpoints`]: The endpoints to fetch.
+    ///
+    /// # Returns
+    ///
+    /// One result per endpoint, in the order the endpoints were given.
+    pub fn fetch_many<T>(&self, endpoints: Vec<Endpoints>) -> Vec<Result<T, ApiClientError>>
+    where
+        T: PrimaryResponse + DeserializeOwned + Default + Send,
+    {
+        let mut results = Vec::with_capacity(endpoints.len());
+        let mut endpoints = endpoints.into_iter().peekable();
+
+        while endpoints.peek().is_some() {
+            let chunk: Vec<Endpoints> = endpoints.by_ref().take(MAX_CONCURRENT_REQUESTS).collect();
+            thread::scope(|scope| {
+                let handles: Vec<_> = chunk
+                    .into_iter()
+                    .map(|endpoint| scope.spawn(move || self.fetch::<T>(endpoint)))
+                    .collect();
+
+                results.extend(
+                    handles
+                        .into_iter()
+                        .map(|handle| handle.join().expect("request thread panicked")),
+                );
+            });
+        }
+
+        results
+    }
+
+    /// Lists every bill of a single type across a range of congresses.
+    ///
+    /// The API has no endpoint listing bills by type without a congress, so this pages
+    /// through the [`BillByType`](Endpoints::BillByType) list of each congress in
+    /// `congresses`, like [`CongressApiClient::fetch_all_items`], working on up to four
+    /// congresses at a time, and concatenates the results in congress order.
+    ///
+    /// **Quota cost:** one request per page of every congress in the range, plus any
+    /// retries. A congress with `n` bills of the type takes `n / limit + 1` pages, e.g. 40
+    /// requests for 10,000 House bills at the default page size of 250.
+    ///
+    /// # Parameters
+    ///
+    /// - [`bill_type`]: The type of bill to list.
+    /// - [`congresses`]: The congresses to query, e.g. `110..=118`.
+    /// - [`params`]: The parameters applied to every request. `limit` sets the page size,
+    ///   250 by default; `offset` is replaced while paging.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<BillSummary>)`: The bills of every congress in the range.
+    /// - [`Err`]: The first error encountered, in congress order.
+    pub fn list_bills_by_type_all_congresses(
+        &self,
+        bill_type: BillType,
+        congresses: RangeInclusive<u32>,
+        params: BillByTypeParams,
+    ) -> Result<Vec<BillSummary>, ApiClientError> {
+        let page_limit = params.limit.unwrap_or(MAX_PAGE_LIMIT) as usize;
+        let congresses: Vec<u32> = congresses.collect();
+        let params = &params;
+
+        let mut bills = Vec::new();
+        for chunk in congresses.chunks(MAX_CONCURRENT_REQUESTS) {
+            let results: Vec<_> = thread::scope(|scope| {
+                let handles: Vec<_> = chunk
+                    .iter()
+                    .map(|&congress| {
+                        scope.spawn(move || {
+                            self.fetch_all_items::<BillsResponse, _>(
+                                |offset, limit| {
+                                    Endpoints::new_bill_by_type(
+                                        congress,
+                                        bill_type,
+                                        params.clone().offset(offset as u32).limit(limit as u32),
+                                    )
+                                },
+                                usize::MAX,
+                                page_limit,
+                            )
+                        })
+                    })
+                    .collect();
+
+                handles
+                    .into_iter()
+                    .map(|handle| handle.join().expect("request thread panicked"))
+                    .collect()
+            });
+
+            for result in results {
+                bills.extend(result?);
+            }
+        }
+
+        Ok(bills)
+    }
+
+    /// Returns the number of the current congress.
+    ///
+    /// The number is fetched from the `/congress/current` endpoint on first use and cached
+    /// for the lifetime of the client.
+    pub fn current_congress(&self) -> Result<u32, ApiClientError> {
+        if let Some(number) = self.current_congress.get() {
+            return Ok(*number);
+        }
+
+        let endpoint = Endpoints::new_congress_current(
+            CongressCurrentParams::default().format(FormatType::Json),
+        );
+        let method = endpoint.method();
+        let request_id = self.new_request_id();
+        let response =
+            self.send_url(&method, &self.request_url(endpoint)?, request_id.as_deref())?;
+        let (body, _) = read_body(response)?;
+        let data: CongressDetailsResponse = parse_body(&body)?;
+        let number = data.congress.number.ok_or_else(|| {
+            ApiClientError::Deserialization(serde::de::Error::missing_field("number"))
+        })?;
+
+        Ok(*self.current_congress.get_or_init(|| number))
+    }
+
+    /// Checks that the API is reachable and accepts the API key, e.g. for a readiness probe
+    /// at startup.
+    ///
+    /// Sends `congress/current?limit=1`, bypassing the response cache. The retry policy and
+    /// rate limiter apply as for any other request.
+    ///
+    /// **Quota cost:** one request, plus any retries.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())`: If the API answered with a success status.
+    /// - `Err(ApiClientError::Unauthorized)`: If the API key was rejected.
+    /// - [`Err`]: Any other error the request failed with, e.g. `ApiClientError::Http` when
+    ///   the API is unreachable.
+    pub fn health_check(&self) -> Result<(), ApiClientError> {
+        let endpoint = Endpoints::new_congress_current(
+            CongressCurrentParams::default().format(FormatType::Json),
+        );
+        let method = endpoint.method();
+        let url = set_query_param(&self.request_url(endpoint)?, "limit", "1");
+        let request_id = self.new_request_id();
+        self.send_url(&method, &url, request_id.as_deref())?;
+
+        Ok(())
+    }
+
+    /// Replaces [`Congress::Current`] in the endpoint with the current congress, and checks
+    /// the congress against the range set with [`CongressApiClient::with_congress_range`].
+    /// Congress `0` is rejected rather than sent.
+    fn resolve(&self, endpoint: Endpoints) -> Result<Endpoints, ApiClientError> {
+        self.resolve_with(endpoint, || self.current_congress())
+    }
+
+    /// Like [`CongressApiClient::resolve`], taking the current congress from `current`.
+    fn resolve_with(
+        &self,
+        mut endpoint: Endpoints,
+        current: impl FnOnce() -> Result<u32, ApiClientError>,
+    ) -> Result<Endpoints, ApiClientError> {
+        if let Some(congress) = endpoint.congress_mut() {
+            let number =
+                match *congress {
+                    Congress::Number(0) => return Err(ApiClientError::InvalidParam(
+                        "there is no congress 0; pass `Congress::Current` for the current congress"
+                            .to_string(),
+                    )),
+                    Congress::Number(number) => number,
+                    Congress::Current => current()?,
+                };
+            if let Some(range) = &self.congress_range {
+                if !range.contains(&number) {
+                    return Err(ApiClientError::InvalidParam(format!(
+                        "congress {} is outside the accepted range {}..={}",
+                        number,
+                        range.start(),
+                        range.end()
+                    )));
+                }
+            }
+            *congress = Congress::Number(number);
+        }
+
+        Ok(endpoint)
+    }
+
+    /// Passes the progress of a multi-page fetch to the page hook, if set.
+    fn report_page(&self, offset: usize, fetched: usize, start: Instant, total_so_far: usize) {
+        if let Some(hook) = &self.page_hook {
+            hook(PageStats {
+                offset,
+                fetched,
+                elapsed: start.elapsed(),
+                total_so_far,
+            });
+        }
+    }
+
+    /// Returns the response body for the given endpoint, from the response cache if enabled.
+    ///
+    /// `limit=0` is rejected before sending: the API documents no count-only mode, so such a
+    /// request would not return what the caller expects.
+    /// Params breaking their [`Endpoints::validate`] rules are rejected too.
+    fn body(&self, endpoint: Endpoints) -> Result<String, ApiClientError> {
+        self.body_with_meta(endpoint, None).map(|(body, _)| body)
+    }
+
+    /// Returns the response body like [`CongressApiClient::body`], along with its metadata.
+    ///
+    /// If `format` is given, it replaces the endpoint's `format` query parameter. Otherwise
+    /// `format=json` is added unless the endpoint already sets a format.
+    fn body_with_meta(
+        &self,
+        endpoint: Endpoints,
+        format: Option<FormatType>,
+    ) -> Result<(String, ResponseMeta), ApiClientError> {
+        let endpoint = self.resolve(endpoint)?;
+        endpoint.validate()?;
+        let key = self.with_default_params(&endpoint, endpoint.cache_key()?, format);
+        if query_param(&key, "limit") == Some("0") {
+            return Err(ApiClientError::InvalidParam(
+                "limit must be at least 1; use CongressApiClient::count for the number of results"
+                    .to_string(),
+            ));
+        }
+        let method = endpoint.method();
+        let url = self.with_default_params(&endpoint, self.request_url(endpoint.clone())?, format);
+        self.cached_body_with_meta(&key, method, &url)
+    }
+
+    /// Applies the query parameters the client adds to `url` for `endpoint`: the requested
+    /// `format` (or, if the URL sets none, the client's default for the endpoint's category,
+    /// falling back to `format=json`) and the default `limit` for list endpoints that set none.
+    fn with_default_params(
+        &self,
+        endpoint: &Endpoints,
+        url: String,
+        format: Option<FormatType>,
+    ) -> String {
+        let url = match format {
+            Some(format) => set_query_param(&url, "format", &format.to_string()),
+            None => {
+                let default = if endpoint.is_text() {
+                    self.text_format
+                } else if !endpoint.is_list() {
+                    self.detail_format
+                } else {
+                    None
+                };
+                let default = default.unwrap_or(FormatType::Json);
+                set_default_query_param(&url, "format", &default.to_string())
+            }
+        };
+        match self.default_limit {
+            Some(limit) if endpoint.is_list() => {
+                set_default_query_param(&url, "limit", &limit.to_string())
+            }
+            _ => url,
+        }
+    }
+
+    /// Returns the URL requested for the given endpoint, including the API key only when it is
+    /// sent as a query parameter.
+    fn request_url(&self, endpoint: Endpoints) -> Result<String, ApiClientError> {
+        match self.api_key_location {
+            ApiKeyLocation::Query => generate_url(endpoint, &self.api_key),
+            ApiKeyLocation::Header(_) => endpoint.cache_key(),
+        }
+    }
+
+    /// Returns the cached body stored under `key`, or requests `url` with `method` and caches
+    /// its body.
+    ///
+    /// Failing to write the cache does not fail the request.
+    fn cached_body(&self, key: &str, method: Method, url: &str) -> Result<String, ApiClientError> {
+        self.cached_body_with_meta(key, method, url)
+            .map(|(body, _)| body)
+    }
+
+    /// Returns the body like [`CongressApiClient::cached_body`], along with its metadata.
+    fn cached_body_with_meta(
+        &self,
+        key: &str,
+        method: Method,
+        url: &str,
+    ) -> Result<(String, ResponseMeta), ApiClientError> {
+        let cached = |body: String| {
+            self.record(key, &body);
+            let meta = ResponseMeta {
+                url: key.to_string(),
+                from_cache: true,
+                body_bytes: body.len(),
+                ..ResponseMeta::default()
+            };
+            (body, meta)
+        };
+
+        // Hold the key while fetching so concurrent misses wait for this request instead of
+        // sending their own. If it fails, the next waiter claims the key and tries again.
+        // Entries are renamed into place whole, so the unclaimed lookup never reads a body
+        // still being written; `cache_hit` evicts any left incomplete by an older writer.
+        let _claim = match &self.response_cache {
+            Some(cache) => loop {
+                if let Some(body) = cache_hit(cache, key) {
+                    return Ok(cached(body));
+                }
+                if let Some(claim) = self.in_flight.claim(key) {
+                    // The previous holder may have filled the cache since the lookup above.
+                    if let Some(body) = cache_hit(cache, key) {
+                        return Ok(cached(body));
+                    }
+                    break Some(claim);
+                }
+            },
+            None => None,
+        };
+
+        let request_id = self.new_request_id();
+        let response = self.send_url(&method, url, request_id.as_deref())?;
+        let received_at = SystemTime::now();
+        let header = |name| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string)
+        };
+        let mut meta = ResponseMeta {
+            url: key.to_string(),
+            from_cache: false,
+            status: Some(response.status().as_u16()),
+            content_encoding: header(CONTENT_ENCODING),
+            wire_bytes: None,
+            body_bytes: 0,
+            date: header(DATE),
+            received_at: Some(received_at),
+            request_id,
+        };
+
+        let (body, wire_bytes) = read_body(response)?;
+        meta.wire_bytes = Some(wire_bytes);
+        meta.body_bytes = body.len();
+        if let Some(cache) = &self.response_cache {
+            // An empty body is never a cache hit (see `cache_hit`), so don't store one.
+            if !is_blank(&body) {
+                let _ = cache.put(key, &body);
+            }
+        }
+        self.record(key, &body);
+
+        Ok((body, meta))
+    }
+
+    /// Writes `body` to the recording directory, if set.
+    fn record(&self, key: &str, body: &str) {
+        if let Some(dir) = &self.record_dir {
+            let _ = std::fs::write(dir.join(fixture_file_name(key)), body);
+        }
+    }
+
+    /// Sends a request with the given method to the given URL and checks the response status,
+    /// retrying according to the configured [`RetryPolicy`].
+    fn send_url(
+        &self,
+        method: &Method,
+        url: &str,
+        request_id: Option<&str>,
+    ) -> Result<Response, ApiClientError> {
+        let mut attempt = 0;
+
+        loop {
+            attempt += 1;
+            let err = match self.send_once(method, url, request_id) {
+                Ok(response) => return Ok(response),
+                Err(err) => err,
+            };
+
+            match self
+                .retry_policy
+                .as_ref()
+                .and_then(|policy| policy.should_retry(attempt, &err))
+            {
+                Some(delay) => self.sleeper.sleep(delay),
+                None => return Err(err),
+            }
+        }
+    }
+
+    /// Logs a `429` response with what is needed to right-size a crawl: the delay the API
+    /// asked for, the resource requested, and the requests sent so far.
+    #[cfg(feature = "logging")]
+    fn log_rate_limited(&self, url: &str, retry_after: Option<Duration>) {
+        let resource = resource_of(url);
+        let retry_after = retry_after.map_or_else(
+            || "an unspecified delay".to_string(),
+            |delay| format!("{}s", delay.as_secs()),
+        );
+        log::warn!(
+            "congress.gov API quota exhausted (429) on `{}`: retry after {}; {} requests sent by this client",
+            resource,
+            retry_after,
+            self.requests_sent()
+        );
+    }
+
+    /// Returns a new request ID if request IDs are enabled.
+    fn new_request_id(&self) -> Option<String> {
+        self.request_id_header
+            .as_ref()
+            .map(|_| (self.request_id_generator)())
+    }
+
+    /// Sends a single request with the given method to the given URL and checks the response
+    /// status.
+    fn send_once(
+        &self,
+        method: &Method,
+        url: &str,
+        request_id: Option<&str>,
+    ) -> Result<Response, ApiClientError> {
+        let resource = resource_of(url);
+        if let Some(budget) = self.resource_budgets.get(resource) {
+            if !budget.try_take() {
+                return Err(ApiClientError::BudgetExceeded {
+                    resource: resource.to_string(),
+                    max_per_hour: budget.max(),
+                });
+            }
+        }
+        if let Some(limiter) = &self.rate_limiter {
+            let delay = limiter.acquire();
+            if !delay.is_zero() {
+                self.sleeper.sleep(delay);
+            }
+        }
+
+        let mut request = match &self.base_url {
+            Some(base_url) => {
+                let path = url.strip_prefix(crate::BASE_URL).unwrap_or(url);
+                self.client
+                    .request(method.clone(), format!("{}{}", base_url, path))
+            }
+            None => self.client.request(method.clone(), url),
+        };
+        if let ApiKeyLocation::Header(name) = &self.api_key_location {
+            request = request.header(name.as_str(), &*self.api_key);
+        }
+        if let (Some(name), Some(id)) = (&self.request_id_header, request_id) {
+            request = request.header(name.as_str(), id);
+        }
+        if let Some(tag) = &self.accept_language {
+            request = request.header(ACCEPT_LANGUAGE, tag.as_str());
+        }
+        #[cfg(feature = "gzip")]
+        {
+            request = request.header(reqwest::header::ACCEPT_ENCODING, "gzip");
+        }
+        if let Some(hook) = &self.request_hook {
+            request = hook(request);
+        }
+
+        self.requests_sent.fetch_add(1, Ordering::Relaxed);
+        let response = request.send().map_err(ApiClientError::Http)?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let err = ApiClientError::from_response(&response);
+            #[cfg(feature = "logging")]
+            if let ApiClientError::RateLimited { retry_after } = &err {
+                self.log_rate_limited(url, *retry_after);
+            }
+            let body = read_body(response)
+                .map(|(body, _)| body)
+                .unwrap_or_default();
+            return Err(err.with_body(body));
+        }
+
+        Ok(response)
+    }
+}
+
+/// Reads the body of `response`, decompressing it if the server sent it gzip-encoded.
+///
+/// Returns the body and its on-wire size in bytes.
+fn read_body(response: Response) -> Result<(String, u64), ApiClientError> {
+    #[cfg(feature = "gzip")]
+    let gzip = is_gzip(&response);
+    let bytes = response.bytes().map_err(ApiClientError::Http)?;
+    let wire_bytes = bytes.len() as u64;
+    #[cfg(feature = "gzip")]
+    if gzip {
+        return Ok((decode_gzip(&bytes)?, wire_bytes));
+    }
+    Ok((String::from_utf8_lossy(&bytes).into_owned(), wire_bytes))
+}
+
+/// Returns a reader over the body of `response`, decompressing it as it is read if the server
+/// sent it gzip-encoded.
+#[cfg(feature = "streaming")]
+fn body_reader(response: Response) -> Box<dyn std::io::Read> {
+    #[cfg(feature = "gzip")]
+    if is_gzip(&response) {
+        return Box::new(flate2::read::GzDecoder::new(response));
+    }
+    Box::new(response)
+}
+
+/// Returns whether the body of `response` is gzip-encoded.
+#[cfg(feature = "gzip")]
+fn is_gzip(response: &Response) -> bool {
+    response
+        .headers()
+        .get(CONTENT_ENCODING)
+        .is_some_and(|value| value.as_bytes().eq_ignore_ascii_case(b"gzip"))
+}
+
+/// Decompresses a gzip-encoded body. Invalid UTF-8 is replaced, as for uncompressed bodies.
+#[cfg(feature = "gzip")]
+fn decode_gzip(bytes: &[u8]) -> Result<String, ApiClientError> {
+    use std::io::Read;
+
+    let mut body = Vec::new();
+    flate2::read::GzDecoder::new(bytes)
+        .read_to_end(&mut body)
+        .map_err(|err| ApiClientError::Deserialization(serde_json::Error::io(err)))?;
+    Ok(String::from_utf8_lossy(&body).into_owned())
+}
+
+/// Returns the cached body for `key`, or [`None`] if there is none or it is unusable.
+///
+/// An empty body, or one that is not valid JSON when JSON was requested, can only be left
+/// behind by an interrupted write; it is evicted so the caller fetches the body again instead of
+/// replaying it forever.
+fn cache_hit(cache: &DiskCache, key: &str) -> Option<String> {
+    let body = cache.get(key)?;
+    let is_json = query_param(key, "format").is_none_or(|format| format == "json");
+    let usable = !is_blank(&body)
+        && (!is_json || serde_json::from_str::<serde::de::IgnoredAny>(trim_body(&body)).is_ok());
+    if usable {
+        Some(body)
+    } else {
+        let _ = cache.remove(key);
+        None
+    }
+}
+
+/// Strips a leading byte order mark and surrounding whitespace from a response body.
+fn trim_body(body: &str) -> &str {
+    body.trim_start_matches('\u{feff}').trim()
+}
+
+/// Returns whether a response body is empty once trimmed like [`parse_body`] does.
+fn is_blank(body: &str) -> bool {
+    trim_body(body).is_empty()
+}
+
+/// Returns the resource a request URL belongs to, its first path segment; the same name
+/// [`Endpoints::resource_name`] returns for the endpoint the URL was built from.
+fn resource_of(url: &str) -> &str {
+    let path = url.strip_prefix(crate::BASE_URL).unwrap_or(url);
+    path.split(['/', '?']).next().unwrap_or(path)
+}
+
+/// Extends an `updateDate` given as a bare date, e.g. `2024-01-10`, to the
+/// `YYYY-MM-DDTHH:MM:SSZ` form `fromDateTime` expects.
+fn as_date_time(update_date: &str) -> String {
+    if update_date.len() == "YYYY-MM-DD".len() {
+        format!("{}T00:00:00Z", update_date)
+    } else {
+        update_date.to_string()
+    }
+}
+
+/// Returns a random version 4 UUID such as `9f1c0b6e-3d2a-4c55-8e7f-1a2b3c4d5e6f`.
+///
+/// The randomness comes from the standard library's hasher keys, which is plenty for
+/// correlating log lines but not for anything security-sensitive.
+fn random_uuid() -> String {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    let count = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let nanos = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_nanos())
+        .unwrap_or_default();
+    let random = |salt: u8| RandomState::new().hash_one((nanos, count, salt));
+
+    // Set the version (4) and variant (10xx) bits.
+    let high = (random(0) & !0xf000) | 0x4000;
+    let low = (random(1) & !(0xc << 60)) | (0x8 << 60);
+    format!(
+        "{:08x}-{:04x}-{:04x}-{:04x}-{:012x}",
+        high >> 32,
+        (high >> 16) & 0xffff,
+        high & 0xffff,
+        low >> 48,
+        low & 0xffff_ffff_ffff
+    )
+}
+
+/// Deserializes a response body, ignoring a leading byte order mark and surrounding
+/// whitespace that strict JSON parsers would otherwise reject.
+///
+/// # Parameters
+///
+/// - [`body`]: The raw response body.
+///
+/// # Returns
+///
+/// - `Ok(T)`: The deserialized data.
+/// - `Err(ApiClientError::EmptyResponse)`: If nothing remains after trimming.
+/// - `Err(ApiClientError::Schema)`: If the body is valid JSON that does not match `T`.
+/// - `Err(ApiClientError::Deserialization)`: If the trimmed body is not valid JSON.
+pub fn parse_body<T: DeserializeOwned>(body: &str) -> Result<T, ApiClientError> {
+    let trimmed = trim_body(body);
+    if trimmed.is_empty() {
+        return Err(ApiClientError::EmptyResponse);
+    }
+
+    let mut deserializer = serde_json::Deserializer::from_str(trimmed);
+    let data = serde_path_to_error::deserialize(&mut deserializer).map_err(|err| {
+        let path = err.path().clone();
+        let source = err.into_inner();
+        if !source.is_data() {
+            return ApiClientError::Deserialization(source);
+        }
+
+        // Best-effort: re-parse the body untyped to show what was actually received.
+        let value = serde_json::from_str::<Value>(trimmed)
+            .ok()
+            .and_then(|root| value_at(&root, &path).cloned());
+        ApiClientError::Schema {
+            path: path.to_string(),
+            value,
+            url: None,
+            source,
+        }
+    })?;
+    deserializer
+        .end()
+        .map_err(ApiClientError::Deserialization)?;
+
+    Ok(data)
+}
+
+/// Deserializes a response body like [`parse_body`], recovering from fields that do not
+/// match `T`.
+///
+/// The body is first parsed into a [`Value`]. Whenever deserializing that value into `T`
+/// fails, the offending field is removed (or the offending element, inside an array) and
+/// deserialization is retried, so optional fields fall back to [`None`]. Fields the model
+/// requires cannot be recovered this way.
+///
+/// # Parameters
+///
+/// - [`body`]: The raw response body.
+///
+/// # Returns
+///
+/// - `Ok((T, Vec<String>))`: The deserialized data and the paths of the removed fields, in
+///   the order they were removed.
+/// - `Err(ApiClientError::EmptyResponse)`: If nothing remains after trimming.
+/// - `Err(ApiClientError::Schema)`: If a required field does not match `T`; `value` holds the
+///   raw value found there.
+/// - `Err(ApiClientError::Deserialization)`: If the trimmed body is not valid JSON.
+pub fn parse_body_lenient<T: DeserializeOwned>(
+    body: &str,
+) -> Result<(T, Vec<String>), ApiClientError> {
+    let mut value: Value = parse_body(body)?;
+    let mut dropped = Vec::new();
+
+    loop {
+        let err = match serde_path_to_error::deserialize::<_, T>(&value) {
+            Ok(data) => return Ok((data, dropped)),
+            Err(err) => err,
+        };
+
+        let path = err.path().clone();
+        let source = err.into_inner();
+        let path_str = path.to_string();
+        if !source.is_data() || dropped.contains(&path_str) || !remove_at(&mut value, &path) {
+            return Err(ApiClientError::Schema {
+                value: value_at(&value, &path).cloned(),
+                path: path_str,
+                url: None,
+                source,
+            });
+        }
+        dropped.push(path_str);
+    }
+}
+
+/// Removes the value at a deserialization error path from its parent object or array.
+///
+/// Returns `false` if the path cannot be followed or points at the root.
+fn remove_at(root: &mut Value, path: &serde_path_to_error::Path) -> bool {
+    use serde_path_to_error::Segment;
+
+    let segments: Vec<&Segment> = path.iter().collect();
+    let Some((last, parents)) = segments.split_last() else {
+        return false;
+    };
+
+    let mut parent = root;
+    for segment in parents {
+        let next = match segment {
+            Segment::Seq { index } => parent.get_mut(*index),
+            Segment::Map { key } => parent.get_mut(key.as_str()),
+            Segment::Enum { variant } => parent.get_mut(variant.as_str()),
+            Segment::Unknown => None,
+        };
+        match next {
+            Some(value) => parent = value,
+            None => return false,
+        }
+    }
+
+    match (last, parent) {
+        (Segment::Seq { index }, Value::Array(items)) if *index < items.len() => {
+            items.remove(*index);
+            true
+        }
+        (Segment::Map { key }, Value::Object(map)) => map.remove(key.as_str()).is_some(),
+        _ => false,
+    }
+}
+
+/// Looks up the value at a deserialization error path, if the path can be followed.
+fn value_at<'a>(root: &'a Value, path: &serde_path_to_error::Path) -> Option<&'a Value> {
+    use serde_path_to_error::Segment;
+
+    path.iter().try_fold(root, |value, segment| match segment {
+        Segment::Seq { index } => value.get(index),
+        Segment::Map { key } => value.get(key),
+        Segment::Enum { variant } => value.get(variant),
+        Segment::Unknown => None,
+    })
+}
+
+/// Custom error type for [`CongressApiClient`].
+#[derive(Debug)]
+pub enum ApiClientError {
+    Http(reqwest::Error),
+    Url(String),
+    Deserialization(serde_json::Error),
+    /// The response is valid JSON but does not match the response model.
+    ///
+    /// `path` locates the offending field (e.g. `bills[0].number`) and `value` holds the
+    /// JSON found there, when it could be located. `url` is the request URL without the API
+    /// key, when the error was raised by a fetch.
+    Schema {
+        path: String,
+        value: Option<Value>,
+        url: Option<String>,
+        source: serde_json::Error,
+    },
+    /// No API key was passed and the named environment variable is not set.
+    EnvVar(String),
+    /// The API returned an empty body.
+    EmptyResponse,
+    /// The request parameters are invalid and the request was not sent.
+    InvalidParam(String),
+    /// The API rejected the API key (`401` or `403`).
+    Unauthorized,
+    /// The requested resource does not exist (`404`).
+    NotFound,
+    /// The API key has exceeded its request quota (`429`). `retry_after` holds the delay
+    /// requested by the `Retry-After` header, if present, whether it was sent as seconds or
+    /// as an HTTP date.
+    RateLimited {
+        retry_after: Option<Duration>,
+    },
+    /// The API failed to process the request (`5xx`). `body` holds the start of the
+    /// response body, capped at [`MAX_ERROR_BODY_BYTES`].
+    ServerError {
+        status: u16,
+        body: String,
+    },
+    /// Any other non-success status code. `body` holds the start of the response body,
+    /// capped at [`MAX_ERROR_BODY_BYTES`].
+    Other {
+        status: u16,
+        body: String,
+    },
+    /// The budget set with [`CongressApiClient::with_resource_budget`] for `resource` is
+    /// spent, and the request was not sent.
+    BudgetExceeded {
+        resource: String,
+        max_per_hour: u32,
+    },
+}
+
+/// Maximum number of bytes of an error response body kept in an [`ApiClientError`].
+///
+/// Outage pages can be megabytes of HTML; capping them keeps error values and logs small.
+pub const MAX_ERROR_BODY_BYTES: usize = 8 * 1024;
+
+/// Caps `body` at [`MAX_ERROR_BODY_BYTES`], appending a marker with the number of bytes
+/// dropped. The cut never splits a UTF-8 character.
+pub fn truncate_error_body(mut body: String) -> String {
+    if body.len() <= MAX_ERROR_BODY_BYTES {
+        return body;
+    }
+
+    let mut end = MAX_ERROR_BODY_BYTES;
+    while !body.is_char_boundary(end) {
+        end -= 1;
+    }
+    let dropped = body.len() - end;
+    body.truncate(end);
+    body.push_str(&format!("... [truncated {} bytes]", dropped));
+    body
+}
+
+impl ApiClientError {
+    /// Maps a non-success HTTP status code to the matching [`ApiClientError`] variant.
+    ///
+    /// # Parameters
+    ///
+    /// - [`status`]: The status code returned by the API.
+    pub fn from_status(status: reqwest::StatusCode) -> Self {
+        match status.as_u16() {
+            401 | 403 => ApiClientError::Unauthorized,
+            404 => ApiClientError::NotFound,
+            429 => ApiClientError::RateLimited { retry_after: None },
+            status @ 500..=599 => ApiClientError::ServerError {
+                status,
+                body: String::new(),
+            },
+            status => ApiClientError::Other {
+                status,
+                body: String::new(),
+            },
+        }
+    }
+
+    /// Attaches the response body, capped with [`truncate_error_body`], to the variants that
+    /// carry one. Other variants are returned unchanged.
+    pub fn with_body(self, body: String) -> Self {
+        match self {
+            ApiClientError::ServerError { status, .. } => ApiClientError::ServerError {
+                status,
+                body: truncate_error_body(body),
+            },
+            ApiClientError::Other { status, .. } => ApiClientError::Other {
+                status,
+                body: truncate_error_body(body),
+            },
+            err => err,
+        }
+    }
+
+    /// Attaches the request URL to [`ApiClientError::Schema`] errors. Other variants are
+    /// returned unchanged.
+    pub fn with_url(self, url: String) -> Self {
+        match self {
+            ApiClientError::Schema {
+                path,
+                value,
+                source,
+                ..
+            } => ApiClientError::Schema {
+                path,
+                value,
+                url: Some(url),
+                source,
+            },
+            err => err,
+        }
+    }
+
+    /// Maps a non-success response to the matching [`ApiClientError`] variant, reading the
+    /// `Retry-After` header for rate-limited responses.
+    ///
+    /// `Retry-After` may be a number of seconds or an HTTP date; a date already in the past
+    /// means no delay. Any other value is ignored.
+    ///
+    /// # Parameters
+    ///
+    /// - [`response`]: The response returned by the API.
+    pub fn from_response(response: &Response) -> Self {
+        match ApiClientError::from_status(response.status()) {
+            ApiClientError::RateLimited { .. } => ApiClientError::RateLimited {
+                retry_after: response
+                    .headers()
+                    .get(reqwest::header::RETRY_AFTER)
+                    .and_then(|value| value.to_str().ok())
+                    .and_then(parse_retry_after),
+            },
+            err => err,
+        }
+    }
+}
+
+/// Parses a `Retry-After` value, either delay seconds or an HTTP date.
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = parse_http_date(value)?;
+    Some(
+        date.duration_since(SystemTime::now())
+            .unwrap_or(Duration::ZERO),
+    )
+}
+
+/// Parses an HTTP date in the IMF-fixdate format servers are required to send, e.g.
+/// `Sun, 06 Nov 1994 08:49:37 GMT`. The obsolete RFC 850 and asctime formats are not accepted.
+fn parse_http_date(value: &str) -> Option<SystemTime> {
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+
+    let (_, date) = value.split_once(", ")?;
+    let mut parts = date.split(' ');
+    let day: u64 = parts.next()?.parse().ok()?;
+    let month_name = parts.next()?;
+    let month = MONTHS.iter().position(|name| *name == month_name)? as u64 + 1;
+    let year: u64 = parts.next()?.parse().ok()?;
+    let mut time = parts
+        .next()?
+        .split(':')
+        .map(|part| part.parse::<u64>().ok());
+    let (hour, minute, second) = (time.next()??, time.next()??, time.next()??);
+    if parts.next()? != "GMT" || parts.next().is_some() || time.next().is_some() {
+        return None;
+    }
+    if !(1..=31).contains(&day) || year < 1970 || hour > 23 || minute > 59 || second > 60 {
+        return None;
+    }
+
+    // Days since the Unix epoch, counting years from March so the leap day comes last.
+    let (year, month) = if month <= 2 {
+        (year - 1, month + 9)
+    } else {
+        (year, month - 3)
+    };
+    let (era, year_of_era) = (year / 400, year % 400);
+    let day_of_year = (153 * month + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146_097 + day_of_era - 719_468;
+
+    Some(UNIX_EPOCH + Duration::from_secs(days * 86_400 + hour * 3_600 + minute * 60 + second))
+}
+
+impl fmt::Display for ApiClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ApiClientError::Http(e) => write!(f, "HTTP error: {}", e),
+            ApiClientError::Url(e) => write!(f, "URL error: {}", e),
+            ApiClientError::Deserialization(e) => write!(f, "Deserialization error: {}", e),
+            ApiClientError::Schema {
+                path,
+                value,
+                url,
+                source,
+            } => {
+                write!(f, "Schema error at `{}`", path)?;
+                if let Some(value) = value {
+                    write!(f, " (found {})", value)?;
+                }
+                write!(f, ": {}", source)?;
+                if let Some(url) = url {
+                    write!(f, " while fetching {}", url)?;
+                }
+                Ok(())
+            }
+            ApiClientError::EnvVar(var) => write!(
+                f,
+                "Missing API key: set {} or pass a key to CongressApiClient::new",
+                var
+            ),
+            ApiClientError::EmptyResponse => write!(f, "Empty response: the API returned no data"),
+            ApiClientError::InvalidParam(reason) => write!(f, "Invalid parameter: {}", reason),
+            ApiClientError::Unauthorized => write!(f, "Unauthorized: the API key was rejected"),
+            ApiClientError::NotFound => {
+                write!(f, "Not found: the requested resource does not exist")
+            }
+            ApiClientError::RateLimited {
+                retry_after: Some(delay),
+            } => write!(
+                f,
+                "Rate limited: hourly quota exceeded; retry after {} seconds",
+                delay.as_secs()
+            ),
+            ApiClientError::RateLimited { retry_after: None } => {
+                write!(f, "Rate limited: hourly quota exceeded; retry later")
+            }
+            ApiClientError::ServerError { status, .. } => {
+                write!(f, "Server error: status {}", status)
+            }
+            ApiClientError::Other { status, .. } => write!(f, "Unexpected status: {}", status),
+            ApiClientError::BudgetExceeded {
+                resource,
+                max_per_hour,
+            } => write!(
+                f,
+                "Budget exceeded: {} requests per hour for `{}` already sent",
+                max_per_hour, resource
+            ),
+        }
+    }
+}
+
+impl Error for ApiClientError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ApiClientError::Http(e) => Some(e),
+            ApiClientError::Deserialization(e) => Some(e),
+            ApiClientError::Schema { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Lets `?` turn a malformed treaty suffix into `ApiClientError::InvalidParam`, e.g. when
+/// parsing user input into a [`TreatySuffix`](crate::cdg_types::TreatySuffix) before building a treaty endpoint.
+impl From<InvalidTreatySuffix> for ApiClientError {
+    fn from(err: InvalidTreatySuffix) -> Self {
+        ApiClientError::InvalidParam(err.to_string())
+    }
 }
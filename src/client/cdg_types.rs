@@ -96,35 +96,61 @@ impl SortType {
 ///
 /// This enum categorizes bills based on their origin and nature within
 /// the legislative system.
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default)]
+///
+/// Serializes to the uppercase codes used in API responses (`"HR"`, `"HJRES"`) and also
+/// deserializes from the lowercase URL tokens returned by [`BillType::to_string`] and from the
+/// variant names (`"Hr"`, `"Hjres"`) earlier releases serialized.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
 pub enum BillType {
     /// House Resolution ([`hr`]).
     #[default]
+    #[serde(rename = "HR", alias = "hr", alias = "Hr")]
     Hr, // House Resolution
 
     /// Senate Bill ([`s`]).
+    #[serde(rename = "S", alias = "s")]
     S, // Senate
 
     /// House Joint Resolution ([`hjres`]).
+    #[serde(rename = "HJRES", alias = "hjres", alias = "Hjres")]
     Hjres, // House Joint Resolution
 
     /// Senate Joint Resolution ([`sjres`]).
+    #[serde(rename = "SJRES", alias = "sjres", alias = "Sjres")]
     Sjres, // Senate Joint Resolution
 
     /// House Concurrent Resolution ([`hconres`]).
+    #[serde(rename = "HCONRES", alias = "hconres", alias = "Hconres")]
     Hconres, // House Concurrent Resolution
 
     /// Senate Concurrent Resolution ([`sconres`]).
+    #[serde(rename = "SCONRES", alias = "sconres", alias = "Sconres")]
     Sconres, // Senate Concurrent Resolution
 
     /// House Simple Resolution ([`hres`]).
+    #[serde(rename = "HRES", alias = "hres", alias = "Hres")]
     Hres, // House Simple Resolution
 
     /// Senate Simple Resolution ([`sres`]).
+    #[serde(rename = "SRES", alias = "sres", alias = "Sres")]
     Sres, // Senate Simple Resolution
 }
 
 impl BillType {
+    /// Returns every [`BillType`] variant, in declaration order.
+    pub fn all() -> &'static [BillType] {
+        &[
+            BillType::Hr,
+            BillType::S,
+            BillType::Hjres,
+            BillType::Sjres,
+            BillType::Hconres,
+            BillType::Sconres,
+            BillType::Hres,
+            BillType::Sres,
+        ]
+    }
+
     /// Converts the [`BillType`] variant to its lowercase string representation.
     ///
     /// # Returns
@@ -163,16 +189,23 @@ impl BillType {
 ///
 /// This enum categorizes amendments based on their origin within
 /// the legislative chambers.
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default)]
+///
+/// Serializes to the uppercase codes used in API responses (`"SAMDT"`) and also deserializes
+/// from the lowercase URL tokens returned by [`AmendmentType::to_string`] and from the variant
+/// names (`"Samdt"`) earlier releases serialized.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
 pub enum AmendmentType {
     /// House Amendment ([`hamdt`]).
+    #[serde(rename = "HAMDT", alias = "hamdt", alias = "Hamdt")]
     Hamdt, // House Amendment
 
     /// Senate Amendment ([`samdt`]).
     #[default]
+    #[serde(rename = "SAMDT", alias = "samdt", alias = "Samdt")]
     Samdt, // Senate Amendment
 
     /// Senate Unnumbered Amendment ([`suamdt`]).
+    #[serde(rename = "SUAMDT", alias = "suamdt", alias = "Suamdt")]
     Suamdt, // Senate Unnumbered Amendment
 }
 
@@ -195,19 +228,26 @@ impl AmendmentType {
 ///
 /// This enum distinguishes between the House, Senate, and Joint committees
 /// within the legislative body.
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default)]
+///
+/// Serializes to the capitalized names used in API responses (`"House"`, `"NoChamber"`) and
+/// also deserializes from the lowercase URL tokens returned by [`ChamberType::to_string`].
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
 pub enum ChamberType {
     /// House chamber.
+    #[serde(alias = "house")]
     House,
 
     /// Senate chamber.
     #[default]
+    #[serde(alias = "senate")]
     Senate,
 
     /// Joint chamber (both House and Senate).
+    #[serde(alias = "joint")]
     Joint,
 
     /// No chamber specified.
+    #[serde(alias = "nochamber", alias = "No Chamber")]
     NoChamber,
 }
 
@@ -225,6 +265,20 @@ impl ChamberType {
             ChamberType::NoChamber => "nochamber".to_string(),
         }
     }
+
+    /// Returns the capitalized name used in API responses, for display.
+    ///
+    /// # Returns
+    ///
+    /// A `&str` such as `"House"`, `"Senate"`, `"Joint"`, or `"NoChamber"`.
+    pub fn label(&self) -> &'static str {
+        match self {
+            ChamberType::House => "House",
+            ChamberType::Senate => "Senate",
+            ChamberType::Joint => "Joint",
+            ChamberType::NoChamber => "NoChamber",
+        }
+    }
 }
 
 /// Enum representing different types of communications handled by committees.
@@ -329,3 +383,179 @@ impl CommitteeReportType {
         }
     }
 }
+
+/// A validated treaty partition suffix, such as `A` or `B`.
+///
+/// The API expects the suffix of a partitioned treaty to be a single uppercase letter.
+/// Lowercase input is normalized; anything else is rejected when parsing. The suffixed treaty
+/// variants, and their constructors such as [`NewEndpoint::new_treaty_partitioned`], only hold a
+/// [`TreatySuffix`], so a malformed suffix never reaches the network.
+///
+/// [`NewEndpoint::new_treaty_partitioned`]: crate::endpoints::NewEndpoint::new_treaty_partitioned
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(try_from = "String", into = "String")]
+pub struct TreatySuffix(char);
+
+impl TreatySuffix {
+    /// Creates a new [`TreatySuffix`], normalizing a lowercase letter to uppercase.
+    ///
+    /// # Parameters
+    ///
+    /// - [`suffix`]: The suffix, e.g. `"A"` or `"a"`.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(TreatySuffix)`: If `suffix` is a single ASCII letter.
+    /// - `Err(InvalidTreatySuffix)`: Otherwise.
+    pub fn new(suffix: &str) -> Result<Self, InvalidTreatySuffix> {
+        let mut chars = suffix.trim().chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) if c.is_ascii_alphabetic() => Ok(Self(c.to_ascii_uppercase())),
+            _ => Err(InvalidTreatySuffix(suffix.to_string())),
+        }
+    }
+
+    /// Returns the suffix letter.
+    pub fn as_char(&self) -> char {
+        self.0
+    }
+}
+
+impl std::fmt::Display for TreatySuffix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::str::FromStr for TreatySuffix {
+    type Err = InvalidTreatySuffix;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl TryFrom<String> for TreatySuffix {
+    type Error = InvalidTreatySuffix;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(&value)
+    }
+}
+
+impl From<TreatySuffix> for String {
+    fn from(suffix: TreatySuffix) -> Self {
+        suffix.0.to_string()
+    }
+}
+
+/// Error returned when a treaty suffix is not a single letter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidTreatySuffix(pub String);
+
+impl std::fmt::Display for InvalidTreatySuffix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid treaty suffix {:?}: expected a single letter such as \"A\"",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for InvalidTreatySuffix {}
+
+/// The congress an endpoint refers to: a specific number, or the current congress.
+///
+/// Endpoint constructors take `impl Into<Congress>`, so a plain [`u32`] such as `118` works
+/// as well. [`CongressApiClient`] replaces [`Congress::Current`] with the number from
+/// `/congress/current` (looked up once, then cached) before sending a request; rendering an
+/// endpoint for the current congress without a client fails, see [`Endpoints::cache_key`].
+///
+/// It serializes like its [`Display`](std::fmt::Display) form: a number such as `118`, or the
+/// string `"current"`.
+///
+/// ```
+/// use cdg_api::cdg_types::Congress;
+/// use cdg_api::endpoints::{Endpoints, NewEndpoint};
+/// use cdg_api::param_models::LawParams;
+///
+/// let endpoint = Endpoints::new_law_by_congress(Congress::Current, LawParams::default());
+/// ```
+///
+/// [`CongressApiClient`]: crate::CongressApiClient
+/// [`Endpoints::cache_key`]: crate::endpoints::Endpoints::cache_key
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "CongressRepr", into = "CongressRepr")]
+pub enum Congress {
+    /// A specific congress, e.g. `118`.
+    Number(u32),
+    /// The current congress, resolved by the client.
+    Current,
+}
+
+impl Congress {
+    /// Returns the congress number, or [`None`] for an unresolved [`Congress::Current`].
+    pub fn number(&self) -> Option<u32> {
+        match self {
+            Congress::Number(number) => Some(*number),
+            Congress::Current => None,
+        }
+    }
+}
+
+impl From<u32> for Congress {
+    fn from(number: u32) -> Self {
+        Congress::Number(number)
+    }
+}
+
+/// Renders the number, or `current` for [`Congress::Current`].
+impl std::fmt::Display for Congress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Congress::Number(number) => write!(f, "{}", number),
+            Congress::Current => write!(f, "current"),
+        }
+    }
+}
+
+/// Parses a congress number, or `current` for [`Congress::Current`].
+impl std::str::FromStr for Congress {
+    type Err = std::num::ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "current" => Ok(Congress::Current),
+            number => number.parse().map(Congress::Number),
+        }
+    }
+}
+
+/// The serialized form of a [`Congress`]: a number, or the string `"current"`.
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum CongressRepr {
+    Number(u32),
+    Text(String),
+}
+
+impl From<Congress> for CongressRepr {
+    fn from(congress: Congress) -> Self {
+        match congress {
+            Congress::Number(number) => CongressRepr::Number(number),
+            Congress::Current => CongressRepr::Text(congress.to_string()),
+        }
+    }
+}
+
+impl TryFrom<CongressRepr> for Congress {
+    type Error = std::num::ParseIntError;
+
+    fn try_from(repr: CongressRepr) -> Result<Self, Self::Error> {
+        match repr {
+            CongressRepr::Number(number) => Ok(Congress::Number(number)),
+            CongressRepr::Text(text) => text.parse(),
+        }
+    }
+}
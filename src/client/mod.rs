@@ -1,3 +1,7 @@
+pub mod cache;
 pub mod cdg_client;
 pub mod cdg_types;
+pub mod pagination;
+pub mod rate_limit;
+pub mod retry;
 pub mod url_builders;
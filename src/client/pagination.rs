@@ -0,0 +1,77 @@
+//! # `pagination` Module
+//!
+//! This module provides [`paginate`], the offset arithmetic behind
+//! [`CongressApiClient::fetch_all`], kept independent of the network so it can be verified
+//! against the API's paging contract.
+//!
+//! ## Offset Semantics
+//!
+//! The congress.gov `offset` parameter is **0-based**: it is the number of records to skip.
+//! With a page limit of `250`, the first page is requested with `offset=0`, the second with
+//! `offset=250`, and so on. Each subsequent offset advances by the number of records actually
+//! received, so no record is skipped or duplicated.
+//!
+//! [`CongressApiClient::fetch_all`]: crate::CongressApiClient::fetch_all
+
+use std::time::Duration;
+
+/// Maximum number of items allocated for before the first page arrives.
+const MAX_PREALLOCATED_ITEMS: usize = 1000;
+
+/// Progress of a multi-page fetch, reported after every page to the hook set with
+/// [`CongressApiClient::on_page`](crate::CongressApiClient::on_page).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageStats {
+    /// The 0-based offset the page was requested with.
+    pub offset: usize,
+
+    /// The number of items on the page.
+    pub fetched: usize,
+
+    /// The time since the first page was requested.
+    pub elapsed: Duration,
+
+    /// The number of items received so far, including this page.
+    pub total_so_far: usize,
+}
+
+/// Collects up to `max` items by requesting consecutive pages from `fetch_page`.
+///
+/// # Parameters
+///
+/// - [`fetch_page`]: Called with the 0-based `offset` and the page `limit`; returns the items
+///   of that page.
+/// - [`max`]: The maximum number of items to collect.
+/// - [`page_limit`]: The number of items requested per page.
+///
+/// # Returns
+///
+/// - `Ok(Vec<U>)`: The collected items, at most `max`. Paging stops early when a page holds
+///   fewer than `page_limit` items.
+/// - [`Err`]: The first error returned by `fetch_page`.
+pub fn paginate<U, E, F>(mut fetch_page: F, max: usize, page_limit: usize) -> Result<Vec<U>, E>
+where
+    F: FnMut(usize, usize) -> Result<Vec<U>, E>,
+{
+    // Reserve room for up to four full pages (1000 items at the API's 250-item page limit)
+    // up front. Larger crawls grow one page at a time below, so a huge `max` never
+    // allocates memory that a short result set would leave unused.
+    let mut all_items = Vec::with_capacity(max.min(MAX_PREALLOCATED_ITEMS));
+    let mut offset = 0;
+
+    while all_items.len() < max {
+        let items = fetch_page(offset, page_limit)?;
+        let fetched_count = items.len();
+        all_items.reserve(fetched_count.min(max - all_items.len()));
+        all_items.extend(items);
+
+        if fetched_count < page_limit {
+            break;
+        }
+
+        offset += fetched_count;
+    }
+
+    all_items.truncate(max);
+    Ok(all_items)
+}
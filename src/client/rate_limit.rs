@@ -0,0 +1,159 @@
+//! # `rate_limit` Module
+//!
+//! This module defines [`RateLimiter`], a token bucket that paces the requests of one or
+//! more [`CongressApiClient`] instances. congress.gov enforces its quota per API key, so
+//! every client using the same key should share a single limiter behind an [`Arc`].
+//!
+//! It also holds the per-resource budgets set with
+//! [`CongressApiClient::with_resource_budget`](crate::CongressApiClient::with_resource_budget),
+//! which reserve parts of the quota for different kinds of requests.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use cdg_api::CongressApiClient;
+//! use cdg_api::rate_limit::RateLimiter;
+//! use std::sync::Arc;
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let limiter = Arc::new(RateLimiter::per_hour(5000));
+//!     let bills = CongressApiClient::new(Some("YOUR_API_KEY".to_string()))?
+//!         .with_rate_limiter(Arc::clone(&limiter));
+//!     let members = CongressApiClient::new(Some("YOUR_API_KEY".to_string()))?
+//!         .with_rate_limiter(limiter);
+//!     Ok(())
+//! }
+//! ```
+//!
+//! [`CongressApiClient`]: crate::CongressApiClient
+//! [`Arc`]: std::sync::Arc
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// A token bucket shared by the clients that use one API key.
+///
+/// The bucket starts full with `capacity` tokens and refills evenly over `period`. Every
+/// request attempt takes one token; when none is left, the request waits until one has
+/// been refilled. Waits are reserved in order, so concurrent callers queue up instead of
+/// all sending at once.
+#[derive(Debug)]
+pub struct RateLimiter {
+    capacity: f64,
+    refill: Duration,
+    bucket: Mutex<Bucket>,
+}
+
+/// The mutable state of a [`RateLimiter`].
+#[derive(Debug)]
+struct Bucket {
+    /// Available tokens; negative while requests are waiting for a refill.
+    tokens: f64,
+    updated: Instant,
+}
+
+impl RateLimiter {
+    /// Creates a new [`RateLimiter`] allowing `capacity` requests per `period`.
+    ///
+    /// # Parameters
+    ///
+    /// - [`capacity`]: The number of requests allowed per period, and the largest burst.
+    /// - [`period`]: The time over which the bucket refills completely.
+    ///
+    /// # Panics
+    ///
+    /// If `capacity` is zero.
+    pub fn new(capacity: u32, period: Duration) -> Self {
+        assert!(capacity > 0, "RateLimiter capacity must be at least 1");
+        Self {
+            capacity: f64::from(capacity),
+            refill: period / capacity,
+            bucket: Mutex::new(Bucket {
+                tokens: f64::from(capacity),
+                updated: Instant::now(),
+            }),
+        }
+    }
+
+    /// Creates a new [`RateLimiter`] allowing `requests` per hour, the unit of the
+    /// congress.gov quota.
+    pub fn per_hour(requests: u32) -> Self {
+        Self::new(requests, Duration::from_secs(3600))
+    }
+
+    /// Takes a token, returning how long the caller must wait before sending its request.
+    ///
+    /// # Returns
+    ///
+    /// [`Duration::ZERO`] if a token was available, otherwise the time until the reserved
+    /// token is refilled.
+    pub fn acquire(&self) -> Duration {
+        let mut bucket = self
+            .bucket
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        let now = Instant::now();
+        let refilled = now.duration_since(bucket.updated).as_secs_f64() / self.refill.as_secs_f64();
+        bucket.tokens = (bucket.tokens + refilled).min(self.capacity);
+        bucket.updated = now;
+        bucket.tokens -= 1.0;
+
+        if bucket.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            self.refill.mul_f64(-bucket.tokens)
+        }
+    }
+}
+
+/// A cap on the requests sent for one resource within a sliding window.
+///
+/// Unlike [`RateLimiter`], an exhausted budget does not wait: the request is refused, so
+/// the caller can move on to other work.
+#[derive(Debug)]
+pub(crate) struct ResourceBudget {
+    max: u32,
+    period: Duration,
+    /// When each request still inside the window was sent, oldest first.
+    sent: Mutex<VecDeque<Instant>>,
+}
+
+impl ResourceBudget {
+    /// Creates a new [`ResourceBudget`] allowing `max` requests per hour.
+    pub(crate) fn per_hour(max: u32) -> Self {
+        Self {
+            max,
+            period: Duration::from_secs(3600),
+            sent: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// Returns the number of requests allowed per hour.
+    pub(crate) fn max(&self) -> u32 {
+        self.max
+    }
+
+    /// Counts a request against the budget, returning `false` without counting it if the
+    /// budget is spent.
+    pub(crate) fn try_take(&self) -> bool {
+        let mut sent = self
+            .sent
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        let now = Instant::now();
+        while sent
+            .front()
+            .is_some_and(|&at| now.duration_since(at) >= self.period)
+        {
+            sent.pop_front();
+        }
+        if sent.len() >= self.max as usize {
+            return false;
+        }
+        sent.push_back(now);
+        true
+    }
+}
@@ -0,0 +1,135 @@
+//! # `retry` Module
+//!
+//! This module defines the [`RetryPolicy`] trait, which the [`CongressApiClient`] consults
+//! whenever a request fails, along with [`ExponentialBackoff`], a ready-made policy that
+//! retries transient failures with an exponentially growing delay.
+//!
+//! The delay between attempts is applied by a [`Sleeper`]. The client uses [`ThreadSleeper`]
+//! by default; tests can inject their own to record delays instead of waiting.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use cdg_api::CongressApiClient;
+//! use cdg_api::retry::ExponentialBackoff;
+//! use std::time::Duration;
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let client = CongressApiClient::new(Some("YOUR_API_KEY".to_string()))?
+//!         .retry_policy(ExponentialBackoff::new(3, Duration::from_millis(500)));
+//!     Ok(())
+//! }
+//! ```
+//!
+//! [`CongressApiClient`]: crate::CongressApiClient
+
+use crate::client::cdg_client::ApiClientError;
+use std::time::Duration;
+
+/// Decides whether a failed request should be retried, and after how long.
+///
+/// Implement this trait to retry only specific failures, honor the `Retry-After`
+/// header reported through [`ApiClientError::RateLimited`], or apply any other custom
+/// strategy without changing the client.
+pub trait RetryPolicy: Send + Sync {
+    /// Called after a request fails.
+    ///
+    /// # Parameters
+    ///
+    /// - [`attempt`]: The number of attempts made so far, starting at `1`.
+    /// - [`err`]: The error returned by the latest attempt.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(Duration)`: Retry the request after waiting for the given delay.
+    /// - [`None`]: Give up and return `err` to the caller.
+    fn should_retry(&self, attempt: u32, err: &ApiClientError) -> Option<Duration>;
+}
+
+/// Retries transient failures with an exponentially growing delay.
+///
+/// Rate limits, server errors (`5xx`), and transport errors are retried; every other
+/// error is returned immediately. When the API supplies a `Retry-After` delay it is used
+/// instead of the computed backoff; a `Retry-After` longer than [`max_delay`] is never
+/// shortened, the rate limit error is returned instead.
+///
+/// [`max_delay`]: ExponentialBackoff::max_delay
+#[derive(Debug, Clone, Copy)]
+pub struct ExponentialBackoff {
+    /// Maximum number of retries after the initial attempt.
+    pub max_retries: u32,
+
+    /// Delay before the first retry, doubled for every subsequent retry.
+    pub base_delay: Duration,
+
+    /// Upper bound for any single delay. The computed backoff is capped at this value, and a
+    /// longer `Retry-After` ends the retries.
+    pub max_delay: Duration,
+}
+
+impl ExponentialBackoff {
+    /// Creates a new [`ExponentialBackoff`] policy with a maximum delay of 60 seconds.
+    ///
+    /// # Parameters
+    ///
+    /// - [`max_retries`]: Maximum number of retries after the initial attempt.
+    /// - [`base_delay`]: Delay before the first retry.
+    pub fn new(max_retries: u32, base_delay: Duration) -> Self {
+        Self {
+            max_retries,
+            base_delay,
+            max_delay: Duration::from_secs(60),
+        }
+    }
+
+    /// Sets the upper bound for any single delay.
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+}
+
+impl Default for ExponentialBackoff {
+    fn default() -> Self {
+        Self::new(3, Duration::from_millis(500))
+    }
+}
+
+impl RetryPolicy for ExponentialBackoff {
+    fn should_retry(&self, attempt: u32, err: &ApiClientError) -> Option<Duration> {
+        if attempt > self.max_retries {
+            return None;
+        }
+
+        match err {
+            ApiClientError::RateLimited {
+                retry_after: Some(delay),
+            } => (*delay <= self.max_delay).then_some(*delay),
+            ApiClientError::RateLimited { retry_after: None }
+            | ApiClientError::ServerError { .. }
+            | ApiClientError::Http(_) => {
+                let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+                Some(self.base_delay.saturating_mul(factor).min(self.max_delay))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Waits between retry attempts.
+///
+/// Implement this trait to replace real sleeping, e.g. with a no-op or a recorder in tests.
+pub trait Sleeper: Send + Sync {
+    /// Blocks for the given delay.
+    fn sleep(&self, delay: Duration);
+}
+
+/// The default [`Sleeper`], which blocks the current thread with [`std::thread::sleep`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ThreadSleeper;
+
+impl Sleeper for ThreadSleeper {
+    fn sleep(&self, delay: Duration) {
+        std::thread::sleep(delay);
+    }
+}
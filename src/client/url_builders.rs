@@ -39,10 +39,10 @@
 //!
 //!     let endpoint = Endpoints::BillList(params);
 //!     let api_key = "YOUR_API_KEY";
-//!     let url = generate_url(endpoint, api_key);
+//!     let url = generate_url(endpoint, api_key).expect("the endpoint has no congress to resolve");
 //!
 //!     println!("URL: {}", url);
-//!     // Output: https://api.congress.gov/v3/bill/?format=json&limit=10&fromDateTime=2023-01-01&toDateTime=2023-12-31&sort=updateDateDesc&api_key=YOUR_API_KEY
+//!     // Output: https://api.congress.gov/v3/bill?format=json&limit=10&fromDateTime=2023-01-01&toDateTime=2023-12-31&sort=updateDateDesc&api_key=YOUR_API_KEY
 //! }
 //! ```
 //!
@@ -52,8 +52,12 @@
 //! - **Extensibility**: Easily add new endpoints by implementing [`ApiParams`] for new parameter structs.
 //! - **Convenience**: Simplifies URL construction using Rust's formatting capabilities.
 
+use crate::cdg_types::{Congress, FormatType, SortType};
+use crate::client::cdg_client::ApiClientError;
 use crate::endpoints::Endpoints;
 use crate::param_models::*;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
 use std::fmt::Display;
 
 /// Called by the api client to generate the complete URL for the request.
@@ -61,17 +65,222 @@ use std::fmt::Display;
 /// as the separator for the query parameters.
 ///
 /// Otherwise, the URL is generated with '?' as the separator for the query parameters.
+/// If the endpoint ends with a bare '?' (params that set no field), no separator is added.
 /// The API key is appended to the URL as a query parameter.
 ///
 /// This works due to the [`Display`] implementation for the [`Endpoints`] enum, as well as
 /// the [`Display`] implementation for the [`ApiParam`] structs. These both convert the existing
 /// data into a query string.
-pub fn generate_url(endpoint: Endpoints, api_key: &str) -> String {
-    if endpoint.to_string().contains("?") {
-        format!("{}{}&api_key={}", crate::BASE_URL, endpoint, api_key)
-    } else {
-        format!("{}{}?api_key={}", crate::BASE_URL, endpoint, api_key)
+///
+/// # Returns
+///
+/// - `Ok(String)`: The request URL.
+/// - `Err(ApiClientError::InvalidParam)`: If the endpoint is for [`Congress::Current`]; see
+///   [`Endpoints::cache_key`].
+pub fn generate_url(endpoint: Endpoints, api_key: &str) -> Result<String, ApiClientError> {
+    // Render the endpoint once and append the borrowed key to it; the key is never cloned.
+    let mut url = endpoint.cache_key()?;
+    // Params that set nothing still render a bare `?`, which already separates the key.
+    if !url.ends_with(['?', '&']) {
+        url.push(if url.contains('?') { '&' } else { '?' });
     }
+    url.push_str("api_key=");
+    url.push_str(api_key);
+    Ok(url)
+}
+
+/// Sets a query parameter on a complete URL, replacing any existing value for the same key.
+///
+/// Used by the api client when a request needs to override the parameters supplied by the
+/// caller, e.g. forcing `limit=1` when only the result count is needed.
+pub(crate) fn set_query_param(url: &str, key: &str, value: &str) -> String {
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+    let mut query_params: Vec<&str> = query
+        .split('&')
+        .filter(|param| !param.is_empty())
+        .filter(|param| param.split('=').next() != Some(key))
+        .collect();
+
+    let param = format!("{}={}", key, value);
+    query_params.push(&param);
+    format!("{}?{}", path, query_params.join("&"))
+}
+
+/// Sets a query parameter on a complete URL unless the URL already has a value for the key,
+/// so applying it repeatedly never duplicates the parameter.
+pub(crate) fn set_default_query_param(url: &str, key: &str, value: &str) -> String {
+    match query_param(url, key) {
+        Some(_) => url.to_string(),
+        None => set_query_param(url, key, value),
+    }
+}
+
+/// Returns the value of a query parameter in a URL, if present.
+pub(crate) fn query_param<'a>(url: &'a str, key: &str) -> Option<&'a str> {
+    let (_, query) = url.split_once('?')?;
+    query
+        .split('&')
+        .filter_map(|param| param.split_once('='))
+        .find(|(name, _)| *name == key)
+        .map(|(_, value)| value)
+}
+
+impl Endpoints {
+    /// Returns a key suitable for caching the response of this endpoint.
+    ///
+    /// The key is the complete request URL *without* the API key, so entries stay
+    /// stable regardless of which key made the request and the key never ends up
+    /// stored alongside cached data. Clients using different keys can share a cache.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(String)`: A key such as `https://api.congress.gov/v3/bill?format=json&limit=10`.
+    /// - `Err(ApiClientError::InvalidParam)`: If the endpoint is for [`Congress::Current`],
+    ///   which has no URL until a client replaces it with the current congress number. Use
+    ///   [`CongressApiClient::plan_urls`](crate::CongressApiClient::plan_urls) to render such
+    ///   endpoints.
+    pub fn cache_key(&self) -> Result<String, ApiClientError> {
+        if self.congress() == Some(Congress::Current) {
+            return Err(ApiClientError::InvalidParam(
+                "cannot render an endpoint for the current congress without resolving it; \
+                 fetch it with a client or use `CongressApiClient::plan_urls`"
+                    .to_string(),
+            ));
+        }
+        Ok(format!("{}{}", crate::BASE_URL, self))
+    }
+
+    /// Returns the HTTP method used to request this endpoint.
+    ///
+    /// Every congress.gov endpoint is currently read with `GET`; endpoints added later that
+    /// need another method override it here, and the client sends whatever this returns.
+    pub fn method(&self) -> Method {
+        Method::GET
+    }
+
+    /// Parses a congress.gov API URL, such as the `url` field of a response item, back into
+    /// an endpoint.
+    ///
+    /// Detail URLs for bills, amendments, members, congresses, committees, nominations, and
+    /// treaties map to their typed variants; any other path becomes
+    /// [`Endpoints::Generic`]. A missing `format` defaults to JSON, as for the params.
+    ///
+    /// # Parameters
+    ///
+    /// - [`url`]: A URL starting with [`BASE_URL`](crate::BASE_URL), e.g.
+    ///   `https://api.congress.gov/v3/bill/118/hr/3076?format=json`.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok((Endpoints, Option<String>))`: The endpoint and the `api_key` query parameter,
+    ///   if the URL carried one. The key is stripped from the endpoint.
+    /// - `Err(ApiClientError::InvalidParam)`: If the URL is not a congress.gov API URL, or
+    ///   has a query parameter that no endpoint model represents.
+    pub fn from_api_url(url: &str) -> Result<(Endpoints, Option<String>), ApiClientError> {
+        let path_and_query = url.strip_prefix(crate::BASE_URL).ok_or_else(|| {
+            ApiClientError::InvalidParam(format!("not a congress.gov API URL: {}", url))
+        })?;
+        let (path, query) = path_and_query
+            .split_once('?')
+            .unwrap_or((path_and_query, ""));
+        let path = path.trim_matches('/');
+
+        let mut api_key = None;
+        let mut params = GenericParams::default();
+        let mut format_only = true;
+        for (name, value) in query.split('&').filter_map(|param| param.split_once('=')) {
+            let invalid = || {
+                ApiClientError::InvalidParam(format!("invalid `{}` in {}: {}", name, url, value))
+            };
+            let number = || value.parse::<u32>().map_err(|_| invalid());
+
+            match name {
+                "api_key" => {
+                    api_key = Some(value.to_string());
+                    continue;
+                }
+                "format" => {
+                    params.format = Some(parse_token(value).ok_or_else(invalid)?);
+                    continue;
+                }
+                "offset" => params.offset = Some(number()?),
+                "limit" => params.limit = Some(number()?),
+                "fromDateTime" => params.from_date_time = Some(value.to_string()),
+                "toDateTime" => params.to_date_time = Some(value.to_string()),
+                "conference" => params.conference = Some(value.parse().map_err(|_| invalid())?),
+                "year" => params.year = Some(number()?),
+                "month" => params.month = Some(number()?),
+                "day" => params.day = Some(number()?),
+                "sort" => {
+                    params.sort = Some(match value {
+                        "updateDate+asc" | "asc" => SortType::UpdateDateAsc,
+                        "updateDate+desc" | "desc" => SortType::UpdateDateDesc,
+                        _ => return Err(invalid()),
+                    })
+                }
+                _ => {
+                    return Err(ApiClientError::InvalidParam(format!(
+                        "unsupported query parameter `{}` in {}",
+                        name, url
+                    )))
+                }
+            }
+            format_only = false;
+        }
+
+        let typed = format_only
+            .then(|| detail_endpoint(path, params.format))
+            .flatten();
+        let endpoint = typed.unwrap_or_else(|| Endpoints::Generic(path.to_string(), params));
+        Ok((endpoint, api_key))
+    }
+}
+
+/// Returns the typed detail endpoint for an API path, if it has one.
+fn detail_endpoint(path: &str, format: Option<FormatType>) -> Option<Endpoints> {
+    let segments: Vec<&str> = path.split('/').collect();
+    let endpoint = match segments.as_slice() {
+        ["bill", congress, bill_type, number] => Endpoints::BillDetails(
+            congress.parse().ok()?,
+            parse_token(bill_type)?,
+            number.parse().ok()?,
+            BillDetailsParams { format },
+        ),
+        ["amendment", congress, amendment_type, number] => Endpoints::AmendmentDetails(
+            congress.parse().ok()?,
+            parse_token(amendment_type)?,
+            number.parse().ok()?,
+            AmendmentDetailsParams { format },
+        ),
+        ["member", bioguide_id] => {
+            Endpoints::MemberDetails(bioguide_id.to_string(), MemberDetailsParams { format })
+        }
+        ["congress", congress] => {
+            Endpoints::CongressDetails(congress.parse().ok()?, CongressDetailsParams { format })
+        }
+        ["committee", chamber, code] => Endpoints::CommitteeDetails(
+            parse_token(chamber)?,
+            code.to_string(),
+            CommitteeDetailsParams { format },
+        ),
+        ["nomination", congress, number] => Endpoints::NominationDetails(
+            congress.parse().ok()?,
+            number.to_string(),
+            NominationDetailsParams { format },
+        ),
+        ["treaty", congress, number] => Endpoints::TreatyDetails(
+            congress.parse().ok()?,
+            number.parse().ok()?,
+            TreatyDetailsParams { format },
+        ),
+        _ => return None,
+    };
+    Some(endpoint)
+}
+
+/// Parses a lowercase URL token such as `hr` or `json` into its enum.
+fn parse_token<T: DeserializeOwned>(token: &str) -> Option<T> {
+    serde_json::from_value(serde_json::Value::String(token.to_string())).ok()
 }
 
 /// Implementation of the [`Display`] trait for the [`Endpoints`] enum.
@@ -85,19 +294,25 @@ pub fn generate_url(endpoint: Endpoints, api_key: &str) -> String {
 /// If we have an endpoint like `Endpoints::BillList(params)`, where `params`
 /// is a [`BillListParams`] struct with [`format`] set to `FormatType::Json` and `limit`
 /// set to [`10`], the URL string would look like:
-/// `bill/?format=json&limit=10`
+/// `bill?format=json&limit=10`
 impl std::fmt::Display for Endpoints {
+    #[allow(deprecated)]
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         // NOTE: A '?' is appended to the params string via the `Display`
         // implementation for the [`ApiParam`] structs.
+        //
+        // Paths never end with a slash, matching the paths documented by congress.gov.
         match self {
-            Endpoints::Generic(endpoint, params) => {
-                write!(f, "{}/{}", endpoint, params.to_query_string())
-            }
+            Endpoints::Generic(endpoint, params) => write!(
+                f,
+                "{}{}",
+                endpoint.trim_matches('/'),
+                params.to_query_string()
+            ),
             // ================================
             // Bill Endpoints
             // ================================
-            Endpoints::BillList(params) => write!(f, "bill/?{}", params.to_query_string()),
+            Endpoints::BillList(params) => write!(f, "bill{}", params.to_query_string()),
             Endpoints::BillByCongress(congress, params) => {
                 write!(f, "bill/{}{}", congress, params.to_query_string())
             }
@@ -183,7 +398,7 @@ impl std::fmt::Display for Endpoints {
             Endpoints::BillSummaries(congress, bill_type, bill_number, params) => {
                 write!(
                     f,
-                    "bill/{}/{}/{}/summary{}",
+                    "bill/{}/{}/{}/summaries{}",
                     congress,
                     bill_type.to_string(),
                     bill_number,
@@ -307,14 +522,14 @@ impl std::fmt::Display for Endpoints {
             // ================================
             // Summaries Endpoints
             // ================================
-            Endpoints::SummariesList(params) => write!(f, "summary{}", params.to_query_string()),
+            Endpoints::SummariesList(params) => write!(f, "summaries{}", params.to_query_string()),
             Endpoints::SummariesByCongress(congress, params) => {
-                write!(f, "summary/{}{}", congress, params.to_query_string())
+                write!(f, "summaries/{}{}", congress, params.to_query_string())
             }
             Endpoints::SummariesByType(congress, bill_type, params) => {
                 write!(
                     f,
-                    "summary/{}/{}{}",
+                    "summaries/{}/{}{}",
                     congress,
                     bill_type.to_string(),
                     params.to_query_string()
@@ -389,11 +604,11 @@ impl std::fmt::Display for Endpoints {
             // ================================
             // Committee Endpoints
             // ================================
-            Endpoints::CommitteeList(params) => write!(f, "committee?{}", params.to_query_string()),
+            Endpoints::CommitteeList(params) => write!(f, "committee{}", params.to_query_string()),
             Endpoints::CommitteeByChamber(chamber, params) => {
                 write!(
                     f,
-                    "committee/chamber/{}{}",
+                    "committee/{}{}",
                     chamber.to_string(),
                     params.to_query_string()
                 )
@@ -541,6 +756,16 @@ impl std::fmt::Display for Endpoints {
                     params.to_query_string()
                 )
             }
+            Endpoints::CommitteePrintDetails(congress, chamber, jacket_number, params) => {
+                write!(
+                    f,
+                    "committee-print/{}/{}/{}{}",
+                    congress,
+                    chamber.to_string(),
+                    jacket_number,
+                    params.to_query_string()
+                )
+            }
             Endpoints::CommitteePrintText(congress, chamber, jacket_number, params) => {
                 write!(
                     f,
@@ -578,7 +803,7 @@ impl std::fmt::Display for Endpoints {
             Endpoints::CommitteeMeetingByEvent(congress, chamber, event_id, params) => {
                 write!(
                     f,
-                    "committee-meeting/{}/{}/{}/{}",
+                    "committee-meeting/{}/{}/{}{}",
                     congress,
                     chamber.to_string(),
                     event_id,
@@ -646,7 +871,7 @@ impl std::fmt::Display for Endpoints {
             Endpoints::DailyCongressionalRecordArticles(volume, issue, params) => {
                 write!(
                     f,
-                    "daily-congressional-record/{}/{}{}",
+                    "daily-congressional-record/{}/{}/articles{}",
                     volume,
                     issue,
                     params.to_query_string()
@@ -779,7 +1004,7 @@ impl std::fmt::Display for Endpoints {
             Endpoints::HouseRequirementMatching(requirement_number, params) => {
                 write!(
                     f,
-                    "house-requirement/{}/matching{}",
+                    "house-requirement/{}/matching-communications{}",
                     requirement_number,
                     params.to_query_string()
                 )
@@ -1838,7 +2063,7 @@ impl ApiParams for CommitteeDetailsParams {
     /// A [`String`] containing the query parameter for committee details.
     fn to_query_string(&self) -> String {
         if let Some(format) = &self.format {
-            format.to_query_param()
+            format!("?{}", format.to_query_param())
         } else {
             "".to_string()
         }
@@ -2239,9 +2464,7 @@ impl ApiParams for TreatyListParams {
             query_params.push(sort.to_query_param());
         }
 
-        query_params.join("&");
-        query_params.insert(0, "?".to_string());
-        query_params.join("")
+        "?".to_string() + &query_params.join("&")
     }
 }
 
@@ -2296,7 +2519,7 @@ impl ApiParams for TreatyDetailsParams {
     /// A [`String`] containing the query parameter for treaty details.
     fn to_query_string(&self) -> String {
         if let Some(format) = &self.format {
-            format.to_query_param()
+            format!("?{}", format.to_query_param())
         } else {
             "".to_string()
         }
@@ -2393,6 +2616,26 @@ impl ApiParams for SummariesListParams {
             query_params.push(format.to_query_param());
         }
 
+        if let Some(offset) = &self.offset {
+            query_params.push(format!("offset={}", offset));
+        }
+
+        if let Some(limit) = &self.limit {
+            query_params.push(format!("limit={}", limit));
+        }
+
+        if let Some(from_date_time) = &self.from_date_time {
+            query_params.push(format!("fromDateTime={}", from_date_time));
+        }
+
+        if let Some(to_date_time) = &self.to_date_time {
+            query_params.push(format!("toDateTime={}", to_date_time));
+        }
+
+        if let Some(sort) = &self.sort {
+            query_params.push(sort.to_query_param());
+        }
+
         "?".to_string() + &query_params.join("&")
     }
 }
@@ -2413,19 +2656,40 @@ impl ApiParams for SummariesByCongressParams {
             query_params.push(format.to_query_param());
         }
 
+        if let Some(offset) = &self.offset {
+            query_params.push(format!("offset={}", offset));
+        }
+
+        if let Some(limit) = &self.limit {
+            query_params.push(format!("limit={}", limit));
+        }
+
+        if let Some(from_date_time) = &self.from_date_time {
+            query_params.push(format!("fromDateTime={}", from_date_time));
+        }
+
+        if let Some(to_date_time) = &self.to_date_time {
+            query_params.push(format!("toDateTime={}", to_date_time));
+        }
+
+        if let Some(sort) = &self.sort {
+            query_params.push(sort.to_query_param());
+        }
+
         "?".to_string() + &query_params.join("&")
     }
 }
 
-/// Implementation of [`ApiParams`] for [`SummariesByCongressChamberParams`].
+/// Implementation of [`ApiParams`] for [`SummariesByTypeParams`].
 ///
-/// Converts [`SummariesByCongressChamberParams`] into a query string suitable for the
+/// Converts [`SummariesByTypeParams`] into a query string suitable for the [`SummariesByType`]
+/// endpoint.
 impl ApiParams for SummariesByTypeParams {
-    /// Converts the [`SummariesByCongressChamberParams`] into a query string.
+    /// Converts the [`SummariesByTypeParams`] into a query string.
     ///
     /// # Returns
     ///
-    /// A [`String`] containing the query parameters for summaries by congress and chamber.
+    /// A [`String`] containing the query parameters for summaries by congress and bill type.
     fn to_query_string(&self) -> String {
         let mut query_params = vec![];
 
@@ -2433,6 +2697,26 @@ impl ApiParams for SummariesByTypeParams {
             query_params.push(format.to_query_param());
         }
 
+        if let Some(offset) = &self.offset {
+            query_params.push(format!("offset={}", offset));
+        }
+
+        if let Some(limit) = &self.limit {
+            query_params.push(format!("limit={}", limit));
+        }
+
+        if let Some(from_date_time) = &self.from_date_time {
+            query_params.push(format!("fromDateTime={}", from_date_time));
+        }
+
+        if let Some(to_date_time) = &self.to_date_time {
+            query_params.push(format!("toDateTime={}", to_date_time));
+        }
+
+        if let Some(sort) = &self.sort {
+            query_params.push(sort.to_query_param());
+        }
+
         "?".to_string() + &query_params.join("&")
     }
 }
@@ -3526,7 +3810,7 @@ impl ApiParams for GenericParams {
         }
 
         if let Some(sort) = &self.sort {
-            query_params.push(format!("sort={}", sort.to_string()));
+            query_params.push(sort.to_query_param());
         }
 
         "?".to_string() + &query_params.join("&")
@@ -4,15 +4,20 @@ pub mod client;
 pub mod requests;
 pub mod response;
 
-pub use client::{cdg_client::CongressApiClient, cdg_types, url_builders};
+pub use client::{
+    cache, cdg_client::CongressApiClient, cdg_types, pagination, rate_limit, retry, url_builders,
+};
 pub use request_handlers::get_congress_data;
 pub use requests::{endpoints, param_models};
 pub use response::response_models;
+#[cfg(feature = "streaming")]
+pub use response::streaming;
 
 /// The base URL for the US Congress API.
 pub const BASE_URL: &str = "https://api.congress.gov/v3/";
 
 /// Unwraps an `Option<String>` and returns the inner `String` or an empty string if `None`.
+#[deprecated(note = "use `unwrap_option`, which works for any `Default` type")]
 pub fn unwrap_option_string(opt: Option<String>) -> String {
     match opt {
         Some(s) => s,
@@ -21,6 +26,7 @@ pub fn unwrap_option_string(opt: Option<String>) -> String {
 }
 
 /// Unwraps an `Option<u32>` and returns the inner `u32` or 0 if `None`.
+#[deprecated(note = "use `unwrap_option`, which works for any `Default` type")]
 pub fn unwrap_option_u32(opt: Option<u32>) -> u32 {
     match opt {
         Some(i) => i,
@@ -36,6 +42,12 @@ pub fn unwrap_option<T: Default>(opt: Option<T>) -> T {
     }
 }
 
+/// Unwraps an `Option<String>` and returns the inner `String` or `"N/A"` if `None`, for
+/// displaying fields the API left out.
+pub fn unwrap_or_na(opt: Option<String>) -> String {
+    opt.unwrap_or_else(|| "N/A".to_string())
+}
+
 #[cfg(feature = "request_handlers")]
 pub mod request_handlers {
     //! # `request_handlers` Module
@@ -50,7 +62,7 @@ pub mod request_handlers {
     //! use cdg_api::response_models::BillsResponse;
     //!
     //! fn main() -> Result<(), Box<dyn std::error::Error>> {
-    //!     let url = "https://api.congress.gov/v3/bill/?format=json&limit=10&api_key=YOUR_API_KEY";
+    //!     let url = "https://api.congress.gov/v3/bill?format=json&limit=10&api_key=YOUR_API_KEY";
     //!     
     //!     // Fetch and deserialize data
     //!     // Note:
@@ -98,7 +110,8 @@ pub mod request_handlers {
     ) -> Result<T, Box<dyn std::error::Error>> {
         let client = Client::new();
         let response = client.get(url).send()?;
-        let data = response.json::<T>()?;
+        let body = response.text()?;
+        let data = crate::client::cdg_client::parse_body(&body)?;
         Ok(data)
     }
 
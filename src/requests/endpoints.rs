@@ -24,7 +24,7 @@
 //! }
 //! ```
 
-use crate::{cdg_types::*, param_models::*};
+use crate::{cdg_types::*, client::cdg_client::ApiClientError, param_models::*};
 use serde::{Deserialize, Serialize};
 
 /// Each variant of the [`Endpoints`] enum corresponds to a specific API endpoint,
@@ -33,6 +33,9 @@ use serde::{Deserialize, Serialize};
 pub enum Endpoints {
     /// Endpoint for manual API requests, where the user provides the entire endpoint.
     /// The base URL, and the API key are automatically appended to the provided endpoint.
+    ///
+    /// [`GenericParams`] request JSON by default, so the target type passed to `fetch` must
+    /// deserialize from JSON.
     Generic(String, GenericParams),
 
     // ================================
@@ -46,157 +49,157 @@ pub enum Endpoints {
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`BillByCongressParams`]: Additional parameters for filtering bills.
     /// /bill/{congress}
-    BillByCongress(u32, BillByCongressParams),
+    BillByCongress(Congress, BillByCongressParams),
 
     /// Endpoint to get bills filtered by type within a specific congress.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`BillType`]: The type of bill (e.g., House, Senate).
     /// - [`BillByTypeParams`]: Additional parameters for filtering bills by type.
     /// /bill/{congress}/{billType}
-    BillByType(u32, BillType, BillByTypeParams),
+    BillByType(Congress, BillType, BillByTypeParams),
 
     /// Endpoint to retrieve detailed information about a specific bill.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`BillType`]: The type of bill.
     /// - [`u32`]: The bill number.
     /// - [`BillDetailsParams`]: Additional parameters for bill details.
     /// /bill/{congress}/{billType}/{billNumber}
-    BillDetails(u32, BillType, u32, BillDetailsParams),
+    BillDetails(Congress, BillType, u32, BillDetailsParams),
 
     /// Endpoint to fetch actions taken on a specific bill.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`BillType`]: The type of bill.
     /// - [`u32`]: The bill number.
     /// - [`BillActionsParams`]: Additional parameters for bill actions.
     /// /bill/{congress}/{billType}/{billNumber}/actions
-    BillActions(u32, BillType, u32, BillActionsParams),
+    BillActions(Congress, BillType, u32, BillActionsParams),
 
     /// Endpoint to list amendments of a specific bill.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`BillType`]: The type of bill.
     /// - [`u32`]: The bill number.
     /// - [`BillAmendmentsParams`]: Additional parameters for bill amendments.
     /// /bill/{congress}/{billType}/{billNumber}/amendments
-    BillAmendments(u32, BillType, u32, BillAmendmentsParams),
+    BillAmendments(Congress, BillType, u32, BillAmendmentsParams),
 
     /// Endpoint to get committees associated with a specific bill.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`BillType`]: The type of bill.
     /// - [`u32`]: The bill number.
     /// - [`BillCommitteesParams`]: Additional parameters for bill committees.
     /// /bill/{congress}/{billType}/{billNumber}/committees
-    BillCommittees(u32, BillType, u32, BillCommitteesParams),
+    BillCommittees(Congress, BillType, u32, BillCommitteesParams),
 
     /// Endpoint to retrieve cosponsors of a specific bill.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`BillType`]: The type of bill.
     /// - [`u32`]: The bill number.
     /// - [`BillCosponsorsParams`]: Additional parameters for bill cosponsors.
     /// /bill/{congress}/{billType}/{billNumber}/cosponsors
-    BillCosponsors(u32, BillType, u32, BillCosponsorsParams),
+    BillCosponsors(Congress, BillType, u32, BillCosponsorsParams),
 
     /// Endpoint to get related bills of a specific bill.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`BillType`]: The type of bill.
     /// - [`u32`]: The bill number.
     /// - [`BillRelatedParams`]: Additional parameters for related bills.
     /// /bill/{congress}/{billType}/{billNumber}/relatedbills
-    BillRelated(u32, BillType, u32, BillRelatedParams),
+    BillRelated(Congress, BillType, u32, BillRelatedParams),
 
     /// Endpoint to retrieve subjects of a specific bill.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`BillType`]: The type of bill.
     /// - [`u32`]: The bill number.
     /// - [`BillSubjectsParams`]: Additional parameters for bill subjects.
     /// /bill/{congress}/{billType}/{billNumber}/subjects
-    BillSubjects(u32, BillType, u32, BillSubjectsParams),
+    BillSubjects(Congress, BillType, u32, BillSubjectsParams),
 
     /// Endpoint to retrieve summaries of a specific bill.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`BillType`]: The type of bill.
     /// - [`u32`]: The bill number.
     /// - [`BillSummariesParams`]: Additional parameters for bill summaries.
     /// /bill/{congress}/{billType}/{billNumber}/summaries
-    BillSummaries(u32, BillType, u32, BillSummariesParams),
+    BillSummaries(Congress, BillType, u32, BillSummariesParams),
 
     /// Endpoint to retrieve text of a specific bill.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`BillType`]: The type of bill.
     /// - [`u32`]: The bill number.
     /// - [`BillTextParams`]: Additional parameters for bill text.
     /// /bill/{congress}/{billType}/{billNumber}/text
-    BillText(u32, BillType, u32, BillTextParams),
+    BillText(Congress, BillType, u32, BillTextParams),
 
     /// Endpoint to retrieve titles of a specific bill.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`BillType`]: The type of bill.
     /// - [`u32`]: The bill number.
     /// - [`BillTitlesParams`]: Additional parameters for bill titles.
     /// /bill/{congress}/{billType}/{billNumber}/titles
-    BillTitles(u32, BillType, u32, BillTitlesParams),
+    BillTitles(Congress, BillType, u32, BillTitlesParams),
 
     // ================================
     // Law Endpoints
     // ================================
     /// Endpoint to list laws based on provided parameters.
     /// /law/{congress}/{lawType}
-    LawByType(u32, LawType, LawParams),
+    LawByType(Congress, LawType, LawParams),
 
     /// Endpoint to retrieve laws by a specific congress number.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`LawParams`]: Additional parameters for filtering laws.
     /// /law/{congress}
-    LawByCongress(u32, LawParams),
+    LawByCongress(Congress, LawParams),
 
     /// Endpoint to get detailed information about a specific law.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`LawType`]: The type of law.
     /// - [`u32`]: The law number.
     /// - [`LawDetailsParams`]: Additional parameters for law details.
     /// /law/{congress}/{lawType}/{lawNumber}
-    LawDetails(u32, LawType, u32, LawParams),
+    LawDetails(Congress, LawType, u32, LawParams),
 
     // ================================
     // Amendment Endpoints
@@ -209,101 +212,101 @@ pub enum Endpoints {
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`AmendmentByCongressParams`]: Additional parameters for filtering amendments.
     /// /amendment/{congress}
-    AmendmentByCongress(u32, AmendmentByCongressParams),
+    AmendmentByCongress(Congress, AmendmentByCongressParams),
 
     /// Endpoint to get amendments filtered by type within a specific congress.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`AmendmentType`]: The type of amendment.
     /// - [`AmendmentByTypeParams`]: Additional parameters for filtering amendments by type.
     /// /amendment/{congress}/{amendmentType}
-    AmendmentByType(u32, AmendmentType, AmendmentByTypeParams),
+    AmendmentByType(Congress, AmendmentType, AmendmentByTypeParams),
 
     /// Endpoint to retrieve detailed information about a specific amendment.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`AmendmentType`]: The type of amendment.
     /// - [`u32`]: The amendment number.
     /// - [`AmendmentDetailsParams`]: Additional parameters for amendment details.
     /// /amendment/{congress}/{amendmentType}/{amendmentNumber}
-    AmendmentDetails(u32, AmendmentType, u32, AmendmentDetailsParams),
+    AmendmentDetails(Congress, AmendmentType, u32, AmendmentDetailsParams),
 
     /// Endpoint to fetch actions taken on a specific amendment.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`AmendmentType`]: The type of amendment.
     /// - [`String`]: The amendment number.
     /// - [`AmendmentActionsParams`]: Additional parameters for amendment actions.
     /// /amendment/{congress}/{amendmentType}/{amendmentNumber}/actions
-    AmendmentActions(u32, AmendmentType, String, AmendmentActionsParams),
+    AmendmentActions(Congress, AmendmentType, String, AmendmentActionsParams),
 
     /// Endpoint to retrieve cosponsors of a specific amendment.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`AmendmentType`]: The type of amendment.
     /// - [`String`]: The amendment number.
     /// - [`AmendmentCosponsorsParams`]: Additional parameters for amendment cosponsors.
     /// /amendment/{congress}/{amendmentType}/{amendmentNumber}/cosponsors
-    AmendmentCosponsors(u32, AmendmentType, String, AmendmentCosponsorsParams),
+    AmendmentCosponsors(Congress, AmendmentType, String, AmendmentCosponsorsParams),
 
     /// Endpoint to list amendments of a specific amendment.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`AmendmentType`]: The type of amendment.
     /// - [`String`]: The amendment number.
     /// - [`AmendmentAmendmentsParams`]: Additional parameters for amendment amendments.
     /// /amendment/{congress}/{amendmentType}/{amendmentNumber}/amendments
-    AmendmentAmendments(u32, AmendmentType, String, AmendmentAmendmentsParams),
+    AmendmentAmendments(Congress, AmendmentType, String, AmendmentAmendmentsParams),
 
     /// Endpoint to retrieve the text of a specific amendment.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`AmendmentType`]: The type of amendment.
     /// - [`String`]: The amendment number.
     /// - [`AmendmentTextParams`]: Additional parameters for amendment text.
     /// /amendment/{congress}/{amendmentType}/{amendmentNumber}/text
-    AmendmentText(u32, AmendmentType, String, AmendmentTextParams),
+    AmendmentText(Congress, AmendmentType, String, AmendmentTextParams),
 
     // ================================
     // Summaries Endpoints
     // ================================
     /// Endpoint to list summaries based on provided parameters.
-    /// /summary
+    /// /summaries
     SummariesList(SummariesListParams),
 
     /// Endpoint to retrieve summaries by a specific congress number.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`SummariesByCongressParams`]: Additional parameters for filtering summaries.
-    /// /summary/{congress}
-    SummariesByCongress(u32, SummariesByCongressParams),
+    /// /summaries/{congress}
+    SummariesByCongress(Congress, SummariesByCongressParams),
 
     /// Endpoint to get summaries filtered by bill type within a specific congress.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`BillType`]: The type of bill.
     /// - [`SummariesByTypeParams`]: Additional parameters for filtering summaries by type.
-    /// /summary/{congress}/{billType}
-    SummariesByType(u32, BillType, SummariesByTypeParams),
+    /// /summaries/{congress}/{billType}
+    SummariesByType(Congress, BillType, SummariesByTypeParams),
 
     // ================================
     // Congress Endpoints
@@ -316,10 +319,10 @@ pub enum Endpoints {
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`CongressDetailsParams`]: Additional parameters for congress details.
     /// /congress/{congress}
-    CongressDetails(u32, CongressDetailsParams),
+    CongressDetails(Congress, CongressDetailsParams),
 
     /// Endpoint to get information about the current congress session.
     ///
@@ -340,10 +343,10 @@ pub enum Endpoints {
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`MemberByCongressParams`]: Additional parameters for filtering members by congress.
     /// /member/congress/{congress}
-    MemberByCongress(u32, MemberByCongressParams),
+    MemberByCongress(Congress, MemberByCongressParams),
 
     /// Endpoint to get members representing a specific state.
     ///
@@ -369,12 +372,12 @@ pub enum Endpoints {
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`String`]: The state code.
     /// - [`u32`]: The district number.
     /// - [`MemberByCongressStateDistrictParams`]: Additional parameters for filtering.
     /// /member/congress/{congress}/{stateCode}/{district}
-    MemberByCongressStateDistrict(u32, String, u32, MemberByCongressStateDistrictParams),
+    MemberByCongressStateDistrict(Congress, String, u32, MemberByCongressStateDistrictParams),
 
     /// Endpoint to get detailed information about a specific member.
     ///
@@ -427,20 +430,20 @@ pub enum Endpoints {
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`CommitteeByCongressParams`]: Additional parameters for filtering committees by congress.
     /// /committee/{congress}
-    CommitteeByCongress(u32, CommitteeByCongressParams),
+    CommitteeByCongress(Congress, CommitteeByCongressParams),
 
     /// Endpoint to retrieve committees by congress and chamber.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`ChamberType`]: The chamber type.
     /// - [`CommitteeByCongressChamberParams`]: Additional parameters for filtering.
     /// /committee/{congress}/{chamber}
-    CommitteeByCongressChamber(u32, ChamberType, CommitteeByCongressChamberParams),
+    CommitteeByCongressChamber(Congress, ChamberType, CommitteeByCongressChamberParams),
 
     /// Endpoint to get detailed information about a specific committee.
     ///
@@ -517,43 +520,53 @@ pub enum Endpoints {
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`CommitteeReportByCongressParams`]: Additional parameters for filtering committee reports.
     /// /committee-report/{congress}
-    CommitteeReportByCongress(u32, CommitteeReportByCongressParams),
+    CommitteeReportByCongress(Congress, CommitteeReportByCongressParams),
 
     /// Endpoint to get committee reports filtered by type within a specific congress.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`CommitteeReportType`]: The type of committee report.
     /// - [`CommitteeReportByTypeParams`]: Additional parameters for filtering committee reports by
     /// type.
     /// /committee-report/{congress}/{reportType}
-    CommitteeReportByType(u32, CommitteeReportType, CommitteeReportByTypeParams),
+    CommitteeReportByType(Congress, CommitteeReportType, CommitteeReportByTypeParams),
 
     /// Endpoint to retrieve detailed information about a specific committee report.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`CommitteeReportType`]: The type of committee report.
     /// - [`u32`]: The report number.
     /// - [`CommitteeReportDetailsParams`]: Additional parameters for committee report details.
     /// /committee-report/{congress}/{reportType}/{reportNumber}
-    CommitteeReportDetails(u32, CommitteeReportType, u32, CommitteeReportDetailsParams),
+    CommitteeReportDetails(
+        Congress,
+        CommitteeReportType,
+        u32,
+        CommitteeReportDetailsParams,
+    ),
 
     /// Endpoint to retrieve text of a specific committee report.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`CommitteeReportType`]: The type of committee report.
     /// - [`u32`]: The report number.
     /// - [`CommitteeReportTextParams`]: Additional parameters for committee report text.
     /// /committee-report/{congress}/{reportType}/{reportNumber}/text
-    CommitteeReportText(u32, CommitteeReportType, u32, CommitteeReportTextParams),
+    CommitteeReportText(
+        Congress,
+        CommitteeReportType,
+        u32,
+        CommitteeReportTextParams,
+    ),
 
     // ================================
     // Committee Print Endpoints
@@ -570,39 +583,59 @@ pub enum Endpoints {
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`CommitteePrintByCongressParams`]: Additional parameters for filtering committee prints.
     /// /committee-print/{congress}
-    CommitteePrintByCongress(u32, CommitteePrintByCongressParams),
+    CommitteePrintByCongress(Congress, CommitteePrintByCongressParams),
 
     /// Endpoint to get committee prints filtered by type within a specific congress.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`ChamberType`]: The chamber type. (house, senate, nochamber)
     /// - [`CommitteePrintByCongressChamberParams`]: Additional parameters for filtering committee
     /// prints by chamber.
     /// /committee-print/{congress}/{chamber}
-    CommitteePrintByCongressChamber(u32, ChamberType, CommitteePrintByCongressChamberParams),
+    CommitteePrintByCongressChamber(Congress, ChamberType, CommitteePrintByCongressChamberParams),
 
     /// Endpoint to retrieve detailed information about a specific committee print.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
+    /// - [`u32`]: The jacket number for the committee print.
+    ///
+    /// **Note:** The API identifies a committee print by congress, chamber, and jacket
+    /// number; without the chamber this path is indistinguishable from
+    /// [`Endpoints::CommitteePrintByCongressChamber`]. Use [`Endpoints::CommitteePrintDetails`].
+    #[deprecated(note = "use CommitteePrintDetails")]
+    CommitteePrintByJacketNumber(Congress, u32, CommitteePrintByJacketNumberParams),
+
+    /// Endpoint to retrieve detailed information about a specific committee print.
+    ///
+    /// # Parameters
+    ///
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
+    /// - [`ChamberType`]: The chamber type.
     /// - [`u32`]: The jacket number for the committee print.
-    CommitteePrintByJacketNumber(u32, u32, CommitteePrintByJacketNumberParams),
+    /// - [`CommitteePrintByJacketNumberParams`]: Additional parameters for committee print details.
+    CommitteePrintDetails(
+        Congress,
+        ChamberType,
+        u32,
+        CommitteePrintByJacketNumberParams,
+    ),
 
     /// Endpoint to retrieve text of a specific committee print.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`ChamberType`]: The chamber type.
     /// - [`u32`]: The jacket number for the committee print.
     /// - [`CommitteePrintTextParams`]: Additional parameters for committee print text.
-    CommitteePrintText(u32, String, u32, CommitteePrintDetailsParams),
+    CommitteePrintText(Congress, String, u32, CommitteePrintDetailsParams),
 
     // ================================
     // Committee Meeting Endpoints
@@ -619,33 +652,33 @@ pub enum Endpoints {
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`CommitteeMeetingByCongressParams`]: Additional parameters for filtering committee
     /// meetings.
     /// /committee-meeting/{congress}
-    CommitteeMeetingByCongress(u32, CommitteeMeetingByCongressParams),
+    CommitteeMeetingByCongress(Congress, CommitteeMeetingByCongressParams),
 
     /// Endpoint to get committee meetings filtered by chamber within a specific congress.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`ChamberType`]: The chamber type.
     /// - [`CommitteeMeetingByChamberParams`]: Additional parameters for filtering committee meetings
     /// by chamber.
     /// /committee-meeting/{congress}/{chamber}
-    CommitteeMeetingByChamber(u32, ChamberType, CommitteeMeetingByChamberParams),
+    CommitteeMeetingByChamber(Congress, ChamberType, CommitteeMeetingByChamberParams),
 
     /// Endpoint to retrieve detailed information about a specific committee meeting.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`ChamberType`]: The chamber type.
     /// - [`String`]: The eventId for the committee meeting.
     /// - [`CommitteeMeetingDetailsParams`]: Additional parameters for committee meeting details.
     /// /committee-meeting/{congress}/{chamber}/{eventId}
-    CommitteeMeetingByEvent(u32, ChamberType, String, CommitteeMeetingByEventParams),
+    CommitteeMeetingByEvent(Congress, ChamberType, String, CommitteeMeetingByEventParams),
 
     // ================================
     // Hearing Endpoints
@@ -662,31 +695,31 @@ pub enum Endpoints {
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`HearingByCongressParams`]: Additional parameters for filtering hearings.
     /// /hearing/{congress}
-    HearingByCongress(u32, HearingByCongressParams),
+    HearingByCongress(Congress, HearingByCongressParams),
 
     /// Endpoint to get hearings filtered by chamber within a specific congress.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`ChamberType`]: The chamber type.
     /// - [`HearingByChamberParams`]: Additional parameters for filtering hearings by chamber.
     /// /hearing/{congress}/{chamber}
-    HearingByChamber(u32, ChamberType, HearingByChamberParams),
+    HearingByChamber(Congress, ChamberType, HearingByChamberParams),
 
     /// Endpoint to retrieve detailed information about a specific hearing.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`ChamberType`]: The chamber type.
     /// - [`u32`]: The jacket number for the hearing.
     /// - [`HearingDetailsParams`]: Additional parameters for hearing details.
     /// /hearing/{congress}/{jacketNumber}
-    HearingByJacketNumber(u32, ChamberType, u32, HearingByJacketNumberParams),
+    HearingByJacketNumber(Congress, ChamberType, u32, HearingByJacketNumberParams),
 
     // ================================
     // Congressional Record Endpoints
@@ -738,7 +771,7 @@ pub enum Endpoints {
     /// - [`u32`]: The volume number.
     /// - [`DailyCongressionalVolumeNumberIssueNumberParams`]: Additional parameters for filtering
     /// daily Congressional Records by volume, issue number, and article number.
-    /// /daily-congressional-record/{volumeNumber}/{issueNumber}/article
+    /// /daily-congressional-record/{volumeNumber}/{issueNumber}/articles
     DailyCongressionalRecordArticles(u32, u32, DailyCongressionalVolumeNumberIssueNumberParams),
 
     // ================================
@@ -797,33 +830,33 @@ pub enum Endpoints {
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`CommunicationParams`]: Additional parameters for filtering house
     /// /house-communication/{congress}
-    HouseCommunicationByCongress(u32, CommunicationParams),
+    HouseCommunicationByCongress(Congress, CommunicationParams),
 
     /// Endpoint to get house communications filtered by congress and a specific communication
     /// type.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`CommunicationType`]: The type of house communication.
     /// - [`CommunicationParams`]: Additional parameters for filtering house
     /// communications by type.
     /// /house-communication/{congress}/{communicationType}
-    HouseCommunicationByType(u32, CommunicationType, CommunicationParams),
+    HouseCommunicationByType(Congress, CommunicationType, CommunicationParams),
 
     /// Endpoint to retrieve detailed information about a specific house communication.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`CommunicationType`]: The type of house communication.
     /// - [`u32`]: The communication number.
     /// - [`CommunicationDetailsParams`]: Additional parameters for house communication details.
     /// /house-communication/{congress}/{communicationType}/{communicationNumber}
-    HouseCommunicationDetails(u32, CommunicationType, u32, CommunicationDetailsParams),
+    HouseCommunicationDetails(Congress, CommunicationType, u32, CommunicationDetailsParams),
 
     // ================================
     // House Requirement Endpoints
@@ -869,33 +902,33 @@ pub enum Endpoints {
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`CommunicationParams`]: Additional parameters for filtering house
     /// /house-communication/{congress}
-    SenateCommunicationByCongress(u32, CommunicationParams),
+    SenateCommunicationByCongress(Congress, CommunicationParams),
 
     /// Endpoint to get house communications filtered by congress and a specific communication
     /// type.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`CommunicationType`]: The type of house communication.
     /// - [`CommunicationParams`]: Additional parameters for filtering house
     /// communications by type.
     /// /house-communication/{congress}/{communicationType}
-    SenateCommunicationByType(u32, CommunicationType, CommunicationParams),
+    SenateCommunicationByType(Congress, CommunicationType, CommunicationParams),
 
     /// Endpoint to retrieve detailed information about a specific house communication.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`CommunicationType`]: The type of house communication.
     /// - [`u32`]: The communication number.
     /// - [`CommunicationDetailsParams`]: Additional parameters for house communication details.
     /// /house-communication/{congress}/{communicationType}/{communicationNumber}
-    SenateCommunicationDetails(u32, CommunicationType, u32, CommunicationDetailsParams),
+    SenateCommunicationDetails(Congress, CommunicationType, u32, CommunicationDetailsParams),
 
     // ================================
     // Nomination Endpoints
@@ -907,55 +940,55 @@ pub enum Endpoints {
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`NominationByCongressParams`]: Additional parameters for filtering nominations.
-    NominationByCongress(u32, NominationByCongressParams),
+    NominationByCongress(Congress, NominationByCongressParams),
 
     /// Endpoint to get detailed information about a specific nomination.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`String`]: The nomination number.
     /// - [`NominationDetailsParams`]: Additional parameters for nomination details.
-    NominationDetails(u32, String, NominationDetailsParams),
+    NominationDetails(Congress, String, NominationDetailsParams),
 
     /// Endpoint to list nominees of a specific nomination.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`String`]: The nomination number.
     /// - [`u32`]: The ordinal number.
     /// - [`NomineesParams`]: Additional parameters for nominees.
-    Nominees(u32, String, u32, NomineesParams),
+    Nominees(Congress, String, u32, NomineesParams),
 
     /// Endpoint to fetch actions taken on a specific nomination.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`String`]: The nomination number.
     /// - [`NominationActionsParams`]: Additional parameters for nomination actions.
-    NominationActions(u32, String, NominationActionsParams),
+    NominationActions(Congress, String, NominationActionsParams),
 
     /// Endpoint to retrieve committees involved in a specific nomination.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`String`]: The nomination number.
     /// - [`NominationCommitteesParams`]: Additional parameters for nomination committees.
-    NominationCommittees(u32, String, NominationCommitteesParams),
+    NominationCommittees(Congress, String, NominationCommitteesParams),
 
     /// Endpoint to list hearings related to a specific nomination.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`String`]: The nomination number.
     /// - [`NominationHearingsParams`]: Additional parameters for nomination hearings.
-    NominationHearings(u32, String, NominationHearingsParams),
+    NominationHearings(Congress, String, NominationHearingsParams),
 
     // ================================
     // Treaty Endpoints
@@ -967,62 +1000,63 @@ pub enum Endpoints {
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`TreatyByCongressParams`]: Additional parameters for filtering treaties.
-    TreatyByCongress(u32, TreatyByCongressParams),
+    TreatyByCongress(Congress, TreatyByCongressParams),
 
     /// Endpoint to get detailed information about a specific treaty.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`u32`]: The treaty number.
     /// - [`TreatyDetailsParams`]: Additional parameters for treaty details.
-    TreatyDetails(u32, u32, TreatyDetailsParams),
+    TreatyDetails(Congress, u32, TreatyDetailsParams),
 
     /// Endpoint to retrieve partitioned information about a specific treaty.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`u32`]: The treaty number.
-    /// - [`String`]: The treaty suffix.
+    /// - [`TreatySuffix`]: The treaty suffix, e.g. `A`.
     /// - [`TreatyPartitionedParams`]: Additional parameters for partitioned treaties.
-    TreatyPartitioned(u32, u32, String, TreatyPartitionedParams),
+    TreatyPartitioned(Congress, u32, TreatySuffix, TreatyPartitionedParams),
 
     /// Endpoint to retrieve committees associated with a specific treaty.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`u32`]: The treaty number.
     /// - [`TreatyCommitteesParams`]: Additional parameters for treaty committees.
-    TreatyCommittees(u32, u32, TreatyCommitteesParams),
+    TreatyCommittees(Congress, u32, TreatyCommitteesParams),
 
     /// Endpoint to fetch actions taken on a specific treaty.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`u32`]: The treaty number.
     /// - [`TreatyActionsParams`]: Additional parameters for treaty actions.
-    TreatyActions(u32, u32, TreatyActionsParams),
+    TreatyActions(Congress, u32, TreatyActionsParams),
 
     /// Endpoint to fetch actions taken on a specific treaty, by suffix.
     ///
     /// # Parameters
     ///
-    /// - [`u32`]: The congress number.
+    /// - [`Congress`]: The congress number, or [`Congress::Current`].
     /// - [`u32`]: The treaty number.
-    /// - [`String`]: The treaty suffix.
+    /// - [`TreatySuffix`]: The treaty suffix, e.g. `A`.
     /// - [`TreatyActionsParams`]: Additional parameters for treaty actions.
     /// /treaty/{congress}/{treatyNumber}/{treatySuffix}/actions
-    TreatyActionsBySuffix(u32, u32, String, TreatyActionsParams),
+    TreatyActionsBySuffix(Congress, u32, TreatySuffix, TreatyActionsParams),
 }
 
 impl Iterator for Endpoints {
     type Item = Endpoints;
 
+    #[allow(deprecated)]
     fn next(&mut self) -> Option<Self::Item> {
         match self {
             Endpoints::BillList(params) => Some(Endpoints::BillList(params.clone())),
@@ -1194,6 +1228,14 @@ impl Iterator for Endpoints {
             Endpoints::CommitteePrintByJacketNumber(congress, jacket_number, params) => Some(
                 Endpoints::CommitteePrintByJacketNumber(*congress, *jacket_number, params.clone()),
             ),
+            Endpoints::CommitteePrintDetails(congress, chamber, jacket_number, params) => {
+                Some(Endpoints::CommitteePrintDetails(
+                    *congress,
+                    *chamber,
+                    *jacket_number,
+                    params.clone(),
+                ))
+            }
             Endpoints::CommitteePrintText(congress, chamber, jacket_number, params) => {
                 Some(Endpoints::CommitteePrintText(
                     *congress,
@@ -1366,7 +1408,7 @@ impl Iterator for Endpoints {
                 Some(Endpoints::TreatyPartitioned(
                     *congress,
                     *treaty_number,
-                    treaty_suffix.clone(),
+                    *treaty_suffix,
                     params.clone(),
                 ))
             }
@@ -1380,7 +1422,7 @@ impl Iterator for Endpoints {
                 Some(Endpoints::TreatyActionsBySuffix(
                     *congress,
                     *treaty_number,
-                    treaty_suffix.clone(),
+                    *treaty_suffix,
                     params.clone(),
                 ))
             }
@@ -1389,6 +1431,622 @@ impl Iterator for Endpoints {
     }
 }
 
+/// Expands to a match returning a reference to the congress of the endpoint `$endpoint`
+/// (shared or mutable, following the reference passed in), or [`None`].
+macro_rules! congress_of {
+    ($endpoint:expr) => {
+        match $endpoint {
+            Endpoints::BillByCongress(congress, ..)
+            | Endpoints::BillByType(congress, ..)
+            | Endpoints::BillDetails(congress, ..)
+            | Endpoints::BillActions(congress, ..)
+            | Endpoints::BillAmendments(congress, ..)
+            | Endpoints::BillCommittees(congress, ..)
+            | Endpoints::BillCosponsors(congress, ..)
+            | Endpoints::BillRelated(congress, ..)
+            | Endpoints::BillSubjects(congress, ..)
+            | Endpoints::BillSummaries(congress, ..)
+            | Endpoints::BillText(congress, ..)
+            | Endpoints::BillTitles(congress, ..)
+            | Endpoints::LawByType(congress, ..)
+            | Endpoints::LawByCongress(congress, ..)
+            | Endpoints::LawDetails(congress, ..)
+            | Endpoints::AmendmentByCongress(congress, ..)
+            | Endpoints::AmendmentByType(congress, ..)
+            | Endpoints::AmendmentDetails(congress, ..)
+            | Endpoints::AmendmentActions(congress, ..)
+            | Endpoints::AmendmentCosponsors(congress, ..)
+            | Endpoints::AmendmentAmendments(congress, ..)
+            | Endpoints::AmendmentText(congress, ..)
+            | Endpoints::SummariesByCongress(congress, ..)
+            | Endpoints::SummariesByType(congress, ..)
+            | Endpoints::CongressDetails(congress, ..)
+            | Endpoints::MemberByCongress(congress, ..)
+            | Endpoints::MemberByCongressStateDistrict(congress, ..)
+            | Endpoints::CommitteeByCongress(congress, ..)
+            | Endpoints::CommitteeByCongressChamber(congress, ..)
+            | Endpoints::CommitteeReportByCongress(congress, ..)
+            | Endpoints::CommitteeReportByType(congress, ..)
+            | Endpoints::CommitteeReportDetails(congress, ..)
+            | Endpoints::CommitteeReportText(congress, ..)
+            | Endpoints::CommitteePrintByCongress(congress, ..)
+            | Endpoints::CommitteePrintByCongressChamber(congress, ..)
+            | Endpoints::CommitteePrintByJacketNumber(congress, ..)
+            | Endpoints::CommitteePrintDetails(congress, ..)
+            | Endpoints::CommitteePrintText(congress, ..)
+            | Endpoints::CommitteeMeetingByCongress(congress, ..)
+            | Endpoints::CommitteeMeetingByChamber(congress, ..)
+            | Endpoints::CommitteeMeetingByEvent(congress, ..)
+            | Endpoints::HearingByCongress(congress, ..)
+            | Endpoints::HearingByChamber(congress, ..)
+            | Endpoints::HearingByJacketNumber(congress, ..)
+            | Endpoints::HouseCommunicationByCongress(congress, ..)
+            | Endpoints::HouseCommunicationByType(congress, ..)
+            | Endpoints::HouseCommunicationDetails(congress, ..)
+            | Endpoints::SenateCommunicationByCongress(congress, ..)
+            | Endpoints::SenateCommunicationByType(congress, ..)
+            | Endpoints::SenateCommunicationDetails(congress, ..)
+            | Endpoints::NominationByCongress(congress, ..)
+            | Endpoints::NominationDetails(congress, ..)
+            | Endpoints::Nominees(congress, ..)
+            | Endpoints::NominationActions(congress, ..)
+            | Endpoints::NominationCommittees(congress, ..)
+            | Endpoints::NominationHearings(congress, ..)
+            | Endpoints::TreatyByCongress(congress, ..)
+            | Endpoints::TreatyDetails(congress, ..)
+            | Endpoints::TreatyPartitioned(congress, ..)
+            | Endpoints::TreatyCommittees(congress, ..)
+            | Endpoints::TreatyActions(congress, ..)
+            | Endpoints::TreatyActionsBySuffix(congress, ..) => Some(congress),
+            _ => None,
+        }
+    };
+}
+
+impl Endpoints {
+    /// Entry point for the member endpoints.
+    ///
+    /// Returns a [`MemberEndpoints`] builder whose methods construct each member variant,
+    /// so the available endpoints can be discovered from one place.
+    ///
+    /// ```rust
+    /// use cdg_api::endpoints::Endpoints;
+    /// use cdg_api::param_models::MemberByCongressStateDistrictParams;
+    ///
+    /// let endpoint = Endpoints::member().by_congress_state_district(
+    ///     118,
+    ///     "ca",
+    ///     12,
+    ///     MemberByCongressStateDistrictParams::default(),
+    /// );
+    /// assert!(endpoint.to_string().starts_with("member/congress/118/CA/12"));
+    /// ```
+    pub fn member() -> MemberEndpoints {
+        MemberEndpoints
+    }
+
+    /// Entry point for the endpoints of a single treaty.
+    ///
+    /// Returns a [`TreatyEndpoints`] builder for treaty `number` received in `congress`.
+    ///
+    /// ```rust
+    /// use cdg_api::cdg_types::TreatySuffix;
+    /// use cdg_api::endpoints::Endpoints;
+    /// use cdg_api::param_models::TreatyActionsParams;
+    ///
+    /// let suffix = TreatySuffix::new("a").expect("Invalid treaty suffix");
+    /// let endpoint = Endpoints::treaty(114, 13).actions_by_suffix(suffix, TreatyActionsParams::default());
+    /// assert!(endpoint.to_string().starts_with("treaty/114/13/A/actions"));
+    /// ```
+    pub fn treaty(congress: impl Into<Congress>, number: u32) -> TreatyEndpoints {
+        TreatyEndpoints {
+            congress: congress.into(),
+            number,
+        }
+    }
+
+    /// Entry point for the endpoints of a single nomination.
+    ///
+    /// Returns a [`NominationEndpoints`] builder for nomination `number` (e.g. `"2"` or
+    /// `"1064-1"` for a partitioned nomination) in `congress`.
+    ///
+    /// ```rust
+    /// use cdg_api::endpoints::Endpoints;
+    /// use cdg_api::param_models::NomineesParams;
+    ///
+    /// let endpoint = Endpoints::nomination(118, "2").nominees(1, NomineesParams::default());
+    /// assert!(endpoint.to_string().starts_with("nomination/118/2/1"));
+    /// ```
+    pub fn nomination(
+        congress: impl Into<Congress>,
+        number: impl Into<String>,
+    ) -> NominationEndpoints {
+        NominationEndpoints {
+            congress: congress.into(),
+            number: number.into(),
+        }
+    }
+
+    /// Returns `true` if the endpoint returns a paged list of items, `false` for endpoints
+    /// returning a single item.
+    ///
+    /// [`Endpoints::Generic`] counts as a list, since its path is not inspected.
+    pub fn is_list(&self) -> bool {
+        !matches!(
+            self,
+            Endpoints::BillDetails(..)
+                | Endpoints::LawDetails(..)
+                | Endpoints::AmendmentDetails(..)
+                | Endpoints::CongressDetails(..)
+                | Endpoints::CongressCurrent(..)
+                | Endpoints::MemberDetails(..)
+                | Endpoints::CommitteeDetails(..)
+                | Endpoints::CommitteeReportDetails(..)
+                | Endpoints::CommitteePrintDetails(..)
+                | Endpoints::CommitteeMeetingByEvent(..)
+                | Endpoints::HearingByJacketNumber(..)
+                | Endpoints::HouseCommunicationDetails(..)
+                | Endpoints::HouseRequirementDetails(..)
+                | Endpoints::SenateCommunicationDetails(..)
+                | Endpoints::NominationDetails(..)
+                | Endpoints::TreatyDetails(..)
+                | Endpoints::TreatyPartitioned(..)
+        )
+    }
+
+    /// Returns a short, human-readable description of what the endpoint fetches, e.g.
+    /// `Cosponsors of a bill.` for [`Endpoints::BillCosponsors`], for listings and tooltips
+    /// in tools built on this enum.
+    #[allow(deprecated)]
+    pub fn describe(&self) -> &'static str {
+        match self {
+            Endpoints::Generic(..) => "A user-provided endpoint path.",
+            Endpoints::BillList(..) => "Lists bills.",
+            Endpoints::BillByCongress(..) => "Lists bills from a congress.",
+            Endpoints::BillByType(..) => "Lists bills of one type from a congress.",
+            Endpoints::BillDetails(..) => "Details of a bill.",
+            Endpoints::BillActions(..) => "Actions taken on a bill.",
+            Endpoints::BillAmendments(..) => "Amendments to a bill.",
+            Endpoints::BillCommittees(..) => "Committees a bill was referred to.",
+            Endpoints::BillCosponsors(..) => "Cosponsors of a bill.",
+            Endpoints::BillRelated(..) => "Bills related to a bill.",
+            Endpoints::BillSubjects(..) => "Legislative subjects of a bill.",
+            Endpoints::BillSummaries(..) => "Summaries of a bill.",
+            Endpoints::BillText(..) => "Text versions of a bill.",
+            Endpoints::BillTitles(..) => "Titles of a bill.",
+            Endpoints::LawByType(..) => "Lists public or private laws from a congress.",
+            Endpoints::LawByCongress(..) => "Lists laws from a congress.",
+            Endpoints::LawDetails(..) => "Details of a law.",
+            Endpoints::AmendmentList(..) => "Lists amendments.",
+            Endpoints::AmendmentByCongress(..) => "Lists amendments from a congress.",
+            Endpoints::AmendmentByType(..) => "Lists amendments of one type from a congress.",
+            Endpoints::AmendmentDetails(..) => "Details of an amendment.",
+            Endpoints::AmendmentActions(..) => "Actions taken on an amendment.",
+            Endpoints::AmendmentCosponsors(..) => "Cosponsors of an amendment.",
+            Endpoints::AmendmentAmendments(..) => "Amendments to an amendment.",
+            Endpoints::AmendmentText(..) => "Text versions of an amendment.",
+            Endpoints::SummariesList(..) => "Lists bill summaries.",
+            Endpoints::SummariesByCongress(..) => "Lists bill summaries from a congress.",
+            Endpoints::SummariesByType(..) => "Lists summaries of one bill type from a congress.",
+            Endpoints::CongressList(..) => "Lists congresses and their sessions.",
+            Endpoints::CongressDetails(..) => "Details of a congress.",
+            Endpoints::CongressCurrent(..) => "Details of the current congress.",
+            Endpoints::MemberList(..) => "Lists members of Congress.",
+            Endpoints::MemberByCongress(..) => "Lists members of a congress.",
+            Endpoints::MemberByState(..) => "Lists members representing a state.",
+            Endpoints::MemberByStateDistrict(..) => {
+                "Lists members representing a congressional district."
+            }
+            Endpoints::MemberByCongressStateDistrict(..) => {
+                "Lists members representing a congressional district in a congress."
+            }
+            Endpoints::MemberDetails(..) => "Details of a member.",
+            Endpoints::SponsorshipList(..) => "Legislation sponsored by a member.",
+            Endpoints::CosponsorshipList(..) => "Legislation cosponsored by a member.",
+            Endpoints::CommitteeList(..) => "Lists congressional committees.",
+            Endpoints::CommitteeByChamber(..) => "Lists committees of a chamber.",
+            Endpoints::CommitteeByCongress(..) => "Lists committees of a congress.",
+            Endpoints::CommitteeByCongressChamber(..) => {
+                "Lists committees of a chamber in a congress."
+            }
+            Endpoints::CommitteeDetails(..) => "Details of a committee.",
+            Endpoints::CommitteeBills(..) => "Bills referred to a committee.",
+            Endpoints::CommitteeReports(..) => "Reports issued by a committee.",
+            Endpoints::CommitteeNominations(..) => "Nominations referred to a committee.",
+            Endpoints::CommitteeHouseCommunication(..) => {
+                "House communications referred to a committee."
+            }
+            Endpoints::CommitteeSenateCommunication(..) => {
+                "Senate communications referred to a committee."
+            }
+            Endpoints::CommitteeReportList(..) => "Lists committee reports.",
+            Endpoints::CommitteeReportByCongress(..) => "Lists committee reports from a congress.",
+            Endpoints::CommitteeReportByType(..) => {
+                "Lists committee reports of one type from a congress."
+            }
+            Endpoints::CommitteeReportDetails(..) => "Details of a committee report.",
+            Endpoints::CommitteeReportText(..) => "Text versions of a committee report.",
+            Endpoints::CommitteePrintList(..) => "Lists committee prints.",
+            Endpoints::CommitteePrintByCongress(..) => "Lists committee prints from a congress.",
+            Endpoints::CommitteePrintByCongressChamber(..) => {
+                "Lists committee prints of a chamber in a congress."
+            }
+            Endpoints::CommitteePrintByJacketNumber(..) => {
+                "Committee print by jacket number, without the chamber the API needs."
+            }
+            Endpoints::CommitteePrintDetails(..) => "Details of a committee print.",
+            Endpoints::CommitteePrintText(..) => "Text versions of a committee print.",
+            Endpoints::CommitteeMeetingList(..) => "Lists committee meetings.",
+            Endpoints::CommitteeMeetingByCongress(..) => {
+                "Lists committee meetings from a congress."
+            }
+            Endpoints::CommitteeMeetingByChamber(..) => {
+                "Lists committee meetings of a chamber in a congress."
+            }
+            Endpoints::CommitteeMeetingByEvent(..) => "Details of a committee meeting.",
+            Endpoints::HearingList(..) => "Lists hearings.",
+            Endpoints::HearingByCongress(..) => "Lists hearings from a congress.",
+            Endpoints::HearingByChamber(..) => "Lists hearings of a chamber in a congress.",
+            Endpoints::HearingByJacketNumber(..) => "Details of a hearing.",
+            Endpoints::CongressionalRecordList(..) => "Lists issues of the Congressional Record.",
+            Endpoints::DailyCongressionalRecordList(..) => {
+                "Lists issues of the daily Congressional Record."
+            }
+            Endpoints::DailyCongressionalRecordVolume(..) => {
+                "Lists daily Congressional Record issues of a volume."
+            }
+            Endpoints::DailyCongressionalRecordVolumeIssue(..) => {
+                "Details of a daily Congressional Record issue."
+            }
+            Endpoints::DailyCongressionalRecordArticles(..) => {
+                "Articles of a daily Congressional Record issue."
+            }
+            Endpoints::BoundCongressionalRecordList(..) => "Lists bound Congressional Records.",
+            Endpoints::BoundCongressionalRecordByYear(..) => {
+                "Lists bound Congressional Records from a year."
+            }
+            Endpoints::BoundCongressionalRecordByYearMonth(..) => {
+                "Lists bound Congressional Records from a month."
+            }
+            Endpoints::BoundCongressionalRecordByYearMonthDay(..) => {
+                "Lists bound Congressional Records from a day."
+            }
+            Endpoints::HouseCommunicationList(..) => "Lists House communications.",
+            Endpoints::HouseCommunicationByCongress(..) => {
+                "Lists House communications from a congress."
+            }
+            Endpoints::HouseCommunicationByType(..) => {
+                "Lists House communications of one type from a congress."
+            }
+            Endpoints::HouseCommunicationDetails(..) => "Details of a House communication.",
+            Endpoints::HouseRequirementList(..) => "Lists House requirements.",
+            Endpoints::HouseRequirementDetails(..) => "Details of a House requirement.",
+            Endpoints::HouseRequirementMatching(..) => {
+                "Communications matching a House requirement."
+            }
+            Endpoints::SenateCommunicationList(..) => "Lists Senate communications.",
+            Endpoints::SenateCommunicationByCongress(..) => {
+                "Lists Senate communications from a congress."
+            }
+            Endpoints::SenateCommunicationByType(..) => {
+                "Lists Senate communications of one type from a congress."
+            }
+            Endpoints::SenateCommunicationDetails(..) => "Details of a Senate communication.",
+            Endpoints::NominationList(..) => "Lists nominations.",
+            Endpoints::NominationByCongress(..) => "Lists nominations from a congress.",
+            Endpoints::NominationDetails(..) => "Details of a nomination.",
+            Endpoints::Nominees(..) => "Nominees of a nomination position.",
+            Endpoints::NominationActions(..) => "Actions taken on a nomination.",
+            Endpoints::NominationCommittees(..) => "Committees a nomination was referred to.",
+            Endpoints::NominationHearings(..) => "Hearings held on a nomination.",
+            Endpoints::TreatyList(..) => "Lists treaties.",
+            Endpoints::TreatyByCongress(..) => "Lists treaties from a congress.",
+            Endpoints::TreatyDetails(..) => "Details of a treaty.",
+            Endpoints::TreatyPartitioned(..) => "Details of one part of a partitioned treaty.",
+            Endpoints::TreatyCommittees(..) => "Committees a treaty was referred to.",
+            Endpoints::TreatyActions(..) => "Actions taken on a treaty.",
+            Endpoints::TreatyActionsBySuffix(..) => {
+                "Actions taken on one part of a partitioned treaty."
+            }
+        }
+    }
+
+    /// Returns the resource the endpoint belongs to: the first segment of its path, e.g.
+    /// `bill`, `member`, or `committee-report`.
+    ///
+    /// Sub-resources belong to their parent, so [`Endpoints::SponsorshipList`] is a `member`
+    /// request and [`Endpoints::CommitteeReports`] a `committee` request. Resource budgets
+    /// set with [`CongressApiClient::with_resource_budget`](crate::CongressApiClient::with_resource_budget)
+    /// count requests by this name.
+    #[allow(deprecated)]
+    pub fn resource_name(&self) -> &str {
+        match self {
+            Endpoints::Generic(path, _) => {
+                let path = path.trim_matches('/');
+                path.split(['/', '?']).next().unwrap_or(path)
+            }
+            Endpoints::BillList(..)
+            | Endpoints::BillByCongress(..)
+            | Endpoints::BillByType(..)
+            | Endpoints::BillDetails(..)
+            | Endpoints::BillActions(..)
+            | Endpoints::BillAmendments(..)
+            | Endpoints::BillCommittees(..)
+            | Endpoints::BillCosponsors(..)
+            | Endpoints::BillRelated(..)
+            | Endpoints::BillSubjects(..)
+            | Endpoints::BillSummaries(..)
+            | Endpoints::BillText(..)
+            | Endpoints::BillTitles(..) => "bill",
+            Endpoints::LawByType(..) | Endpoints::LawByCongress(..) | Endpoints::LawDetails(..) => {
+                "law"
+            }
+            Endpoints::AmendmentList(..)
+            | Endpoints::AmendmentByCongress(..)
+            | Endpoints::AmendmentByType(..)
+            | Endpoints::AmendmentDetails(..)
+            | Endpoints::AmendmentActions(..)
+            | Endpoints::AmendmentCosponsors(..)
+            | Endpoints::AmendmentAmendments(..)
+            | Endpoints::AmendmentText(..) => "amendment",
+            Endpoints::SummariesList(..)
+            | Endpoints::SummariesByCongress(..)
+            | Endpoints::SummariesByType(..) => "summaries",
+            Endpoints::CongressList(..)
+            | Endpoints::CongressDetails(..)
+            | Endpoints::CongressCurrent(..) => "congress",
+            Endpoints::MemberList(..)
+            | Endpoints::MemberByCongress(..)
+            | Endpoints::MemberByState(..)
+            | Endpoints::MemberByStateDistrict(..)
+            | Endpoints::MemberByCongressStateDistrict(..)
+            | Endpoints::MemberDetails(..)
+            | Endpoints::SponsorshipList(..)
+            | Endpoints::CosponsorshipList(..) => "member",
+            Endpoints::CommitteeList(..)
+            | Endpoints::CommitteeByChamber(..)
+            | Endpoints::CommitteeByCongress(..)
+            | Endpoints::CommitteeByCongressChamber(..)
+            | Endpoints::CommitteeDetails(..)
+            | Endpoints::CommitteeBills(..)
+            | Endpoints::CommitteeReports(..)
+            | Endpoints::CommitteeNominations(..)
+            | Endpoints::CommitteeHouseCommunication(..)
+            | Endpoints::CommitteeSenateCommunication(..) => "committee",
+            Endpoints::CommitteeReportList(..)
+            | Endpoints::CommitteeReportByCongress(..)
+            | Endpoints::CommitteeReportByType(..)
+            | Endpoints::CommitteeReportDetails(..)
+            | Endpoints::CommitteeReportText(..) => "committee-report",
+            Endpoints::CommitteePrintList(..)
+            | Endpoints::CommitteePrintByCongress(..)
+            | Endpoints::CommitteePrintByCongressChamber(..)
+            | Endpoints::CommitteePrintByJacketNumber(..)
+            | Endpoints::CommitteePrintDetails(..)
+            | Endpoints::CommitteePrintText(..) => "committee-print",
+            Endpoints::CommitteeMeetingList(..)
+            | Endpoints::CommitteeMeetingByCongress(..)
+            | Endpoints::CommitteeMeetingByChamber(..)
+            | Endpoints::CommitteeMeetingByEvent(..) => "committee-meeting",
+            Endpoints::HearingList(..)
+            | Endpoints::HearingByCongress(..)
+            | Endpoints::HearingByChamber(..)
+            | Endpoints::HearingByJacketNumber(..) => "hearing",
+            Endpoints::CongressionalRecordList(..) => "congressional-record",
+            Endpoints::DailyCongressionalRecordList(..)
+            | Endpoints::DailyCongressionalRecordVolume(..)
+            | Endpoints::DailyCongressionalRecordVolumeIssue(..)
+            | Endpoints::DailyCongressionalRecordArticles(..) => "daily-congressional-record",
+            Endpoints::BoundCongressionalRecordList(..)
+            | Endpoints::BoundCongressionalRecordByYear(..)
+            | Endpoints::BoundCongressionalRecordByYearMonth(..)
+            | Endpoints::BoundCongressionalRecordByYearMonthDay(..) => "bound-congressional-record",
+            Endpoints::HouseCommunicationList(..)
+            | Endpoints::HouseCommunicationByCongress(..)
+            | Endpoints::HouseCommunicationByType(..)
+            | Endpoints::HouseCommunicationDetails(..) => "house-communication",
+            Endpoints::HouseRequirementList(..)
+            | Endpoints::HouseRequirementDetails(..)
+            | Endpoints::HouseRequirementMatching(..) => "house-requirement",
+            Endpoints::SenateCommunicationList(..)
+            | Endpoints::SenateCommunicationByCongress(..)
+            | Endpoints::SenateCommunicationByType(..)
+            | Endpoints::SenateCommunicationDetails(..) => "senate-communication",
+            Endpoints::NominationList(..)
+            | Endpoints::NominationByCongress(..)
+            | Endpoints::NominationDetails(..)
+            | Endpoints::Nominees(..)
+            | Endpoints::NominationActions(..)
+            | Endpoints::NominationCommittees(..)
+            | Endpoints::NominationHearings(..) => "nomination",
+            Endpoints::TreatyList(..)
+            | Endpoints::TreatyByCongress(..)
+            | Endpoints::TreatyDetails(..)
+            | Endpoints::TreatyPartitioned(..)
+            | Endpoints::TreatyCommittees(..)
+            | Endpoints::TreatyActions(..)
+            | Endpoints::TreatyActionsBySuffix(..) => "treaty",
+        }
+    }
+
+    /// Returns `true` for the text endpoints, which list the formats a document's text is
+    /// published in: bill, amendment, committee report, and committee print text.
+    pub fn is_text(&self) -> bool {
+        matches!(
+            self,
+            Endpoints::BillText(..)
+                | Endpoints::AmendmentText(..)
+                | Endpoints::CommitteeReportText(..)
+                | Endpoints::CommitteePrintText(..)
+        )
+    }
+
+    /// Checks the endpoint's params for contradictory combinations.
+    ///
+    /// Only params with a `validate` method are checked, currently [`GenericParams`] and
+    /// [`CongressionalRecordListParams`]; every other endpoint is accepted.
+    ///
+    /// # Returns
+    ///
+    /// - `Err(ApiClientError::InvalidParam)`: If the params break one of their rules.
+    pub fn validate(&self) -> Result<(), ApiClientError> {
+        match self {
+            Endpoints::Generic(_, params) => params.validate(),
+            Endpoints::CongressionalRecordList(params) => params.validate(),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the endpoint's congress, if it has one.
+    #[allow(deprecated)]
+    pub fn congress(&self) -> Option<Congress> {
+        congress_of!(self).copied()
+    }
+
+    /// Returns a mutable reference to the endpoint's congress, if it has one.
+    ///
+    /// Used to substitute [`Congress::Current`] with the actual current congress before a
+    /// request is sent.
+    #[allow(deprecated)]
+    pub fn congress_mut(&mut self) -> Option<&mut Congress> {
+        congress_of!(self)
+    }
+
+    /// Sets the `sort` order of the endpoint's params, replacing any order already set.
+    ///
+    /// # Returns
+    ///
+    /// - `Err(ApiClientError::InvalidParam)`: If the endpoint's params have no `sort`.
+    pub fn set_sort(&mut self, sort: SortType) -> Result<(), ApiClientError> {
+        match self {
+            Endpoints::Generic(.., params) => params.sort = Some(sort),
+            Endpoints::BillList(.., params) => params.sort = Some(sort),
+            Endpoints::BillByCongress(.., params) => params.sort = Some(sort),
+            Endpoints::BillByType(.., params) => params.sort = Some(sort),
+            Endpoints::AmendmentList(.., params) => params.sort = Some(sort),
+            Endpoints::AmendmentByCongress(.., params) => params.sort = Some(sort),
+            Endpoints::AmendmentByType(.., params) => params.sort = Some(sort),
+            Endpoints::SummariesList(.., params) => params.sort = Some(sort),
+            Endpoints::SummariesByCongress(.., params) => params.sort = Some(sort),
+            Endpoints::SummariesByType(.., params) => params.sort = Some(sort),
+            Endpoints::NominationList(.., params) => params.sort = Some(sort),
+            Endpoints::NominationByCongress(.., params) => params.sort = Some(sort),
+            Endpoints::TreatyList(.., params) => params.sort = Some(sort),
+            Endpoints::TreatyByCongress(.., params) => params.sort = Some(sort),
+            _ => return Err(self.unsupported_param("sort")),
+        }
+        Ok(())
+    }
+
+    /// Sets the `fromDateTime` filter of the endpoint's params, replacing any value already
+    /// set.
+    ///
+    /// # Parameters
+    ///
+    /// - [`from_date_time`]: The start of the range, as `YYYY-MM-DDTHH:MM:SSZ`.
+    ///
+    /// # Returns
+    ///
+    /// - `Err(ApiClientError::InvalidParam)`: If the endpoint's params have no
+    ///   `fromDateTime`.
+    pub fn set_from_date_time(
+        &mut self,
+        from_date_time: impl Into<String>,
+    ) -> Result<(), ApiClientError> {
+        match self {
+            Endpoints::Generic(.., params) => params.from_date_time = Some(from_date_time.into()),
+            Endpoints::BillList(.., params) => params.from_date_time = Some(from_date_time.into()),
+            Endpoints::BillByCongress(.., params) => {
+                params.from_date_time = Some(from_date_time.into())
+            }
+            Endpoints::BillByType(.., params) => {
+                params.from_date_time = Some(from_date_time.into())
+            }
+            Endpoints::BillSubjects(.., params) => {
+                params.from_date_time = Some(from_date_time.into())
+            }
+            Endpoints::BillTitles(.., params) => {
+                params.from_date_time = Some(from_date_time.into())
+            }
+            Endpoints::AmendmentList(.., params) => {
+                params.from_date_time = Some(from_date_time.into())
+            }
+            Endpoints::AmendmentByCongress(.., params) => {
+                params.from_date_time = Some(from_date_time.into())
+            }
+            Endpoints::AmendmentByType(.., params) => {
+                params.from_date_time = Some(from_date_time.into())
+            }
+            Endpoints::SummariesList(.., params) => {
+                params.from_date_time = Some(from_date_time.into())
+            }
+            Endpoints::SummariesByCongress(.., params) => {
+                params.from_date_time = Some(from_date_time.into())
+            }
+            Endpoints::SummariesByType(.., params) => {
+                params.from_date_time = Some(from_date_time.into())
+            }
+            Endpoints::MemberList(.., params) => {
+                params.from_date_time = Some(from_date_time.into())
+            }
+            Endpoints::CommitteeList(.., params) => {
+                params.from_date_time = Some(from_date_time.into())
+            }
+            Endpoints::CommitteeByChamber(.., params) => {
+                params.from_date_time = Some(from_date_time.into())
+            }
+            Endpoints::CommitteeByCongress(.., params) => {
+                params.from_date_time = Some(from_date_time.into())
+            }
+            Endpoints::CommitteeByCongressChamber(.., params) => {
+                params.from_date_time = Some(from_date_time.into())
+            }
+            Endpoints::CommitteeReportList(.., params) => {
+                params.from_date_time = Some(from_date_time.into())
+            }
+            Endpoints::CommitteeReportByCongress(.., params) => {
+                params.from_date_time = Some(from_date_time.into())
+            }
+            Endpoints::CommitteeReportByType(.., params) => {
+                params.from_date_time = Some(from_date_time.into())
+            }
+            Endpoints::CommitteePrintList(.., params) => {
+                params.from_date_time = Some(from_date_time.into())
+            }
+            Endpoints::CommitteePrintByCongress(.., params) => {
+                params.from_date_time = Some(from_date_time.into())
+            }
+            Endpoints::CommitteePrintByCongressChamber(.., params) => {
+                params.from_date_time = Some(from_date_time.into())
+            }
+            Endpoints::NominationList(.., params) => {
+                params.from_date_time = Some(from_date_time.into())
+            }
+            Endpoints::NominationByCongress(.., params) => {
+                params.from_date_time = Some(from_date_time.into())
+            }
+            Endpoints::TreatyList(.., params) => {
+                params.from_date_time = Some(from_date_time.into())
+            }
+            Endpoints::TreatyByCongress(.., params) => {
+                params.from_date_time = Some(from_date_time.into())
+            }
+            _ => return Err(self.unsupported_param("fromDateTime")),
+        }
+        Ok(())
+    }
+
+    /// Returns the error for a query parameter the endpoint does not take.
+    fn unsupported_param(&self, name: &str) -> ApiClientError {
+        let path = self.to_string();
+        ApiClientError::InvalidParam(format!(
+            "`{}` does not take `{}`",
+            path.split('?').next().unwrap_or_default(),
+            name
+        ))
+    }
+}
+
 /// Trait defining constructors for creating new instances of [`Endpoints`].
 ///
 /// This trait provides a standardized way to instantiate each variant of the
@@ -1413,7 +2071,7 @@ pub trait NewEndpoint {
     ///
     /// - [`congress`]: The congress number.
     /// - [`params`]: Parameters for filtering bills by congress.
-    fn new_bill_by_congress(congress: u32, params: BillByCongressParams) -> Self;
+    fn new_bill_by_congress(congress: impl Into<Congress>, params: BillByCongressParams) -> Self;
 
     /// Constructs a [`BillByType`] endpoint variant.
     ///
@@ -1422,7 +2080,11 @@ pub trait NewEndpoint {
     /// - [`congress`]: The congress number.
     /// - [`bill_type`]: The type of bill.
     /// - [`params`]: Parameters for filtering bills by type.
-    fn new_bill_by_type(congress: u32, bill_type: BillType, params: BillByTypeParams) -> Self;
+    fn new_bill_by_type(
+        congress: impl Into<Congress>,
+        bill_type: BillType,
+        params: BillByTypeParams,
+    ) -> Self;
 
     /// Constructs a [`BillDetails`] endpoint variant.
     ///
@@ -1433,7 +2095,7 @@ pub trait NewEndpoint {
     /// - [`bill_number`]: The bill number.
     /// - [`params`]: Parameters for bill details.
     fn new_bill_details(
-        congress: u32,
+        congress: impl Into<Congress>,
         bill_type: BillType,
         bill_number: u32,
         params: BillDetailsParams,
@@ -1448,7 +2110,7 @@ pub trait NewEndpoint {
     /// - [`bill_number`]: The bill number.
     /// - [`params`]: Parameters for bill actions.
     fn new_bill_actions(
-        congress: u32,
+        congress: impl Into<Congress>,
         bill_type: BillType,
         bill_number: u32,
         params: BillActionsParams,
@@ -1463,7 +2125,7 @@ pub trait NewEndpoint {
     /// - [`bill_number`]: The bill number.
     /// - [`params`]: Parameters for bill amendments.
     fn new_bill_amendments(
-        congress: u32,
+        congress: impl Into<Congress>,
         bill_type: BillType,
         bill_number: u32,
         params: BillAmendmentsParams,
@@ -1478,7 +2140,7 @@ pub trait NewEndpoint {
     /// - [`bill_number`]: The bill number.
     /// - [`params`]: Parameters for bill committees.
     fn new_bill_committees(
-        congress: u32,
+        congress: impl Into<Congress>,
         bill_type: BillType,
         bill_number: u32,
         params: BillCommitteesParams,
@@ -1493,7 +2155,7 @@ pub trait NewEndpoint {
     /// - [`bill_number`]: The bill number.
     /// - [`params`]: Parameters for bill cosponsors.
     fn new_bill_cosponsors(
-        congress: u32,
+        congress: impl Into<Congress>,
         bill_type: BillType,
         bill_number: u32,
         params: BillCosponsorsParams,
@@ -1508,7 +2170,7 @@ pub trait NewEndpoint {
     /// # Parameters
     ///
     /// - [`params`]: Parameters for listing laws.
-    fn new_law_type(congress: u32, law_type: LawType, params: LawParams) -> Self;
+    fn new_law_type(congress: impl Into<Congress>, law_type: LawType, params: LawParams) -> Self;
 
     /// Constructs a [`LawByCongress`] endpoint variant.
     ///
@@ -1516,7 +2178,7 @@ pub trait NewEndpoint {
     ///
     /// - [`congress`]: The congress number.
     /// - [`params`]: Parameters for filtering laws by congress.
-    fn new_law_by_congress(congress: u32, params: LawParams) -> Self;
+    fn new_law_by_congress(congress: impl Into<Congress>, params: LawParams) -> Self;
 
     /// Constructs a [`LawDetails`] endpoint variant.
     ///
@@ -1525,7 +2187,7 @@ pub trait NewEndpoint {
     /// - [`congress`]: The congress number.
     /// - [`params`]: Parameters for law details.
     fn new_law_details(
-        congress: u32,
+        congress: impl Into<Congress>,
         law_type: LawType,
         law_number: u32,
         params: LawParams,
@@ -1548,7 +2210,10 @@ pub trait NewEndpoint {
     ///
     /// - [`congress`]: The congress number.
     /// - [`params`]: Parameters for filtering amendments by congress.
-    fn new_amendment_by_congress(congress: u32, params: AmendmentByCongressParams) -> Self;
+    fn new_amendment_by_congress(
+        congress: impl Into<Congress>,
+        params: AmendmentByCongressParams,
+    ) -> Self;
 
     /// Constructs an [`AmendmentByType`] endpoint variant.
     ///
@@ -1558,7 +2223,7 @@ pub trait NewEndpoint {
     /// - [`amendment_type`]: The type of amendment.
     /// - [`params`]: Parameters for filtering amendments by type.
     fn new_amendment_by_type(
-        congress: u32,
+        congress: impl Into<Congress>,
         amendment_type: AmendmentType,
         params: AmendmentByTypeParams,
     ) -> Self;
@@ -1572,7 +2237,7 @@ pub trait NewEndpoint {
     /// - [`amendment_number`]: The amendment number.
     /// - [`params`]: Parameters for amendment details.
     fn new_amendment_details(
-        congress: u32,
+        congress: impl Into<Congress>,
         amendment_type: AmendmentType,
         amendment_number: u32,
         params: AmendmentDetailsParams,
@@ -1587,7 +2252,7 @@ pub trait NewEndpoint {
     /// - [`amendment_number`]: The amendment number.
     /// - [`params`]: Parameters for amendment actions.
     fn new_amendment_actions(
-        congress: u32,
+        congress: impl Into<Congress>,
         amendment_type: AmendmentType,
         amendment_number: String,
         params: AmendmentActionsParams,
@@ -1602,7 +2267,7 @@ pub trait NewEndpoint {
     /// - [`amendment_number`]: The amendment number.
     /// - [`params`]: Parameters for amendment cosponsors.
     fn new_amendment_cosponsors(
-        congress: u32,
+        congress: impl Into<Congress>,
         amendment_type: AmendmentType,
         amendment_number: String,
         params: AmendmentCosponsorsParams,
@@ -1617,7 +2282,7 @@ pub trait NewEndpoint {
     /// - [`amendment_number`]: The amendment number.
     /// - [`params`]: Parameters for amendment amendments.
     fn new_amendment_amendments(
-        congress: u32,
+        congress: impl Into<Congress>,
         amendment_type: AmendmentType,
         amendment_number: String,
         params: AmendmentAmendmentsParams,
@@ -1632,7 +2297,7 @@ pub trait NewEndpoint {
     /// - [`amendment_number`]: The amendment number.
     /// - [`params`]: Parameters for amendment text.
     fn new_amendment_text(
-        congress: u32,
+        congress: impl Into<Congress>,
         amendment_type: AmendmentType,
         amendment_number: String,
         params: AmendmentTextParams,
@@ -1655,7 +2320,10 @@ pub trait NewEndpoint {
     ///
     /// - [`congress`]: The congress number.
     /// - [`params`]: Parameters for filtering summaries by congress.
-    fn new_summaries_by_congress(congress: u32, params: SummariesByCongressParams) -> Self;
+    fn new_summaries_by_congress(
+        congress: impl Into<Congress>,
+        params: SummariesByCongressParams,
+    ) -> Self;
 
     /// Constructs a [`SummariesByType`] endpoint variant.
     ///
@@ -1665,7 +2333,7 @@ pub trait NewEndpoint {
     /// - [`bill_type`]: The type of bill.
     /// - [`params`]: Parameters for filtering summaries by type.
     fn new_summaries_by_type(
-        congress: u32,
+        congress: impl Into<Congress>,
         bill_type: BillType,
         params: SummariesByTypeParams,
     ) -> Self;
@@ -1687,7 +2355,7 @@ pub trait NewEndpoint {
     ///
     /// - [`congress`]: The congress number.
     /// - [`params`]: Parameters for congress details.
-    fn new_congress_details(congress: u32, params: CongressDetailsParams) -> Self;
+    fn new_congress_details(congress: impl Into<Congress>, params: CongressDetailsParams) -> Self;
 
     /// Constructs a [`CongressCurrent`] endpoint variant.
     ///
@@ -1713,26 +2381,42 @@ pub trait NewEndpoint {
     ///
     /// - [`congress`]: The congress number.
     /// - [`params`]: Parameters for filtering members by congress.
-    fn new_member_by_congress(congress: u32, params: MemberByCongressParams) -> Self;
+    fn new_member_by_congress(
+        congress: impl Into<Congress>,
+        params: MemberByCongressParams,
+    ) -> Self;
 
     /// Constructs a [`MemberByState`] endpoint variant.
     ///
     /// # Parameters
     ///
-    /// - [`state_code`]: The state code (e.g., "CA" for California).
+    /// - [`state_code`]: The state code (e.g., "CA" for California), uppercased.
     /// - [`params`]: Parameters for filtering members by state.
     fn new_member_by_state(state_code: String, params: MemberByStateParams) -> Self;
 
+    /// Constructs a [`MemberByStateDistrict`] endpoint variant.
+    ///
+    /// # Parameters
+    ///
+    /// - [`state_code`]: The state code, uppercased (e.g., "ca" becomes "CA").
+    /// - [`district`]: The district number.
+    /// - [`params`]: Parameters for filtering members by state and district.
+    fn new_member_by_state_district(
+        state_code: String,
+        district: u32,
+        params: MemberByStateDistrictParams,
+    ) -> Self;
+
     /// Constructs a [`MemberByCongressStateDistrict`] endpoint variant.
     ///
     /// # Parameters
     ///
     /// - [`congress`]: The congress number.
-    /// - [`state_code`]: The state code.
+    /// - [`state_code`]: The state code, uppercased (e.g., "ca" becomes "CA").
     /// - [`district`]: The district number.
     /// - [`params`]: Parameters for filtering members by congress, state, and district.
     fn new_member_by_congress_state_district(
-        congress: u32,
+        congress: impl Into<Congress>,
         state_code: String,
         district: u32,
         params: MemberByCongressStateDistrictParams,
@@ -1787,7 +2471,10 @@ pub trait NewEndpoint {
     ///
     /// - [`congress`]: The congress number.
     /// - [`params`]: Parameters for filtering committees by congress.
-    fn new_committee_by_congress(congress: u32, params: CommitteeByCongressParams) -> Self;
+    fn new_committee_by_congress(
+        congress: impl Into<Congress>,
+        params: CommitteeByCongressParams,
+    ) -> Self;
 
     /// Constructs a [`CommitteeByCongressChamber`] endpoint variant.
     ///
@@ -1797,7 +2484,7 @@ pub trait NewEndpoint {
     /// - [`chamber`]: The chamber type.
     /// - [`params`]: Parameters for filtering committees by congress and chamber.
     fn new_committee_by_congress_chamber(
-        congress: u32,
+        congress: impl Into<Congress>,
         chamber: ChamberType,
         params: CommitteeByCongressChamberParams,
     ) -> Self;
@@ -1897,7 +2584,10 @@ pub trait NewEndpoint {
     ///
     /// - [`congress`]: The congress number.
     /// - [`params`]: Parameters for filtering nominations by congress.
-    fn new_nomination_by_congress(congress: u32, params: NominationByCongressParams) -> Self;
+    fn new_nomination_by_congress(
+        congress: impl Into<Congress>,
+        params: NominationByCongressParams,
+    ) -> Self;
 
     /// Constructs a [`NominationDetails`] endpoint variant.
     ///
@@ -1907,7 +2597,7 @@ pub trait NewEndpoint {
     /// - [`nomination_number`]: The nomination number.
     /// - [`params`]: Parameters for nomination details.
     fn new_nomination_details(
-        congress: u32,
+        congress: impl Into<Congress>,
         nomination_number: String,
         params: NominationDetailsParams,
     ) -> Self;
@@ -1921,7 +2611,7 @@ pub trait NewEndpoint {
     /// - [`ordinal`]: The ordinal number.
     /// - [`params`]: Parameters for nominees.
     fn new_nominees(
-        congress: u32,
+        congress: impl Into<Congress>,
         nomination_number: String,
         ordinal: u32,
         params: NomineesParams,
@@ -1935,7 +2625,7 @@ pub trait NewEndpoint {
     /// - [`nomination_number`]: The nomination number.
     /// - [`params`]: Parameters for nomination actions.
     fn new_nomination_actions(
-        congress: u32,
+        congress: impl Into<Congress>,
         nomination_number: String,
         params: NominationActionsParams,
     ) -> Self;
@@ -1948,7 +2638,7 @@ pub trait NewEndpoint {
     /// - [`nomination_number`]: The nomination number.
     /// - [`params`]: Parameters for nomination committees.
     fn new_nomination_committees(
-        congress: u32,
+        congress: impl Into<Congress>,
         nomination_number: String,
         params: NominationCommitteesParams,
     ) -> Self;
@@ -1961,7 +2651,7 @@ pub trait NewEndpoint {
     /// - [`nomination_number`]: The nomination number.
     /// - [`params`]: Parameters for nomination hearings.
     fn new_nomination_hearings(
-        congress: u32,
+        congress: impl Into<Congress>,
         nomination_number: String,
         params: NominationHearingsParams,
     ) -> Self;
@@ -1983,7 +2673,10 @@ pub trait NewEndpoint {
     ///
     /// - [`congress`]: The congress number.
     /// - [`params`]: Parameters for filtering treaties by congress.
-    fn new_treaty_by_congress(congress: u32, params: TreatyByCongressParams) -> Self;
+    fn new_treaty_by_congress(
+        congress: impl Into<Congress>,
+        params: TreatyByCongressParams,
+    ) -> Self;
 
     /// Constructs a [`TreatyDetails`] endpoint variant.
     ///
@@ -1992,7 +2685,11 @@ pub trait NewEndpoint {
     /// - [`congress`]: The congress number.
     /// - [`treaty_number`]: The treaty number.
     /// - [`params`]: Parameters for treaty details.
-    fn new_treaty_details(congress: u32, treaty_number: u32, params: TreatyDetailsParams) -> Self;
+    fn new_treaty_details(
+        congress: impl Into<Congress>,
+        treaty_number: u32,
+        params: TreatyDetailsParams,
+    ) -> Self;
 
     /// Constructs a [`TreatyPartitioned`] endpoint variant.
     ///
@@ -2000,12 +2697,12 @@ pub trait NewEndpoint {
     ///
     /// - [`congress`]: The congress number.
     /// - [`treaty_number`]: The treaty number.
-    /// - [`treaty_suffix`]: The treaty suffix.
+    /// - [`treaty_suffix`]: The treaty suffix, a single letter such as `A`.
     /// - [`params`]: Parameters for partitioned treaties.
     fn new_treaty_partitioned(
-        congress: u32,
+        congress: impl Into<Congress>,
         treaty_number: u32,
-        treaty_suffix: String,
+        treaty_suffix: TreatySuffix,
         params: TreatyPartitionedParams,
     ) -> Self;
 
@@ -2017,7 +2714,7 @@ pub trait NewEndpoint {
     /// - [`treaty_number`]: The treaty number.
     /// - [`params`]: Parameters for treaty committees.
     fn new_treaty_committees(
-        congress: u32,
+        congress: impl Into<Congress>,
         treaty_number: u32,
         params: TreatyCommitteesParams,
     ) -> Self;
@@ -2029,7 +2726,11 @@ pub trait NewEndpoint {
     /// - [`congress`]: The congress number.
     /// - [`treaty_number`]: The treaty number.
     /// - [`params`]: Parameters for treaty actions.
-    fn new_treaty_actions(congress: u32, treaty_number: u32, params: TreatyActionsParams) -> Self;
+    fn new_treaty_actions(
+        congress: impl Into<Congress>,
+        treaty_number: u32,
+        params: TreatyActionsParams,
+    ) -> Self;
 
     /// Constructs a [`TreatyActionsBySuffix`] endpoint variant.
     ///
@@ -2037,17 +2738,17 @@ pub trait NewEndpoint {
     ///
     /// - [`congress`]: The congress number.
     /// - [`treaty_number`]: The treaty number.
-    /// - [`treaty_suffix`]: The treaty suffix.
+    /// - [`treaty_suffix`]: The treaty suffix, a single letter such as `A`.
     /// - [`params`]: Parameters for treaty actions.
     fn new_treaty_actions_by_suffix(
-        congress: u32,
+        congress: impl Into<Congress>,
         treaty_number: u32,
-        treaty_suffix: String,
+        treaty_suffix: TreatySuffix,
         params: TreatyActionsParams,
     ) -> Self;
 
     fn new_bill_related(
-        congress: u32,
+        congress: impl Into<Congress>,
         bill_type: BillType,
         bill_number: u32,
         params: BillRelatedParams,
@@ -2055,7 +2756,7 @@ pub trait NewEndpoint {
 
     /// Constructs a [`BillSubjects`] endpoint variant.
     fn new_bill_subjects(
-        congress: u32,
+        congress: impl Into<Congress>,
         bill_type: BillType,
         bill_number: u32,
         params: BillSubjectsParams,
@@ -2063,7 +2764,7 @@ pub trait NewEndpoint {
 
     /// Constructs a [`BillSummaries`] endpoint variant.
     fn new_bill_summaries(
-        congress: u32,
+        congress: impl Into<Congress>,
         bill_type: BillType,
         bill_number: u32,
         params: BillSummariesParams,
@@ -2071,7 +2772,7 @@ pub trait NewEndpoint {
 
     /// Constructs a [`BillText`] endpoint variant.
     fn new_bill_text(
-        congress: u32,
+        congress: impl Into<Congress>,
         bill_type: BillType,
         bill_number: u32,
         params: BillTextParams,
@@ -2079,7 +2780,7 @@ pub trait NewEndpoint {
 
     /// Constructs a [`BillTitles`] endpoint variant.
     fn new_bill_titles(
-        congress: u32,
+        congress: impl Into<Congress>,
         bill_type: BillType,
         bill_number: u32,
         params: BillTitlesParams,
@@ -2094,20 +2795,20 @@ pub trait NewEndpoint {
 
     /// Constructs a [`CommitteeReportByCongress`] endpoint variant.
     fn new_committee_report_by_congress(
-        congress: u32,
+        congress: impl Into<Congress>,
         params: CommitteeReportByCongressParams,
     ) -> Self;
 
     /// Constructs a [`CommitteeReportByType`] endpoint variant.
     fn new_committee_report_by_type(
-        congress: u32,
+        congress: impl Into<Congress>,
         report_type: CommitteeReportType,
         params: CommitteeReportByTypeParams,
     ) -> Self;
 
     /// Constructs a [`CommitteeReportDetails`] endpoint variant.
     fn new_committee_report_details(
-        congress: u32,
+        congress: impl Into<Congress>,
         report_type: CommitteeReportType,
         report_number: u32,
         params: CommitteeReportDetailsParams,
@@ -2115,7 +2816,7 @@ pub trait NewEndpoint {
 
     /// Constructs a [`CommitteeReportText`] endpoint variant.
     fn new_committee_report_text(
-        congress: u32,
+        congress: impl Into<Congress>,
         report_type: CommitteeReportType,
         report_number: u32,
         params: CommitteeReportTextParams,
@@ -2130,27 +2831,36 @@ pub trait NewEndpoint {
 
     /// Constructs a [`CommitteePrintByCongress`] endpoint variant.
     fn new_committee_print_by_congress(
-        congress: u32,
+        congress: impl Into<Congress>,
         params: CommitteePrintByCongressParams,
     ) -> Self;
 
     /// Constructs a [`CommitteePrintByCongressChamber`] endpoint variant.
     fn new_committee_print_by_congress_chamber(
-        congress: u32,
+        congress: impl Into<Congress>,
         chamber: ChamberType,
         params: CommitteePrintByCongressChamberParams,
     ) -> Self;
 
-    /// Constructs a [`CommitteePrintByJacketNumber`] endpoint variant.
+    /// Constructs a [`Endpoints::CommitteePrintByJacketNumber`] endpoint variant.
+    #[deprecated(note = "use new_committee_print_details")]
     fn new_committee_print_by_jacket_number(
-        congress: u32,
+        congress: impl Into<Congress>,
+        jacket_number: u32,
+        params: CommitteePrintByJacketNumberParams,
+    ) -> Self;
+
+    /// Constructs a [`Endpoints::CommitteePrintDetails`] endpoint variant.
+    fn new_committee_print_details(
+        congress: impl Into<Congress>,
+        chamber: ChamberType,
         jacket_number: u32,
         params: CommitteePrintByJacketNumberParams,
     ) -> Self;
 
     /// Constructs a [`CommitteePrintText`] endpoint variant.
     fn new_committee_print_text(
-        congress: u32,
+        congress: impl Into<Congress>,
         chamber: String,
         jacket_number: u32,
         params: CommitteePrintDetailsParams,
@@ -2165,20 +2875,20 @@ pub trait NewEndpoint {
 
     /// Constructs a [`CommitteeMeetingByCongress`] endpoint variant.
     fn new_committee_meeting_by_congress(
-        congress: u32,
+        congress: impl Into<Congress>,
         params: CommitteeMeetingByCongressParams,
     ) -> Self;
 
     /// Constructs a [`CommitteeMeetingByChamber`] endpoint variant.
     fn new_committee_meeting_by_chamber(
-        congress: u32,
+        congress: impl Into<Congress>,
         chamber: ChamberType,
         params: CommitteeMeetingByChamberParams,
     ) -> Self;
 
     /// Constructs a [`CommitteeMeetingByEvent`] endpoint variant.
     fn new_committee_meeting_by_event(
-        congress: u32,
+        congress: impl Into<Congress>,
         chamber: ChamberType,
         event_id: String,
         params: CommitteeMeetingByEventParams,
@@ -2192,18 +2902,21 @@ pub trait NewEndpoint {
     fn new_hearing_list(params: HearingListParams) -> Self;
 
     /// Constructs a [`HearingByCongress`] endpoint variant.
-    fn new_hearing_by_congress(congress: u32, params: HearingByCongressParams) -> Self;
+    fn new_hearing_by_congress(
+        congress: impl Into<Congress>,
+        params: HearingByCongressParams,
+    ) -> Self;
 
     /// Constructs a [`HearingByChamber`] endpoint variant.
     fn new_hearing_by_chamber(
-        congress: u32,
+        congress: impl Into<Congress>,
         chamber: ChamberType,
         params: HearingByChamberParams,
     ) -> Self;
 
     /// Constructs a [`HearingByJacketNumber`] endpoint variant.
     fn new_hearing_by_jacket_number(
-        congress: u32,
+        congress: impl Into<Congress>,
         chamber: ChamberType,
         jacket_number: u32,
         params: HearingByJacketNumberParams,
@@ -2279,18 +2992,21 @@ pub trait NewEndpoint {
     fn new_house_communication_list(params: CommunicationParams) -> Self;
 
     /// Constructs a [`HouseCommunicationByCongress`] endpoint variant.
-    fn new_house_communication_by_congress(congress: u32, params: CommunicationParams) -> Self;
+    fn new_house_communication_by_congress(
+        congress: impl Into<Congress>,
+        params: CommunicationParams,
+    ) -> Self;
 
     /// Constructs a [`HouseCommunicationByType`] endpoint variant.
     fn new_house_communication_by_type(
-        congress: u32,
+        congress: impl Into<Congress>,
         communication_type: CommunicationType,
         params: CommunicationParams,
     ) -> Self;
 
     /// Constructs a [`HouseCommunicationDetails`] endpoint variant.
     fn new_house_communication_details(
-        congress: u32,
+        congress: impl Into<Congress>,
         communication_type: CommunicationType,
         communication_number: u32,
         params: CommunicationDetailsParams,
@@ -2320,18 +3036,21 @@ pub trait NewEndpoint {
     fn new_senate_communication_list(params: CommunicationParams) -> Self;
 
     /// Constructs a [`SenateCommunicationByCongress`] endpoint variant.
-    fn new_senate_communication_by_congress(congress: u32, params: CommunicationParams) -> Self;
+    fn new_senate_communication_by_congress(
+        congress: impl Into<Congress>,
+        params: CommunicationParams,
+    ) -> Self;
 
     /// Constructs a [`SenateCommunicationByType`] endpoint variant.
     fn new_senate_communication_by_type(
-        congress: u32,
+        congress: impl Into<Congress>,
         communication_type: CommunicationType,
         params: CommunicationParams,
     ) -> Self;
 
     /// Constructs a [`SenateCommunicationDetails`] endpoint variant.
     fn new_senate_communication_details(
-        congress: u32,
+        congress: impl Into<Congress>,
         communication_type: CommunicationType,
         communication_number: u32,
         params: CommunicationDetailsParams,
@@ -2352,78 +3071,82 @@ impl NewEndpoint for Endpoints {
         Endpoints::BillList(params)
     }
 
-    fn new_bill_by_congress(congress: u32, params: BillByCongressParams) -> Self {
-        Endpoints::BillByCongress(congress, params)
+    fn new_bill_by_congress(congress: impl Into<Congress>, params: BillByCongressParams) -> Self {
+        Endpoints::BillByCongress(congress.into(), params)
     }
 
-    fn new_bill_by_type(congress: u32, bill_type: BillType, params: BillByTypeParams) -> Self {
-        Endpoints::BillByType(congress, bill_type, params)
+    fn new_bill_by_type(
+        congress: impl Into<Congress>,
+        bill_type: BillType,
+        params: BillByTypeParams,
+    ) -> Self {
+        Endpoints::BillByType(congress.into(), bill_type, params)
     }
 
     fn new_bill_details(
-        congress: u32,
+        congress: impl Into<Congress>,
         bill_type: BillType,
         bill_number: u32,
         params: BillDetailsParams,
     ) -> Self {
-        Endpoints::BillDetails(congress, bill_type, bill_number, params)
+        Endpoints::BillDetails(congress.into(), bill_type, bill_number, params)
     }
 
     fn new_bill_actions(
-        congress: u32,
+        congress: impl Into<Congress>,
         bill_type: BillType,
         bill_number: u32,
         params: BillActionsParams,
     ) -> Self {
-        Endpoints::BillActions(congress, bill_type, bill_number, params)
+        Endpoints::BillActions(congress.into(), bill_type, bill_number, params)
     }
 
     fn new_bill_amendments(
-        congress: u32,
+        congress: impl Into<Congress>,
         bill_type: BillType,
         bill_number: u32,
         params: BillAmendmentsParams,
     ) -> Self {
-        Endpoints::BillAmendments(congress, bill_type, bill_number, params)
+        Endpoints::BillAmendments(congress.into(), bill_type, bill_number, params)
     }
 
     fn new_bill_committees(
-        congress: u32,
+        congress: impl Into<Congress>,
         bill_type: BillType,
         bill_number: u32,
         params: BillCommitteesParams,
     ) -> Self {
-        Endpoints::BillCommittees(congress, bill_type, bill_number, params)
+        Endpoints::BillCommittees(congress.into(), bill_type, bill_number, params)
     }
 
     fn new_bill_cosponsors(
-        congress: u32,
+        congress: impl Into<Congress>,
         bill_type: BillType,
         bill_number: u32,
         params: BillCosponsorsParams,
     ) -> Self {
-        Endpoints::BillCosponsors(congress, bill_type, bill_number, params)
+        Endpoints::BillCosponsors(congress.into(), bill_type, bill_number, params)
     }
 
     // ================================
     // Law Endpoints
     // ================================
 
-    fn new_law_type(congress: u32, law_type: LawType, params: LawParams) -> Self {
-        Endpoints::LawByType(congress, law_type, params)
+    fn new_law_type(congress: impl Into<Congress>, law_type: LawType, params: LawParams) -> Self {
+        Endpoints::LawByType(congress.into(), law_type, params)
     }
 
-    fn new_law_by_congress(congress: u32, params: LawParams) -> Self {
-        Endpoints::LawByCongress(congress, params)
+    fn new_law_by_congress(congress: impl Into<Congress>, params: LawParams) -> Self {
+        Endpoints::LawByCongress(congress.into(), params)
     }
 
     fn new_law_details(
-        congress: u32,
+        congress: impl Into<Congress>,
         law_type: LawType,
         law_number: u32,
         params: LawParams,
     ) -> Self {
-        Endpoints::LawDetails(congress, law_type, law_number, params)
+        Endpoints::LawDetails(congress.into(), law_type, law_number, params)
     }
 
     // ================================
@@ -2434,61 +3157,64 @@ impl NewEndpoint for Endpoints {
         Endpoints::AmendmentList(params)
     }
 
-    fn new_amendment_by_congress(congress: u32, params: AmendmentByCongressParams) -> Self {
-        Endpoints::AmendmentByCongress(congress, params)
+    fn new_amendment_by_congress(
+        congress: impl Into<Congress>,
+        params: AmendmentByCongressParams,
+    ) -> Self {
+        Endpoints::AmendmentByCongress(congress.into(), params)
     }
 
     fn new_amendment_by_type(
-        congress: u32,
+        congress: impl Into<Congress>,
         amendment_type: AmendmentType,
         params: AmendmentByTypeParams,
     ) -> Self {
-        Endpoints::AmendmentByType(congress, amendment_type, params)
+        Endpoints::AmendmentByType(congress.into(), amendment_type, params)
     }
 
     fn new_amendment_details(
-        congress: u32,
+        congress: impl Into<Congress>,
         amendment_type: AmendmentType,
         amendment_number: u32,
         params: AmendmentDetailsParams,
     ) -> Self {
-        Endpoints::AmendmentDetails(congress, amendment_type, amendment_number, params)
+        Endpoints::AmendmentDetails(congress.into(), amendment_type, amendment_number, params)
     }
 
     fn new_amendment_actions(
-        congress: u32,
+        congress: impl Into<Congress>,
         amendment_type: AmendmentType,
         amendment_number: String,
         params: AmendmentActionsParams,
     ) -> Self {
-        Endpoints::AmendmentActions(congress, amendment_type, amendment_number, params)
+        Endpoints::AmendmentActions(congress.into(), amendment_type, amendment_number, params)
     }
 
     fn new_amendment_cosponsors(
-        congress: u32,
+        congress: impl Into<Congress>,
         amendment_type: AmendmentType,
         amendment_number: String,
         params: AmendmentCosponsorsParams,
     ) -> Self {
-        Endpoints::AmendmentCosponsors(congress, amendment_type, amendment_number, params)
+        Endpoints::AmendmentCosponsors(congress.into(), amendment_type, amendment_number, params)
     }
 
     fn new_amendment_amendments(
-        congress: u32,
+        congress: impl Into<Congress>,
         amendment_type: AmendmentType,
         amendment_number: String,
         params: AmendmentAmendmentsParams,
     ) -> Self {
-        Endpoints::AmendmentAmendments(congress, amendment_type, amendment_number, params)
+        Endpoints::AmendmentAmendments(congress.into(), amendment_type, amendment_number, params)
     }
 
     fn new_amendment_text(
-        congress: u32,
+        congress: impl Into<Congress>,
         amendment_type: AmendmentType,
         amendment_number: String,
         params: AmendmentTextParams,
     ) -> Self {
-        Endpoints::AmendmentText(congress, amendment_type, amendment_number, params)
+        Endpoints::AmendmentText(congress.into(), amendment_type, amendment_number, params)
     }
 
     // ================================
@@ -2499,16 +3225,19 @@ impl NewEndpoint for Endpoints {
         Endpoints::SummariesList(params)
     }
 
-    fn new_summaries_by_congress(congress: u32, params: SummariesByCongressParams) -> Self {
-        Endpoints::SummariesByCongress(congress, params)
+    fn new_summaries_by_congress(
+        congress: impl Into<Congress>,
+        params: SummariesByCongressParams,
+    ) -> Self {
+        Endpoints::SummariesByCongress(congress.into(), params)
     }
 
     fn new_summaries_by_type(
-        congress: u32,
+        congress: impl Into<Congress>,
         bill_type: BillType,
         params: SummariesByTypeParams,
     ) -> Self {
-        Endpoints::SummariesByType(congress, bill_type, params)
+        Endpoints::SummariesByType(congress.into(), bill_type, params)
     }
 
     // ================================
@@ -2519,8 +3248,8 @@ impl NewEndpoint for Endpoints {
         Endpoints::CongressList(params)
     }
 
-    fn new_congress_details(congress: u32, params: CongressDetailsParams) -> Self {
-        Endpoints::CongressDetails(congress, params)
+    fn new_congress_details(congress: impl Into<Congress>, params: CongressDetailsParams) -> Self {
+        Endpoints::CongressDetails(congress.into(), params)
     }
 
     fn new_congress_current(params: CongressCurrentParams) -> Self {
@@ -2535,21 +3264,37 @@ impl NewEndpoint for Endpoints {
         Endpoints::MemberList(params)
     }
 
-    fn new_member_by_congress(congress: u32, params: MemberByCongressParams) -> Self {
-        Endpoints::MemberByCongress(congress, params)
+    fn new_member_by_congress(
+        congress: impl Into<Congress>,
+        params: MemberByCongressParams,
+    ) -> Self {
+        Endpoints::MemberByCongress(congress.into(), params)
     }
 
     fn new_member_by_state(state_code: String, params: MemberByStateParams) -> Self {
-        Endpoints::MemberByState(state_code, params)
+        Endpoints::MemberByState(state_code.trim().to_ascii_uppercase(), params)
+    }
+
+    fn new_member_by_state_district(
+        state_code: String,
+        district: u32,
+        params: MemberByStateDistrictParams,
+    ) -> Self {
+        Endpoints::MemberByStateDistrict(state_code.trim().to_ascii_uppercase(), district, params)
     }
 
     fn new_member_by_congress_state_district(
-        congress: u32,
+        congress: impl Into<Congress>,
         state_code: String,
         district: u32,
         params: MemberByCongressStateDistrictParams,
     ) -> Self {
-        Endpoints::MemberByCongressStateDistrict(congress, state_code, district, params)
+        Endpoints::MemberByCongressStateDistrict(
+            congress.into(),
+            state_code.trim().to_ascii_uppercase(),
+            district,
+            params,
+        )
     }
 
     fn new_member_details(bio_guide_id: String, params: MemberDetailsParams) -> Self {
@@ -2576,16 +3321,19 @@ impl NewEndpoint for Endpoints {
         Endpoints::CommitteeByChamber(chamber, params)
     }
 
-    fn new_committee_by_congress(congress: u32, params: CommitteeByCongressParams) -> Self {
-        Endpoints::CommitteeByCongress(congress, params)
+    fn new_committee_by_congress(
+        congress: impl Into<Congress>,
+        params: CommitteeByCongressParams,
+    ) -> Self {
+        Endpoints::CommitteeByCongress(congress.into(), params)
     }
 
     fn new_committee_by_congress_chamber(
-        congress: u32,
+        congress: impl Into<Congress>,
         chamber: ChamberType,
         params: CommitteeByCongressChamberParams,
     ) -> Self {
-        Endpoints::CommitteeByCongressChamber(congress, chamber, params)
+        Endpoints::CommitteeByCongressChamber(congress.into(), chamber, params)
     }
 
     fn new_committee_details(
@@ -2644,49 +3392,52 @@ impl NewEndpoint for Endpoints {
         Endpoints::NominationList(params)
     }
 
-    fn new_nomination_by_congress(congress: u32, params: NominationByCongressParams) -> Self {
-        Endpoints::NominationByCongress(congress, params)
+    fn new_nomination_by_congress(
+        congress: impl Into<Congress>,
+        params: NominationByCongressParams,
+    ) -> Self {
+        Endpoints::NominationByCongress(congress.into(), params)
     }
 
     fn new_nomination_details(
-        congress: u32,
+        congress: impl Into<Congress>,
         nomination_number: String,
         params: NominationDetailsParams,
     ) -> Self {
-        Endpoints::NominationDetails(congress, nomination_number, params)
+        Endpoints::NominationDetails(congress.into(), nomination_number, params)
     }
 
     fn new_nominees(
-        congress: u32,
+        congress: impl Into<Congress>,
         nomination_number: String,
         ordinal: u32,
         params: NomineesParams,
     ) -> Self {
-        Endpoints::Nominees(congress, nomination_number, ordinal, params)
+        Endpoints::Nominees(congress.into(), nomination_number, ordinal, params)
     }
 
     fn new_nomination_actions(
-        congress: u32,
+        congress: impl Into<Congress>,
         nomination_number: String,
         params: NominationActionsParams,
     ) -> Self {
-        Endpoints::NominationActions(congress, nomination_number, params)
+        Endpoints::NominationActions(congress.into(), nomination_number, params)
     }
 
     fn new_nomination_committees(
-        congress: u32,
+        congress: impl Into<Congress>,
         nomination_number: String,
         params: NominationCommitteesParams,
     ) -> Self {
-        Endpoints::NominationCommittees(congress, nomination_number, params)
+        Endpoints::NominationCommittees(congress.into(), nomination_number, params)
     }
 
     fn new_nomination_hearings(
-        congress: u32,
+        congress: impl Into<Congress>,
         nomination_number: String,
         params: NominationHearingsParams,
     ) -> Self {
-        Endpoints::NominationHearings(congress, nomination_number, params)
+        Endpoints::NominationHearings(congress.into(), nomination_number, params)
     }
 
     // ================================
@@ -2697,87 +3448,108 @@ impl NewEndpoint for Endpoints {
         Endpoints::TreatyList(params)
     }
 
-    fn new_treaty_by_congress(congress: u32, params: TreatyByCongressParams) -> Self {
-        Endpoints::TreatyByCongress(congress, params)
+    fn new_treaty_by_congress(
+        congress: impl Into<Congress>,
+        params: TreatyByCongressParams,
+    ) -> Self {
+        Endpoints::TreatyByCongress(congress.into(), params)
     }
 
-    fn new_treaty_details(congress: u32, treaty_number: u32, params: TreatyDetailsParams) -> Self {
-        Endpoints::TreatyDetails(congress, treaty_number, params)
+    fn new_treaty_details(
+        congress: impl Into<Congress>,
+        treaty_number: u32,
+        params: TreatyDetailsParams,
+    ) -> Self {
+        Endpoints::TreatyDetails(congress.into(), treaty_number, params)
     }
 
     fn new_treaty_partitioned(
-        congress: u32,
+        congress: impl Into<Congress>,
         treaty_number: u32,
-        treaty_suffix: String,
+        treaty_suffix: TreatySuffix,
         params: TreatyPartitionedParams,
     ) -> Self {
-        Endpoints::TreatyPartitioned(congress, treaty_number, treaty_suffix, params)
+        Endpoints::TreatyPartitioned(
+            congress.into(),
+            treaty_number,
+            treaty_suffix,
+            params,
+        )
     }
 
     fn new_treaty_committees(
-        congress: u32,
+        congress: impl Into<Congress>,
         treaty_number: u32,
         params: TreatyCommitteesParams,
     ) -> Self {
-        Endpoints::TreatyCommittees(congress, treaty_number, params)
+        Endpoints::TreatyCommittees(congress.into(), treaty_number, params)
     }
 
-    fn new_treaty_actions(congress: u32, treaty_number: u32, params: TreatyActionsParams) -> Self {
-        Endpoints::TreatyActions(congress, treaty_number, params)
+    fn new_treaty_actions(
+        congress: impl Into<Congress>,
+        treaty_number: u32,
+        params: TreatyActionsParams,
+    ) -> Self {
+        Endpoints::TreatyActions(congress.into(), treaty_number, params)
     }
 
     fn new_treaty_actions_by_suffix(
-        congress: u32,
+        congress: impl Into<Congress>,
         treaty_number: u32,
-        treaty_suffix: String,
+        treaty_suffix: TreatySuffix,
         params: TreatyActionsParams,
     ) -> Self {
-        Endpoints::TreatyActionsBySuffix(congress, treaty_number, treaty_suffix, params)
+        Endpoints::TreatyActionsBySuffix(
+            congress.into(),
+            treaty_number,
+            treaty_suffix,
+            params,
+        )
     }
 
     fn new_bill_related(
-        congress: u32,
+        congress: impl Into<Congress>,
         bill_type: BillType,
         bill_number: u32,
         params: BillRelatedParams,
     ) -> Self {
-        Endpoints::BillRelated(congress, bill_type, bill_number, params)
+        Endpoints::BillRelated(congress.into(), bill_type, bill_number, params)
     }
 
     fn new_bill_subjects(
-        congress: u32,
+        congress: impl Into<Congress>,
         bill_type: BillType,
         bill_number: u32,
         params: BillSubjectsParams,
     ) -> Self {
-        Endpoints::BillSubjects(congress, bill_type, bill_number, params)
+        Endpoints::BillSubjects(congress.into(), bill_type, bill_number, params)
     }
 
     fn new_bill_summaries(
-        congress: u32,
+        congress: impl Into<Congress>,
         bill_type: BillType,
         bill_number: u32,
         params: BillSummariesParams,
     ) -> Self {
-        Endpoints::BillSummaries(congress, bill_type, bill_number, params)
+        Endpoints::BillSummaries(congress.into(), bill_type, bill_number, params)
     }
 
     fn new_bill_text(
-        congress: u32,
+        congress: impl Into<Congress>,
         bill_type: BillType,
         bill_number: u32,
         params: BillTextParams,
     ) -> Self {
-        Endpoints::BillText(congress, bill_type, bill_number, params)
+        Endpoints::BillText(congress.into(), bill_type, bill_number, params)
     }
 
     fn new_bill_titles(
-        congress: u32,
+        congress: impl Into<Congress>,
         bill_type: BillType,
         bill_number: u32,
         params: BillTitlesParams,
     ) -> Self {
-        Endpoints::BillTitles(congress, bill_type, bill_number, params)
+        Endpoints::BillTitles(congress.into(), bill_type, bill_number, params)
     }
 
     // ================================
@@ -2789,36 +3561,36 @@ impl NewEndpoint for Endpoints {
     }
 
     fn new_committee_report_by_congress(
-        congress: u32,
+        congress: impl Into<Congress>,
         params: CommitteeReportByCongressParams,
     ) -> Self {
-        Endpoints::CommitteeReportByCongress(congress, params)
+        Endpoints::CommitteeReportByCongress(congress.into(), params)
     }
 
     fn new_committee_report_by_type(
-        congress: u32,
+        congress: impl Into<Congress>,
         report_type: CommitteeReportType,
         params: CommitteeReportByTypeParams,
     ) -> Self {
-        Endpoints::CommitteeReportByType(congress, report_type, params)
+        Endpoints::CommitteeReportByType(congress.into(), report_type, params)
     }
 
     fn new_committee_report_details(
-        congress: u32,
+        congress: impl Into<Congress>,
         report_type: CommitteeReportType,
         report_number: u32,
         params: CommitteeReportDetailsParams,
     ) -> Self {
-        Endpoints::CommitteeReportDetails(congress, report_type, report_number, params)
+        Endpoints::CommitteeReportDetails(congress.into(), report_type, report_number, params)
     }
 
     fn new_committee_report_text(
-        congress: u32,
+        congress: impl Into<Congress>,
         report_type: CommitteeReportType,
         report_number: u32,
         params: CommitteeReportTextParams,
     ) -> Self {
-        Endpoints::CommitteeReportText(congress, report_type, report_number, params)
+        Endpoints::CommitteeReportText(congress.into(), report_type, report_number, params)
     }
 
     // ================================
@@ -2830,35 +3602,45 @@ impl NewEndpoint for Endpoints {
     }
 
     fn new_committee_print_by_congress(
-        congress: u32,
+        congress: impl Into<Congress>,
         params: CommitteePrintByCongressParams,
     ) -> Self {
-        Endpoints::CommitteePrintByCongress(congress, params)
+        Endpoints::CommitteePrintByCongress(congress.into(), params)
     }
 
     fn new_committee_print_by_congress_chamber(
-        congress: u32,
+        congress: impl Into<Congress>,
         chamber: ChamberType,
         params: CommitteePrintByCongressChamberParams,
     ) -> Self {
-        Endpoints::CommitteePrintByCongressChamber(congress, chamber, params)
+        Endpoints::CommitteePrintByCongressChamber(congress.into(), chamber, params)
     }
 
+    #[allow(deprecated)]
     fn new_committee_print_by_jacket_number(
-        congress: u32,
+        congress: impl Into<Congress>,
         jacket_number: u32,
         params: CommitteePrintByJacketNumberParams,
     ) -> Self {
-        Endpoints::CommitteePrintByJacketNumber(congress, jacket_number, params)
+        Endpoints::CommitteePrintByJacketNumber(congress.into(), jacket_number, params)
+    }
+
+    fn new_committee_print_details(
+        congress: impl Into<Congress>,
+        chamber: ChamberType,
+        jacket_number: u32,
+        params: CommitteePrintByJacketNumberParams,
+    ) -> Self {
+        Endpoints::CommitteePrintDetails(congress.into(), chamber, jacket_number, params)
     }
 
     fn new_committee_print_text(
-        congress: u32,
+        congress: impl Into<Congress>,
         chamber: String,
         jacket_number: u32,
         params: CommitteePrintDetailsParams,
     ) -> Self {
-        Endpoints::CommitteePrintText(congress, chamber, jacket_number, params)
+        Endpoints::CommitteePrintText(congress.into(), chamber, jacket_number, params)
     }
 
     // ================================
@@ -2870,26 +3652,26 @@ impl NewEndpoint for Endpoints {
     }
 
     fn new_committee_meeting_by_congress(
-        congress: u32,
+        congress: impl Into<Congress>,
         params: CommitteeMeetingByCongressParams,
     ) -> Self {
-        Endpoints::CommitteeMeetingByCongress(congress, params)
+        Endpoints::CommitteeMeetingByCongress(congress.into(), params)
     }
 
     fn new_committee_meeting_by_chamber(
-        congress: u32,
+        congress: impl Into<Congress>,
         chamber: ChamberType,
         params: CommitteeMeetingByChamberParams,
     ) -> Self {
-        Endpoints::CommitteeMeetingByChamber(congress, chamber, params)
+        Endpoints::CommitteeMeetingByChamber(congress.into(), chamber, params)
     }
     fn new_committee_meeting_by_event(
-        congress: u32,
+        congress: impl Into<Congress>,
         chamber: ChamberType,
         event_id: String,
         params: CommitteeMeetingByEventParams,
     ) -> Self {
-        Endpoints::CommitteeMeetingByEvent(congress, chamber, event_id, params)
+        Endpoints::CommitteeMeetingByEvent(congress.into(), chamber, event_id, params)
     }
 
     // ================================
@@ -2900,25 +3682,28 @@ impl NewEndpoint for Endpoints {
         Endpoints::HearingList(params)
     }
 
-    fn new_hearing_by_congress(congress: u32, params: HearingByCongressParams) -> Self {
-        Endpoints::HearingByCongress(congress, params)
+    fn new_hearing_by_congress(
+        congress: impl Into<Congress>,
+        params: HearingByCongressParams,
+    ) -> Self {
+        Endpoints::HearingByCongress(congress.into(), params)
     }
 
     fn new_hearing_by_chamber(
-        congress: u32,
+        congress: impl Into<Congress>,
         chamber: ChamberType,
         params: HearingByChamberParams,
     ) -> Self {
-        Endpoints::HearingByChamber(congress, chamber, params)
+        Endpoints::HearingByChamber(congress.into(), chamber, params)
     }
 
     fn new_hearing_by_jacket_number(
-        congress: u32,
+        congress: impl Into<Congress>,
         chamber: ChamberType,
         jacket_number: u32,
         params: HearingByJacketNumberParams,
     ) -> Self {
-        Endpoints::HearingByJacketNumber(congress, chamber, jacket_number, params)
+        Endpoints::HearingByJacketNumber(congress.into(), chamber, jacket_number, params)
     }
 
     // ================================
@@ -3000,26 +3785,29 @@ impl NewEndpoint for Endpoints {
         Endpoints::HouseCommunicationList(params)
     }
 
-    fn new_house_communication_by_congress(congress: u32, params: CommunicationParams) -> Self {
-        Endpoints::HouseCommunicationByCongress(congress, params)
+    fn new_house_communication_by_congress(
+        congress: impl Into<Congress>,
+        params: CommunicationParams,
+    ) -> Self {
+        Endpoints::HouseCommunicationByCongress(congress.into(), params)
     }
 
     fn new_house_communication_by_type(
-        congress: u32,
+        congress: impl Into<Congress>,
         communication_type: CommunicationType,
         params: CommunicationParams,
     ) -> Self {
-        Endpoints::HouseCommunicationByType(congress, communication_type, params)
+        Endpoints::HouseCommunicationByType(congress.into(), communication_type, params)
     }
 
     fn new_house_communication_details(
-        congress: u32,
+        congress: impl Into<Congress>,
         communication_type: CommunicationType,
         communication_number: u32,
         params: CommunicationDetailsParams,
     ) -> Self {
         Endpoints::HouseCommunicationDetails(
-            congress,
+            congress.into(),
             communication_type,
             communication_number,
             params,
@@ -3053,29 +3841,193 @@ impl NewEndpoint for Endpoints {
         Endpoints::SenateCommunicationList(params)
     }
 
-    fn new_senate_communication_by_congress(congress: u32, params: CommunicationParams) -> Self {
-        Endpoints::SenateCommunicationByCongress(congress, params)
+    fn new_senate_communication_by_congress(
+        congress: impl Into<Congress>,
+        params: CommunicationParams,
+    ) -> Self {
+        Endpoints::SenateCommunicationByCongress(congress.into(), params)
     }
 
     fn new_senate_communication_by_type(
-        congress: u32,
+        congress: impl Into<Congress>,
         communication_type: CommunicationType,
         params: CommunicationParams,
     ) -> Self {
-        Endpoints::SenateCommunicationByType(congress, communication_type, params)
+        Endpoints::SenateCommunicationByType(congress.into(), communication_type, params)
     }
 
     fn new_senate_communication_details(
-        congress: u32,
+        congress: impl Into<Congress>,
         communication_type: CommunicationType,
         communication_number: u32,
         params: CommunicationDetailsParams,
     ) -> Self {
         Endpoints::SenateCommunicationDetails(
-            congress,
+            congress.into(),
             communication_type,
             communication_number,
             params,
         )
     }
 }
+
+/// Builder returned by [`Endpoints::member`].
+///
+/// Each method delegates to the matching [`NewEndpoint`] constructor, so state codes are
+/// uppercased the same way.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MemberEndpoints;
+
+impl MemberEndpoints {
+    /// `member`: all members.
+    pub fn list(self, params: MemberListParams) -> Endpoints {
+        Endpoints::new_member_list(params)
+    }
+
+    /// `member/congress/{congress}`: members of a congress.
+    pub fn by_congress(
+        self,
+        congress: impl Into<Congress>,
+        params: MemberByCongressParams,
+    ) -> Endpoints {
+        Endpoints::new_member_by_congress(congress, params)
+    }
+
+    /// `member/{stateCode}`: members from a state.
+    pub fn by_state(self, state_code: impl Into<String>, params: MemberByStateParams) -> Endpoints {
+        Endpoints::new_member_by_state(state_code.into(), params)
+    }
+
+    /// `member/{stateCode}/{district}`: members from a congressional district.
+    pub fn by_state_district(
+        self,
+        state_code: impl Into<String>,
+        district: u32,
+        params: MemberByStateDistrictParams,
+    ) -> Endpoints {
+        Endpoints::new_member_by_state_district(state_code.into(), district, params)
+    }
+
+    /// `member/congress/{congress}/{stateCode}/{district}`: members from a congressional
+    /// district in a specific congress.
+    pub fn by_congress_state_district(
+        self,
+        congress: impl Into<Congress>,
+        state_code: impl Into<String>,
+        district: u32,
+        params: MemberByCongressStateDistrictParams,
+    ) -> Endpoints {
+        Endpoints::new_member_by_congress_state_district(
+            congress,
+            state_code.into(),
+            district,
+            params,
+        )
+    }
+
+    /// `member/{bioguideId}`: details of a member.
+    pub fn details(
+        self,
+        bio_guide_id: impl Into<String>,
+        params: MemberDetailsParams,
+    ) -> Endpoints {
+        Endpoints::new_member_details(bio_guide_id.into(), params)
+    }
+
+    /// `member/{bioguideId}/sponsored-legislation`: legislation sponsored by a member.
+    pub fn sponsored_legislation(
+        self,
+        bio_guide_id: impl Into<String>,
+        params: SponsorshipListParams,
+    ) -> Endpoints {
+        Endpoints::new_sponsorship_list(bio_guide_id.into(), params)
+    }
+
+    /// `member/{bioguideId}/cosponsored-legislation`: legislation cosponsored by a member.
+    pub fn cosponsored_legislation(
+        self,
+        bio_guide_id: impl Into<String>,
+        params: CosponsorshipListParams,
+    ) -> Endpoints {
+        Endpoints::new_cosponsorship_list(bio_guide_id.into(), params)
+    }
+}
+
+/// Builder returned by [`Endpoints::treaty`].
+///
+/// Each method delegates to the matching [`NewEndpoint`] constructor. Treaties split into
+/// parts have suffixed endpoints: [`TreatyEndpoints::partitioned`] and
+/// [`TreatyEndpoints::actions_by_suffix`], which take a validated [`TreatySuffix`].
+#[derive(Debug, Clone, Copy)]
+pub struct TreatyEndpoints {
+    congress: Congress,
+    number: u32,
+}
+
+impl TreatyEndpoints {
+    /// `treaty/{congress}/{treatyNumber}`: details of the treaty.
+    pub fn details(self, params: TreatyDetailsParams) -> Endpoints {
+        Endpoints::new_treaty_details(self.congress, self.number, params)
+    }
+
+    /// `treaty/{congress}/{treatyNumber}/actions`: actions on the treaty.
+    pub fn actions(self, params: TreatyActionsParams) -> Endpoints {
+        Endpoints::new_treaty_actions(self.congress, self.number, params)
+    }
+
+    /// `treaty/{congress}/{treatyNumber}/committees`: committees associated with the treaty.
+    pub fn committees(self, params: TreatyCommitteesParams) -> Endpoints {
+        Endpoints::new_treaty_committees(self.congress, self.number, params)
+    }
+
+    /// `treaty/{congress}/{treatyNumber}/{treatySuffix}`: details of one part of a
+    /// partitioned treaty.
+    pub fn partitioned(self, suffix: TreatySuffix, params: TreatyPartitionedParams) -> Endpoints {
+        Endpoints::new_treaty_partitioned(self.congress, self.number, suffix, params)
+    }
+
+    /// `treaty/{congress}/{treatyNumber}/{treatySuffix}/actions`: actions on one part of a
+    /// partitioned treaty.
+    pub fn actions_by_suffix(self, suffix: TreatySuffix, params: TreatyActionsParams) -> Endpoints {
+        Endpoints::new_treaty_actions_by_suffix(self.congress, self.number, suffix, params)
+    }
+}
+
+/// Builder returned by [`Endpoints::nomination`].
+///
+/// Each method delegates to the matching [`NewEndpoint`] constructor.
+#[derive(Debug, Clone)]
+pub struct NominationEndpoints {
+    congress: Congress,
+    number: String,
+}
+
+impl NominationEndpoints {
+    /// `nomination/{congress}/{nominationNumber}`: details of the nomination.
+    pub fn details(self, params: NominationDetailsParams) -> Endpoints {
+        Endpoints::new_nomination_details(self.congress, self.number, params)
+    }
+
+    /// `nomination/{congress}/{nominationNumber}/actions`: actions on the nomination.
+    pub fn actions(self, params: NominationActionsParams) -> Endpoints {
+        Endpoints::new_nomination_actions(self.congress, self.number, params)
+    }
+
+    /// `nomination/{congress}/{nominationNumber}/committees`: committees associated with the
+    /// nomination.
+    pub fn committees(self, params: NominationCommitteesParams) -> Endpoints {
+        Endpoints::new_nomination_committees(self.congress, self.number, params)
+    }
+
+    /// `nomination/{congress}/{nominationNumber}/hearings`: printed hearings on the
+    /// nomination.
+    pub fn hearings(self, params: NominationHearingsParams) -> Endpoints {
+        Endpoints::new_nomination_hearings(self.congress, self.number, params)
+    }
+
+    /// `nomination/{congress}/{nominationNumber}/{ordinal}`: the nominees in one position of
+    /// the nomination.
+    pub fn nominees(self, ordinal: u32, params: NomineesParams) -> Endpoints {
+        Endpoints::new_nominees(self.congress, self.number, ordinal, params)
+    }
+}
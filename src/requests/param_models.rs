@@ -21,13 +21,19 @@
 //! ```
 
 use crate::cdg_types::*;
+use crate::client::cdg_client::ApiClientError;
 use serde::{Deserialize, Serialize};
 
 // ================================
 // Endpoint-Specific Parameter Structs
 // ================================
 
-#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+/// Parameters for the [`Generic`](crate::endpoints::Endpoints::Generic) endpoint.
+///
+/// Unlike the other parameter structs, the format defaults to [`FormatType::Json`]: the
+/// client can only deserialize JSON, and a generic request is usually fetched as
+/// [`GenericResponse`](crate::response_models::GenericResponse) or another JSON model.
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct GenericParams {
     /// Desired response format (JSON or XML).
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -67,6 +73,25 @@ pub struct GenericParams {
     pub chamber: Option<ChamberType>,
 }
 
+impl Default for GenericParams {
+    fn default() -> Self {
+        Self {
+            format: Some(FormatType::Json),
+            offset: None,
+            limit: None,
+            from_date_time: None,
+            to_date_time: None,
+            sort: None,
+            conference: None,
+            current_member: None,
+            year: None,
+            month: None,
+            day: None,
+            chamber: None,
+        }
+    }
+}
+
 impl GenericParams {
     pub fn new(
         format: Option<FormatType>,
@@ -99,6 +124,71 @@ impl GenericParams {
     }
 }
 
+impl GenericParams {
+    /// Checks that the parameters do not contradict each other.
+    ///
+    /// The rules are:
+    ///
+    /// - `month` requires `year`, and `day` requires `month`.
+    /// - `month` must be within `1..=12` and `day` within `1..=31`.
+    /// - `year`/`month`/`day` cannot be combined with `from_date_time`/`to_date_time`.
+    /// - `from_date_time` must not be later than `to_date_time`.
+    ///
+    /// Called by [`CongressApiClient::fetch`](crate::CongressApiClient::fetch) before sending.
+    ///
+    /// # Returns
+    ///
+    /// - `Err(ApiClientError::InvalidParam)`: Naming the first rule that is broken.
+    pub fn validate(&self) -> Result<(), ApiClientError> {
+        validate_date_parts(self.year, self.month, self.day)?;
+
+        let has_date_parts = self.year.is_some() || self.month.is_some() || self.day.is_some();
+        let has_date_range = self.from_date_time.is_some() || self.to_date_time.is_some();
+        if has_date_parts && has_date_range {
+            return Err(ApiClientError::InvalidParam(
+                "year/month/day cannot be combined with fromDateTime/toDateTime".to_string(),
+            ));
+        }
+
+        // ISO 8601 timestamps in the same format order lexicographically.
+        if let (Some(from), Some(to)) = (&self.from_date_time, &self.to_date_time) {
+            if from > to {
+                return Err(ApiClientError::InvalidParam(format!(
+                    "fromDateTime {} is later than toDateTime {}",
+                    from, to
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Checks a `year`/`month`/`day` filter: each part requires the one before it, and the month
+/// and day must be in range.
+fn validate_date_parts(
+    year: Option<u32>,
+    month: Option<u32>,
+    day: Option<u32>,
+) -> Result<(), ApiClientError> {
+    let invalid = |message: &str| Err(ApiClientError::InvalidParam(message.to_string()));
+
+    if month.is_some() && year.is_none() {
+        return invalid("month requires year");
+    }
+    if day.is_some() && month.is_none() {
+        return invalid("day requires month");
+    }
+    if month.is_some_and(|month| !(1..=12).contains(&month)) {
+        return invalid("month must be between 1 and 12");
+    }
+    if day.is_some_and(|day| !(1..=31).contains(&day)) {
+        return invalid("day must be between 1 and 31");
+    }
+
+    Ok(())
+}
+
 /// Parameters for the [`BillList`] endpoint.
 ///
 /// These parameters allow filtering and pagination when listing bills.
@@ -829,6 +919,8 @@ pub struct CommitteeReportListParams {
     /// Desired response format (JSON or XML).
     pub format: Option<FormatType>,
 
+    /// Whether to list only conference reports (`true`), filed jointly by House and Senate
+    /// conferees to reconcile a bill, or to exclude them (`false`).
     pub conference: Option<bool>,
 
     /// Number of items to skip before starting to collect the result set.
@@ -844,6 +936,18 @@ pub struct CommitteeReportListParams {
     pub to_date_time: Option<String>,
 }
 
+impl CommitteeReportListParams {
+    /// Returns params listing only conference reports.
+    ///
+    /// Conference reports are a distinct subset of committee reports: the compromise text
+    /// agreed by House and Senate conferees, as opposed to a single committee's report on a
+    /// bill. Further params can be chained, e.g.
+    /// `CommitteeReportListParams::conference_only().format(FormatType::Json)`.
+    pub fn conference_only() -> Self {
+        Self::default().conference(true)
+    }
+}
+
 /// Parameters for the [`CommitteeReportByCongress`] endpoint.
 ///
 /// These parameters allow filtering and pagination when retrieving committee reports
@@ -1163,6 +1267,20 @@ pub struct CongressionalRecordListParams {
     pub limit: Option<u32>,
 }
 
+impl CongressionalRecordListParams {
+    /// Checks that the `year`/`month`/`day` filter is consistent: `month` requires `year`,
+    /// `day` requires `month`, and both must be in range.
+    ///
+    /// Called by [`CongressApiClient::fetch`](crate::CongressApiClient::fetch) before sending.
+    ///
+    /// # Returns
+    ///
+    /// - `Err(ApiClientError::InvalidParam)`: Naming the first rule that is broken.
+    pub fn validate(&self) -> Result<(), ApiClientError> {
+        validate_date_parts(self.year, self.month, self.day)
+    }
+}
+
 // ===============================================
 // Daily Congressional Record Endpoints Parameters
 // ===============================================
@@ -0,0 +1,209 @@
+//! # `csv_export` Module
+//!
+//! Available with the `csv` feature. Adds a `to_csv()` method to the list response models so
+//! their items can be exported to spreadsheets. The API itself does not serve CSV; the export
+//! is performed client-side on an already deserialized response.
+//!
+//! Each row holds a flat selection of the item's most useful fields; nested values such as
+//! the latest action are reduced to their date.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use cdg_api::response_models::BillsResponse;
+//!
+//! fn export(response: &BillsResponse) -> Result<(), csv::Error> {
+//!     std::fs::write("bills.csv", response.to_csv()?)?;
+//!     Ok(())
+//! }
+//! ```
+
+use super::response_models::*;
+
+/// Writes the header and rows to an in-memory CSV document.
+fn write_csv<I>(headers: &[&str], rows: I) -> Result<String, csv::Error>
+where
+    I: IntoIterator<Item = Vec<String>>,
+{
+    let mut writer = csv::Writer::from_writer(vec![]);
+    writer.write_record(headers)?;
+    for row in rows {
+        writer.write_record(&row)?;
+    }
+
+    let bytes = writer.into_inner().map_err(|e| e.into_error())?;
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+/// Converts an optional field to its CSV cell value, using an empty cell for [`None`].
+fn cell<T: ToString>(value: &Option<T>) -> String {
+    value.as_ref().map(|v| v.to_string()).unwrap_or_default()
+}
+
+/// Returns the action date of an optional latest action.
+fn action_date(action: &Option<LatestAction>) -> String {
+    action
+        .as_ref()
+        .map(|a| cell(&a.action_date))
+        .unwrap_or_default()
+}
+
+impl BillsResponse {
+    /// Serializes the bills to CSV with the columns
+    /// `number,title,congress,origin_chamber,latest_action_date`.
+    pub fn to_csv(&self) -> Result<String, csv::Error> {
+        write_csv(
+            &[
+                "number",
+                "title",
+                "congress",
+                "origin_chamber",
+                "latest_action_date",
+            ],
+            self.bills.iter().map(|bill| {
+                vec![
+                    cell(&bill.number),
+                    cell(&bill.title),
+                    cell(&bill.congress),
+                    cell(&bill.origin_chamber.map(|chamber| chamber.label())),
+                    action_date(&bill.latest_action),
+                ]
+            }),
+        )
+    }
+}
+
+impl LawsResponse {
+    /// Serializes the laws to CSV with the columns
+    /// `bill_number,law_number,title,congress,origin_chamber,latest_action_date`.
+    ///
+    /// `law_number` cites the laws the bill became, e.g. `Public Law 118-5`, separated by
+    /// `; ` when there are several.
+    pub fn to_csv(&self) -> Result<String, csv::Error> {
+        write_csv(
+            &[
+                "bill_number",
+                "law_number",
+                "title",
+                "congress",
+                "origin_chamber",
+                "latest_action_date",
+            ],
+            self.bills.iter().map(|law| {
+                vec![
+                    cell(&law.number),
+                    law.laws
+                        .iter()
+                        .flatten()
+                        .map(|reference| reference.to_string())
+                        .collect::<Vec<_>>()
+                        .join("; "),
+                    cell(&law.title),
+                    cell(&law.congress),
+                    cell(&law.origin_chamber),
+                    action_date(&law.latest_action),
+                ]
+            }),
+        )
+    }
+}
+
+impl AmendmentsResponse {
+    /// Serializes the amendments to CSV with the columns
+    /// `number,type,congress,purpose,latest_action_date`.
+    pub fn to_csv(&self) -> Result<String, csv::Error> {
+        write_csv(
+            &[
+                "number",
+                "type",
+                "congress",
+                "purpose",
+                "latest_action_date",
+            ],
+            self.amendments.iter().map(|amendment| {
+                vec![
+                    cell(&amendment.number),
+                    cell(
+                        &amendment
+                            .amendment_type
+                            .map(|t| t.to_string().to_uppercase()),
+                    ),
+                    cell(&amendment.congress),
+                    cell(&amendment.purpose),
+                    action_date(&amendment.latest_action),
+                ]
+            }),
+        )
+    }
+}
+
+impl MembersResponse {
+    /// Serializes the members to CSV with the columns
+    /// `bioguide_id,name,state,district,party_name`.
+    pub fn to_csv(&self) -> Result<String, csv::Error> {
+        write_csv(
+            &["bioguide_id", "name", "state", "district", "party_name"],
+            self.members.iter().map(|member| {
+                vec![
+                    cell(&member.bioguide_id),
+                    cell(&member.name),
+                    cell(&member.state),
+                    cell(&member.district),
+                    cell(&member.party_name),
+                ]
+            }),
+        )
+    }
+}
+
+impl NominationsResponse {
+    /// Serializes the nominations to CSV with the columns
+    /// `number,citation,congress,description,received_date,latest_action_date`.
+    pub fn to_csv(&self) -> Result<String, csv::Error> {
+        write_csv(
+            &[
+                "number",
+                "citation",
+                "congress",
+                "description",
+                "received_date",
+                "latest_action_date",
+            ],
+            self.nominations.iter().map(|nomination| {
+                vec![
+                    cell(&nomination.number),
+                    cell(&nomination.citation),
+                    cell(&nomination.congress),
+                    cell(&nomination.description),
+                    cell(&nomination.received_date),
+                    action_date(&nomination.latest_action),
+                ]
+            }),
+        )
+    }
+}
+
+impl TreatiesResponse {
+    /// Serializes the treaties to CSV with the columns
+    /// `number,suffix,congress_received,topic,transmitted_date`.
+    pub fn to_csv(&self) -> Result<String, csv::Error> {
+        write_csv(
+            &[
+                "number",
+                "suffix",
+                "congress_received",
+                "topic",
+                "transmitted_date",
+            ],
+            self.treaties.iter().map(|treaty| {
+                vec![
+                    cell(&treaty.number),
+                    cell(&treaty.suffix),
+                    cell(&treaty.congress_received),
+                    cell(&treaty.topic),
+                    cell(&treaty.transmitted_date),
+                ]
+            }),
+        )
+    }
+}
@@ -0,0 +1,70 @@
+//! # `datetime` Module
+//!
+//! Available with the `chrono` feature. Adds a `datetime()` method to the action models,
+//! combining the `actionDate` (`2024-01-10`) and optional `actionTime` (`14:05:33`) fields
+//! into a single [`NaiveDateTime`], and parsed date accessors to [`Session`].
+//!
+//! The API gives no time zone; its times are Eastern Time, the local time of Congress.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use cdg_api::response_models::LatestAction;
+//!
+//! fn print_when(action: &LatestAction) {
+//!     if let Some(when) = action.datetime() {
+//!         println!("{}", when); // e.g. 2024-01-10 14:05:33
+//!     }
+//! }
+//! ```
+
+use super::response_models::{BillAction, LatestAction, Session};
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+/// Parses a `YYYY-MM-DD` date, returning [`None`] if it is missing or malformed.
+fn parse_date(date: &Option<String>) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(date.as_deref()?, "%Y-%m-%d").ok()
+}
+
+/// Combines an action date with its optional time.
+///
+/// Returns [`None`] if the date is missing or malformed. A missing or malformed time yields
+/// midnight on that date.
+fn combine(date: &Option<String>, time: &Option<String>) -> Option<NaiveDateTime> {
+    let date = parse_date(date)?;
+    let time = time
+        .as_deref()
+        .and_then(|time| NaiveTime::parse_from_str(time, "%H:%M:%S").ok())
+        .unwrap_or(NaiveTime::MIN);
+
+    Some(date.and_time(time))
+}
+
+impl LatestAction {
+    /// Returns the date and time of the action, or midnight on its date if the API gives no
+    /// time.
+    pub fn datetime(&self) -> Option<NaiveDateTime> {
+        combine(&self.action_date, &self.action_time)
+    }
+}
+
+impl BillAction {
+    /// Returns the date and time of the action, or midnight on its date if the API gives no
+    /// time.
+    pub fn datetime(&self) -> Option<NaiveDateTime> {
+        combine(&self.action_date, &self.action_time)
+    }
+}
+
+impl Session {
+    /// Returns the parsed `startDate` of the session.
+    pub fn start(&self) -> Option<NaiveDate> {
+        parse_date(&self.start_date)
+    }
+
+    /// Returns the parsed `endDate` of the session, or [`None`] while it is ongoing; see
+    /// [`Session::is_ongoing`].
+    pub fn end(&self) -> Option<NaiveDate> {
+        parse_date(&self.end_date)
+    }
+}
@@ -1 +1,7 @@
+#[cfg(feature = "csv")]
+mod csv_export;
+#[cfg(feature = "chrono")]
+mod datetime;
 pub mod response_models;
+#[cfg(feature = "streaming")]
+pub mod streaming;
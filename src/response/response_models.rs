@@ -19,18 +19,32 @@
 //! }
 //! ```
 
+use crate::cdg_types::{AmendmentType, BillType, ChamberType, TreatySuffix};
+use crate::endpoints::Endpoints;
+use crate::param_models::MemberDetailsParams;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+/// Base URL of the congress.gov website, against which relative resource URLs are resolved.
+const CONGRESS_GOV_URL: &str = "https://www.congress.gov";
 
 /// Marker trait implemented by all primary response types.
 ///
 /// A primary response is a top-level response model that represents the main data structure
 /// returned by an API endpoint. All other response models are nested within a primary response.
-pub trait PrimaryResponse {}
+pub trait PrimaryResponse {
+    /// Returns the top-level fields of the response that the model does not define, as
+    /// captured by its `unknown` field.
+    fn unknown(&self) -> Option<&Value> {
+        None
+    }
+}
 
-pub use ser_deser_cdg::{parse_response, serialize_response};
+pub use ser_deser_cdg::{parse_response, serialize_response, string_or_number};
 mod ser_deser_cdg {
-    use serde::Serialize;
+    use serde::{de, Deserialize, Deserializer, Serialize};
 
     use super::PrimaryResponse;
 
@@ -57,16 +71,73 @@ mod ser_deser_cdg {
         let response: T = serde_json::from_str(&json)?;
         Ok(response)
     }
+
+    /// Deserializes a numeric field that the API sends either as a number (`118`) or as a
+    /// string (`"118"`), depending on the endpoint.
+    ///
+    /// Use with `#[serde(default, deserialize_with = "string_or_number")]` on `Option<u32>`
+    /// fields. `null` and empty strings deserialize to [`None`]. Whole floats such as `118.0`
+    /// are accepted; fractions, negative numbers, and values above [`u32::MAX`] fail with an
+    /// error naming the value instead of wrapping or truncating.
+    pub fn string_or_number<'de, D>(deserializer: D) -> Result<Option<u32>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum StringOrNumber {
+            Unsigned(u64),
+            Signed(i64),
+            Float(f64),
+            String(String),
+        }
+
+        const EXPECTED: &str = "a whole number between 0 and 4294967295";
+
+        let unsigned = match Option::<StringOrNumber>::deserialize(deserializer)? {
+            None => return Ok(None),
+            Some(StringOrNumber::Unsigned(number)) => number,
+            Some(StringOrNumber::Signed(number)) => {
+                return Err(de::Error::invalid_value(
+                    de::Unexpected::Signed(number),
+                    &EXPECTED,
+                ))
+            }
+            Some(StringOrNumber::Float(number)) => {
+                if number.fract() != 0.0 || !(0.0..=f64::from(u32::MAX)).contains(&number) {
+                    return Err(de::Error::invalid_value(
+                        de::Unexpected::Float(number),
+                        &EXPECTED,
+                    ));
+                }
+                number as u64
+            }
+            Some(StringOrNumber::String(s)) if s.trim().is_empty() => return Ok(None),
+            Some(StringOrNumber::String(s)) => s.trim().parse().map_err(|_| {
+                de::Error::invalid_value(de::Unexpected::Str(&s), &"a number or numeric string")
+            })?,
+        };
+
+        u32::try_from(unsigned)
+            .map(Some)
+            .map_err(|_| de::Error::invalid_value(de::Unexpected::Unsigned(unsigned), &EXPECTED))
+    }
 }
 
 macro_rules! impl_primary_response {
     ($($t:ty),*) => {
-        $(impl PrimaryResponse for $t {})*
+        $(impl PrimaryResponse for $t {
+            fn unknown(&self) -> Option<&Value> {
+                self.unknown.as_ref()
+            }
+        })*
     };
 }
 
+/// [`GenericResponse`] takes any field into its `extra` catch-all, so it reports none as unknown.
+impl PrimaryResponse for GenericResponse {}
+
 impl_primary_response!(
-    GenericResponse,
     AmendmentsResponse,
     AmendmentDetailsResponse,
     AmendmentActionsResponse,
@@ -104,9 +175,243 @@ impl_primary_response!(
     HouseCommunicationsResponse,
     CommitteesResponse,
     CommitteeDetailsResponse,
-    CommitteeReportsResponse
+    CommitteeReportsResponse,
+    CommitteeBillsResponse,
+    CommitteeNominationsResponse,
+    CommitteePrintsResponse,
+    CommitteePrintDetailsResponse,
+    CommitteeMeetingsResponse,
+    CommitteeMeetingDetailsResponse,
+    HouseRequirementsResponse,
+    HouseRequirementDetailsResponse,
+    MatchingCommunicationsResponse
+);
+
+/// Implemented by list response models, giving uniform access to their items.
+pub trait ListResponse {
+    /// The type of the listed items.
+    type Item;
+
+    /// Returns the listed items.
+    fn items(&self) -> &[Self::Item];
+
+    /// Maps every item to a user-chosen projection, e.g. a tuple of the few fields a report
+    /// needs.
+    ///
+    /// The API has no field selection, so this trims full items client-side instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cdg_api::response_models::{BillsResponse, ListResponse};
+    ///
+    /// fn titles(response: &BillsResponse) -> Vec<(Option<String>, Option<String>)> {
+    ///     response.project(|bill| (bill.number.clone(), bill.title.clone()))
+    /// }
+    /// ```
+    fn project<R, F>(&self, f: F) -> Vec<R>
+    where
+        F: FnMut(&Self::Item) -> R,
+    {
+        self.items().iter().map(f).collect()
+    }
+}
+
+/// Implemented by list response models, letting generic code walk the pages of a list
+/// endpoint without per-type extraction closures.
+///
+/// See [`CongressApiClient::fetch_all_items`](crate::CongressApiClient::fetch_all_items).
+pub trait Paginated: ListResponse {
+    /// Consumes the response, returning its items.
+    ///
+    /// Named `into_items` rather than `items` to avoid clashing with [`ListResponse::items`].
+    fn into_items(self) -> Vec<Self::Item>;
+
+    /// Returns the `offset` of the next page, read from the `pagination.next` URL, or
+    /// [`None`] on the last page.
+    fn next_offset(&self) -> Option<u32>;
+}
+
+/// Reads the `offset` query parameter of the `pagination.next` URL captured in a response's
+/// unknown fields.
+fn next_offset_of(unknown: &Option<Value>) -> Option<u32> {
+    let next = unknown.as_ref()?.get("pagination")?.get("next")?.as_str()?;
+    let (_, query) = next.split_once('?')?;
+    query
+        .split('&')
+        .filter_map(|param| param.split_once('='))
+        .find(|(name, _)| *name == "offset")
+        .and_then(|(_, value)| value.parse().ok())
+}
+
+macro_rules! impl_list_response {
+    ($($t:ty => $($field:ident).+: $item:ty),* $(,)?) => {
+        $(impl ListResponse for $t {
+            type Item = $item;
+
+            fn items(&self) -> &[Self::Item] {
+                &self.$($field).+
+            }
+        }
+
+        impl Paginated for $t {
+            fn into_items(self) -> Vec<Self::Item> {
+                self.$($field).+
+            }
+
+            fn next_offset(&self) -> Option<u32> {
+                next_offset_of(&self.unknown)
+            }
+        })*
+    };
+}
+
+impl_list_response!(
+    AmendmentsResponse => amendments: AmendmentSummary,
+    AmendmentActionsResponse => actions: AmendmentAction,
+    AmendmentCosponsorsResponse => cosponsors: AmendmentCosponsor,
+    AmendmentAmendmentsResponse => amendments: AmendmentSummary,
+    AmendmentTextVersionsResponse => text_versions: TextVersion,
+    BillsResponse => bills: BillSummary,
+    BillActionsResponse => actions: BillAction,
+    BillAmendmentsResponse => amendments: AmendmentSummary,
+    BillCommitteesResponse => committees: Committee,
+    BillCosponsorsResponse => cosponsors: BillCosponsor,
+    RelatedBillsResponse => related_bills: RelatedBill,
+    BillSummariesResponse => summaries: BillSummaryItem,
+    BillTextVersionsResponse => text_versions: TextVersion,
+    BillTitlesResponse => titles: BillTitle,
+    SummariesResponse => summaries: SummaryItem,
+    LawsResponse => bills: LawSummary,
+    CongressesResponse => congresses: CongressSummary,
+    DailyCongressionalRecordResponse => daily_congressional_record: DailyIssue,
+    ArticlesResponse => articles: Article,
+    MembersResponse => members: Member,
+    NominationsResponse => nominations: NominationItem,
+    TreatiesResponse => treaties: TreatyItem,
+    HearingsResponse => hearings: HearingItem,
+    HouseCommunicationsResponse => house_communications: CommunicationItem,
+    CommitteesResponse => committees: CommitteeItem,
+    CommitteeReportsResponse => reports: CommitteeReportItem,
+    CommitteeBillsResponse => committee_bills.bills: CommitteeBillItem,
+    CommitteeNominationsResponse => nominations: NominationItem,
+    CommitteePrintsResponse => committee_prints: CommitteePrintItem,
+    CommitteeMeetingsResponse => committee_meetings: CommitteeMeetingItem,
+    HouseRequirementsResponse => house_requirements: HouseRequirementItem,
+    MatchingCommunicationsResponse => matching_communications: MatchingCommunicationItem
 );
 
+/// Implemented by list items carrying an `updateDate`, letting incremental syncs track the
+/// latest update they have seen.
+///
+/// See [`CongressApiClient::fetch_updated_since`](crate::CongressApiClient::fetch_updated_since).
+pub trait Updated {
+    /// Returns the item's `updateDate`, e.g. `2024-01-10` or `2024-01-10T14:05:33Z`.
+    fn update_date(&self) -> Option<&str>;
+}
+
+macro_rules! impl_updated {
+    ($($t:ty),* $(,)?) => {
+        $(impl Updated for $t {
+            fn update_date(&self) -> Option<&str> {
+                self.update_date.as_deref()
+            }
+        })*
+    };
+}
+
+impl_updated!(AmendmentSummary, BillSummary, LawSummary, SummaryItem);
+
+/// Path components of an item's API `url`, so callers need not split the URL themselves.
+///
+/// Returned by the `parsed_ref` accessors on summary models such as
+/// [`BillSummary::parsed_ref`]. Components the URL does not have are [`None`].
+#[derive(Debug, Clone)]
+pub struct ItemRef {
+    /// The endpoint the URL points at, as parsed by [`Endpoints::from_api_url`].
+    pub endpoint: Endpoints,
+
+    /// The congress number, e.g. `118`.
+    pub congress: Option<u32>,
+
+    /// The lowercase type or chamber token, e.g. `hr`, `samdt`, or `house`.
+    pub item_type: Option<String>,
+
+    /// The item number or identifier, e.g. `3076`, a committee code, or a bioguide ID.
+    pub number: Option<String>,
+}
+
+impl ItemRef {
+    /// Parses an API URL into its components, or returns [`None`] if it is not a
+    /// congress.gov API URL.
+    pub fn parse(url: &str) -> Option<ItemRef> {
+        let (endpoint, _) = Endpoints::from_api_url(url).ok()?;
+        let (congress, item_type, number) = match &endpoint {
+            Endpoints::BillDetails(congress, bill_type, number, _) => (
+                congress.number(),
+                Some(bill_type.to_string()),
+                Some(number.to_string()),
+            ),
+            Endpoints::AmendmentDetails(congress, amendment_type, number, _) => (
+                congress.number(),
+                Some(amendment_type.to_string()),
+                Some(number.to_string()),
+            ),
+            Endpoints::MemberDetails(bioguide_id, _) => (None, None, Some(bioguide_id.clone())),
+            Endpoints::CongressDetails(congress, _) => (congress.number(), None, None),
+            Endpoints::CommitteeDetails(chamber, code, _) => {
+                (None, Some(chamber.to_string()), Some(code.clone()))
+            }
+            Endpoints::NominationDetails(congress, number, _) => {
+                (congress.number(), None, Some(number.clone()))
+            }
+            Endpoints::TreatyDetails(congress, number, _) => {
+                (congress.number(), None, Some(number.to_string()))
+            }
+            _ => (None, None, None),
+        };
+
+        Some(ItemRef {
+            endpoint,
+            congress,
+            item_type,
+            number,
+        })
+    }
+}
+
+macro_rules! impl_parsed_ref {
+    ($($t:ty),* $(,)?) => {
+        $(impl $t {
+            /// Returns the components of this item's `url`, or [`None`] if it has no
+            /// congress.gov API URL.
+            pub fn parsed_ref(&self) -> Option<ItemRef> {
+                self.url.as_deref().and_then(ItemRef::parse)
+            }
+        })*
+    };
+}
+
+impl_parsed_ref!(AmendmentSummary, BillSummary, LawSummary, MemberSummary);
+
+macro_rules! impl_member_details_endpoint {
+    ($($t:ty),* $(,)?) => {
+        $(impl $t {
+            /// Returns the [`Endpoints::MemberDetails`] endpoint for this member, or [`None`]
+            /// if the API left out the bioguide ID.
+            pub fn details_endpoint(&self) -> Option<Endpoints> {
+                let bioguide_id = self.bioguide_id.clone()?;
+                Some(Endpoints::MemberDetails(
+                    bioguide_id,
+                    MemberDetailsParams::default(),
+                ))
+            }
+        })*
+    };
+}
+
+impl_member_details_endpoint!(Member, MemberSummary);
+
 /// Dynamic response model that can represent a variety of response types.
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(untagged)]
@@ -286,6 +591,16 @@ pub struct GenericResponse {
     pub extra: Option<GenericResponseModel>,
 }
 
+/// Pagination information included with every list response.
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+pub struct Pagination {
+    pub count: Option<u32>,
+    pub next: Option<String>,
+    pub prev: Option<String>,
+    #[serde(flatten)]
+    pub unknown: Option<Value>,
+}
+
 /// Response model for the `/amendment` endpoint.
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct AmendmentsResponse {
@@ -297,13 +612,15 @@ pub struct AmendmentsResponse {
 /// Represents a summary of an amendment.
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct AmendmentSummary {
+    #[serde(default, deserialize_with = "string_or_number")]
     pub congress: Option<u32>,
     #[serde(rename = "latestAction")]
     pub latest_action: Option<LatestAction>,
     pub number: Option<String>,
     pub purpose: Option<String>,
+    pub sponsors: Option<Vec<MemberSummary>>,
     #[serde(rename = "type")]
-    pub amendment_type: Option<String>,
+    pub amendment_type: Option<AmendmentType>,
     pub url: Option<String>,
     #[serde(rename = "updateDate")]
     pub update_date: Option<String>,
@@ -328,6 +645,7 @@ pub struct AmendmentDetails {
     #[serde(rename = "amendmentsToAmendment")]
     pub amendments_to_amendment: Option<ResourceReference>,
     pub chamber: Option<String>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub congress: Option<u32>,
     pub cosponsors: Option<CosponsorsReference>,
     #[serde(rename = "latestAction")]
@@ -378,6 +696,13 @@ pub struct MemberSummary {
     pub full_name: Option<String>,
     #[serde(rename = "lastName")]
     pub last_name: Option<String>,
+    pub party: Option<String>,
+    pub state: Option<String>,
+    #[serde(default, deserialize_with = "string_or_number")]
+    pub district: Option<u32>,
+    /// `Y` if the member sponsored the legislation by request, e.g. of the executive branch.
+    #[serde(rename = "isByRequest")]
+    pub is_by_request: Option<String>,
     pub url: Option<String>,
     #[serde(flatten)]
     pub unknown: Option<Value>,
@@ -386,6 +711,7 @@ pub struct MemberSummary {
 /// Information about the bill that is being amended.
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct AmendedBill {
+    #[serde(default, deserialize_with = "string_or_number")]
     pub congress: Option<u32>,
     pub number: Option<String>,
     #[serde(rename = "originChamber")]
@@ -428,6 +754,7 @@ pub struct AmendmentAction {
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct RecordedVote {
     pub chamber: Option<String>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub congress: Option<u32>,
     pub date: Option<String>,
     #[serde(rename = "rollNumber")]
@@ -527,17 +854,20 @@ pub struct BillsResponse {
 /// Represents a summary of a bill.
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct BillSummary {
+    #[serde(default, deserialize_with = "string_or_number")]
     pub congress: Option<u32>,
     #[serde(rename = "latestAction")]
     pub latest_action: Option<LatestAction>,
     pub number: Option<String>,
     #[serde(rename = "originChamber")]
-    pub origin_chamber: Option<String>,
+    pub origin_chamber: Option<ChamberType>,
     #[serde(rename = "originChamberCode")]
     pub origin_chamber_code: Option<String>,
+    #[serde(rename = "introducedDate")]
+    pub introduced_date: Option<String>,
     pub title: Option<String>,
     #[serde(rename = "type")]
-    pub bill_type: Option<String>,
+    pub bill_type: Option<BillType>,
     #[serde(rename = "updateDate")]
     pub update_date: Option<String>,
     #[serde(rename = "updateDateIncludingText")]
@@ -552,6 +882,8 @@ pub struct BillSummary {
 pub struct LatestAction {
     #[serde(rename = "actionDate")]
     pub action_date: Option<String>,
+    #[serde(rename = "actionTime")]
+    pub action_time: Option<String>,
     pub text: Option<String>,
     #[serde(flatten)]
     pub unknown: Option<Value>,
@@ -575,6 +907,7 @@ pub struct BillDetails {
     #[serde(rename = "committeeReports")]
     pub committee_reports: Option<Vec<CommitteeReport>>,
     pub committees: Option<ResourceReference>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub congress: Option<u32>,
     #[serde(rename = "constitutionalAuthorityStatementText")]
     pub constitutional_authority_statement_text: Option<String>,
@@ -639,6 +972,17 @@ pub struct LawReference {
     pub unknown: Option<Value>,
 }
 
+/// Formats the law as cited, e.g. `Public Law 118-5`, skipping missing parts.
+impl std::fmt::Display for LawReference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.law_type, &self.number) {
+            (Some(law_type), Some(number)) => write!(f, "{} {}", law_type, number),
+            (Some(part), None) | (None, Some(part)) => write!(f, "{}", part),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
 /// Represents the policy area of a bill.
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct PolicyArea {
@@ -761,9 +1105,11 @@ pub struct RelatedBillsResponse {
 /// Represents a related bill.
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct RelatedBill {
+    #[serde(default, deserialize_with = "string_or_number")]
     pub congress: Option<u32>,
     #[serde(rename = "latestAction")]
     pub latest_action: Option<LatestAction>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub number: Option<u32>,
     #[serde(rename = "relationshipDetails")]
     pub relationship_details: Option<Vec<RelationshipDetail>>,
@@ -912,6 +1258,7 @@ pub struct SummaryItem {
 /// Represents a reference to a bill within a summary.
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct BillReference {
+    #[serde(default, deserialize_with = "string_or_number")]
     pub congress: Option<u32>,
     pub number: Option<String>,
     #[serde(rename = "originChamber")]
@@ -939,6 +1286,7 @@ pub struct LawsResponse {
 /// Represents a summary of a law (bill that became a law).
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct LawSummary {
+    #[serde(default, deserialize_with = "string_or_number")]
     pub congress: Option<u32>,
     #[serde(rename = "latestAction")]
     pub latest_action: Option<LatestAction>,
@@ -982,6 +1330,8 @@ pub struct CongressSummary {
     #[serde(rename = "endYear")]
     pub end_year: Option<String>,
     pub name: Option<String>,
+    #[serde(default, deserialize_with = "string_or_number")]
+    pub number: Option<u32>,
     pub sessions: Option<Vec<Session>>,
     #[serde(rename = "startYear")]
     pub start_year: Option<String>,
@@ -989,12 +1339,25 @@ pub struct CongressSummary {
     pub unknown: Option<Value>,
 }
 
+impl CongressSummary {
+    /// Returns the congress number, read from the name (e.g. `118th Congress`) when the API
+    /// leaves out `number`, as the `/congress` list does.
+    pub fn congress_number(&self) -> Option<u32> {
+        self.number.or_else(|| {
+            let name = self.name.as_deref()?;
+            let digits = name.split(|c: char| !c.is_ascii_digit()).next()?;
+            digits.parse().ok()
+        })
+    }
+}
+
 /// Represents a session within a congress.
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct Session {
     pub chamber: Option<String>,
     #[serde(rename = "endDate")]
     pub end_date: Option<String>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub number: Option<u32>,
     #[serde(rename = "startDate")]
     pub start_date: Option<String>,
@@ -1004,6 +1367,13 @@ pub struct Session {
     pub unknown: Option<Value>,
 }
 
+impl Session {
+    /// Returns `true` if the session has started and the API lists no end date yet.
+    pub fn is_ongoing(&self) -> bool {
+        self.start_date.is_some() && self.end_date.is_none()
+    }
+}
+
 /// Response model for the `/congress/{congress}` and `/congress/current` endpoints.
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct CongressDetailsResponse {
@@ -1018,6 +1388,7 @@ pub struct CongressDetails {
     #[serde(rename = "endYear")]
     pub end_year: Option<String>,
     pub name: Option<String>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub number: Option<u32>,
     pub sessions: Option<Vec<Session>>,
     #[serde(rename = "startYear")]
@@ -1048,6 +1419,7 @@ pub struct Results {
 /// Represents an individual Congressional Record issue.
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct Issue {
+    #[serde(default, deserialize_with = "string_or_number")]
     pub congress: Option<u32>,
     pub issue: Option<u32>,
     pub links: Option<Links>,
@@ -1117,6 +1489,7 @@ pub struct DailyIssue {
     pub volume_number: Option<u32>,
     #[serde(rename = "issueDate")]
     pub issue_date: Option<String>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub congress: Option<u32>,
     #[serde(rename = "sessionNumber")]
     pub session_number: Option<u32>,
@@ -1255,6 +1628,50 @@ pub struct MembersResponse {
     pub unknown: Option<Value>,
 }
 
+impl MembersResponse {
+    /// Returns the members with duplicate bioguide IDs removed, in order of first appearance.
+    ///
+    /// When a member appears more than once, e.g. after paging through several congresses,
+    /// the entry with the most recent term is kept. Members without a bioguide ID are kept
+    /// as is.
+    pub fn dedup_by_bioguide(&self) -> Vec<&Member> {
+        let mut unique: Vec<&Member> = Vec::new();
+        let mut positions: HashMap<&str, usize> = HashMap::new();
+
+        for member in &self.members {
+            let Some(id) = member.bioguide_id.as_deref() else {
+                unique.push(member);
+                continue;
+            };
+            match positions.get(id) {
+                Some(&position) => {
+                    if latest_term_start(member) > latest_term_start(unique[position]) {
+                        unique[position] = member;
+                    }
+                }
+                None => {
+                    positions.insert(id, unique.len());
+                    unique.push(member);
+                }
+            }
+        }
+
+        unique
+    }
+}
+
+/// Returns the start year of the member's most recent term, if any.
+fn latest_term_start(member: &Member) -> Option<u32> {
+    member
+        .terms
+        .as_ref()
+        .and_then(|terms| terms.item.as_ref())
+        .into_iter()
+        .flatten()
+        .filter_map(|term| term.start_year)
+        .max()
+}
+
 /// Represents an individual member's entry.
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct Member {
@@ -1292,6 +1709,10 @@ pub struct Term {
 }
 
 /// Represents the member's current official portrait.
+///
+/// [`Depiction::default`] is the "no portrait" sentinel: every field is [`None`], so
+/// [`Depiction::image_url()`] returns [`None`] rather than an empty URL. Renderers should show
+/// their own placeholder in that case instead of emitting `<img src="">`.
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct Depiction {
     #[serde(rename = "imageUrl")]
@@ -1301,6 +1722,39 @@ pub struct Depiction {
     pub unknown: Option<Value>,
 }
 
+impl Depiction {
+    /// Returns the portrait URL as an absolute `https` URL.
+    ///
+    /// Relative paths (`/img/member/...`) and protocol-relative URLs (`//www.congress.gov/...`)
+    /// are resolved against `https://www.congress.gov`, and `http://` URLs are upgraded to
+    /// `https://`, which requires an owned string; `https://` URLs are borrowed as-is.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(Cow<str>)`: The absolute image URL.
+    /// - [`None`]: If the URL is missing or blank.
+    pub fn image_url(&self) -> Option<Cow<'_, str>> {
+        let url = self.image_url.as_deref()?.trim();
+
+        if url.is_empty() {
+            None
+        } else if url.starts_with("https://") {
+            Some(Cow::Borrowed(url))
+        } else if let Some(rest) = url
+            .strip_prefix("http://")
+            .or_else(|| url.strip_prefix("//"))
+        {
+            Some(Cow::Owned(format!("https://{}", rest)))
+        } else {
+            Some(Cow::Owned(format!(
+                "{}/{}",
+                CONGRESS_GOV_URL,
+                url.trim_start_matches('/')
+            )))
+        }
+    }
+}
+
 /// Response model for the `/member/{bioguideId}` endpoint.
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct MemberDetailsResponse {
@@ -1378,6 +1832,7 @@ pub struct PartyHistory {
 pub struct MemberTerm {
     #[serde(rename = "memberType")]
     pub member_type: Option<String>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub congress: Option<u32>,
     pub chamber: Option<String>,
     #[serde(rename = "stateCode")]
@@ -1423,6 +1878,7 @@ pub struct AddressInformation {
 pub struct LeadershipPosition {
     #[serde(rename = "type")]
     pub position_type: Option<String>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub congress: Option<u32>,
     pub current: Option<bool>,
     #[serde(flatten)]
@@ -1449,7 +1905,9 @@ pub struct NominationsResponse {
 /// Represents an individual nomination entry.
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct NominationItem {
+    #[serde(default, deserialize_with = "string_or_number")]
     pub congress: Option<u32>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub number: Option<u32>,
     #[serde(rename = "partNumber")]
     pub part_number: Option<String>,
@@ -1478,6 +1936,42 @@ pub struct NominationType {
     pub unknown: Option<Value>,
 }
 
+impl NominationType {
+    /// Returns the [`NominationKind`] described by the `isCivilian` and `isMilitary` flags.
+    pub fn kind(&self) -> NominationKind {
+        match (self.is_civilian, self.is_military) {
+            (Some(true), _) => NominationKind::Civilian,
+            (_, Some(true)) => NominationKind::Military,
+            _ => NominationKind::Unknown,
+        }
+    }
+}
+
+/// The kind of a nomination, derived from the flags of [`NominationType`].
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum NominationKind {
+    /// A civilian nomination, e.g. to an ambassadorship or judgeship.
+    Civilian,
+
+    /// A military promotion or appointment.
+    Military,
+
+    /// Neither flag is set.
+    #[default]
+    Unknown,
+}
+
+impl std::fmt::Display for NominationKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NominationKind::Civilian => write!(f, "Civilian"),
+            NominationKind::Military => write!(f, "Military"),
+            NominationKind::Unknown => write!(f, "Unknown"),
+        }
+    }
+}
+
 /// Response model for the `/nomination/{congress}/{number}` endpoint.
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct NominationDetailsResponse {
@@ -1489,7 +1983,9 @@ pub struct NominationDetailsResponse {
 /// Represents detailed information about a nomination.
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct NominationDetails {
+    #[serde(default, deserialize_with = "string_or_number")]
     pub congress: Option<u32>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub number: Option<u32>,
     #[serde(rename = "partNumber")]
     pub part_number: Option<String>,
@@ -1576,6 +2072,7 @@ pub struct TreatyItem {
     pub congress_received: Option<u32>,
     #[serde(rename = "congressConsidered")]
     pub congress_considered: Option<u32>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub number: Option<u32>,
     pub suffix: Option<String>,
     #[serde(rename = "transmittedDate")]
@@ -1599,6 +2096,31 @@ pub struct TreatyParts {
     pub unknown: Option<Value>,
 }
 
+impl TreatyParts {
+    /// Returns `true` if the treaty is split into more than one part.
+    ///
+    /// Partitioned treaties are fetched per part with
+    /// [`Endpoints::TreatyPartitioned`].
+    pub fn is_partitioned(&self) -> bool {
+        self.count.is_some_and(|count| count > 1)
+    }
+
+    /// Returns the part suffixes parsed from `urls`, in the order the API lists them.
+    ///
+    /// Each part URL ends in its suffix (e.g. `.../treaty/114/13/A?format=json`);
+    /// URLs without a valid suffix segment are skipped.
+    pub fn suffixes(&self) -> Vec<TreatySuffix> {
+        self.urls
+            .iter()
+            .flatten()
+            .filter_map(|url| {
+                let path = url.split(['?', '#']).next()?;
+                path.trim_end_matches('/').rsplit('/').next()?.parse().ok()
+            })
+            .collect()
+    }
+}
+
 /// Response model for the `/treaty/{congress}/{number}` endpoint.
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct TreatyDetailsResponse {
@@ -1614,6 +2136,7 @@ pub struct TreatyDetails {
     pub congress_received: Option<u32>,
     #[serde(rename = "congressConsidered")]
     pub congress_considered: Option<u32>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub number: Option<u32>,
     pub suffix: Option<String>,
     #[serde(rename = "transmittedDate")]
@@ -1681,7 +2204,9 @@ pub struct HearingItem {
     #[serde(rename = "updateDate")]
     pub update_date: Option<String>,
     pub chamber: Option<String>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub congress: Option<u32>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub number: Option<u32>,
     pub part: Option<u32>,
     pub url: Option<String>,
@@ -1704,10 +2229,12 @@ pub struct HearingDetails {
     pub jacket_number: Option<u32>,
     #[serde(rename = "libraryOfCongressIdentifier")]
     pub library_of_congress_identifier: Option<String>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub number: Option<u32>,
     pub part: Option<u32>,
     #[serde(rename = "updateDate")]
     pub update_date: Option<String>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub congress: Option<u32>,
     pub title: Option<String>,
     pub citation: Option<String>,
@@ -1766,6 +2293,8 @@ pub struct AssociatedMeeting {
 pub struct HouseCommunicationsResponse {
     #[serde(rename = "houseCommunications")]
     pub house_communications: Vec<CommunicationItem>,
+    #[serde(flatten)]
+    pub unknown: Option<Value>,
 }
 
 /// Response model for the `/senate-communication` endpoint.
@@ -1773,12 +2302,15 @@ pub struct HouseCommunicationsResponse {
 pub struct SenateCommunicationsResponse {
     #[serde(rename = "senateCommunications")]
     pub senate_communications: Vec<CommunicationItem>,
+    #[serde(flatten)]
+    pub unknown: Option<Value>,
 }
 
 /// Represents an individual House communication entry.
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct CommunicationItem {
     pub chamber: Option<String>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub number: Option<u32>,
     #[serde(rename = "communicationType")]
     pub communication_type: Option<CommunicationType>,
@@ -1799,15 +2331,19 @@ pub struct CommunicationType {
 pub struct HouseCommunicationDetailsResponse {
     #[serde(rename = "house-communication")]
     pub house_communication: HouseCommunicationDetails,
+    #[serde(flatten)]
+    pub unknown: Option<Value>,
 }
 
 /// Represents detailed information about a House communication.
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct HouseCommunicationDetails {
     pub chamber: Option<String>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub number: Option<u32>,
     #[serde(rename = "communicationType")]
     pub communication_type: Option<CommunicationType>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub congress: Option<u32>,
     #[serde(rename = "updateDate")]
     pub update_date: Option<String>,
@@ -1861,12 +2397,16 @@ pub struct HouseDocument {
 /// Response model for the `/house-requirement` endpoint.
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct HouseRequirementsResponse {
+    #[serde(rename = "houseRequirements")]
     pub house_requirements: Vec<HouseRequirementItem>,
+    #[serde(flatten)]
+    pub unknown: Option<Value>,
 }
 
 /// Represents an individual House requirement entry.
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct HouseRequirementItem {
+    #[serde(default, deserialize_with = "string_or_number")]
     pub number: Option<u32>,
     #[serde(rename = "updateDate")]
     pub update_date: Option<String>,
@@ -1878,11 +2418,14 @@ pub struct HouseRequirementItem {
 pub struct HouseRequirementDetailsResponse {
     #[serde(rename = "houseRequirement")]
     pub house_requirement: HouseRequirementDetails,
+    #[serde(flatten)]
+    pub unknown: Option<Value>,
 }
 
 /// Represents detailed information about a House requirement.
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct HouseRequirementDetails {
+    #[serde(default, deserialize_with = "string_or_number")]
     pub number: Option<u32>,
     #[serde(rename = "updateDate")]
     pub update_date: Option<String>,
@@ -1911,13 +2454,24 @@ pub struct MatchingCommunications {
     pub matching_communications_outer: Option<Vec<MatchingCommunicationItem>>,
 }
 
+/// Response model for the `/house-requirement/{number}/matching-communications` endpoint.
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+pub struct MatchingCommunicationsResponse {
+    #[serde(rename = "matchingCommunications")]
+    pub matching_communications: Vec<MatchingCommunicationItem>,
+    #[serde(flatten)]
+    pub unknown: Option<Value>,
+}
+
 /// Represents a House communication matching a House requirement.
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct MatchingCommunicationItem {
     pub chamber: Option<String>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub number: Option<u32>,
     #[serde(rename = "communicationType")]
     pub communication_type: Option<CommunicationType>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub congress: Option<u32>,
     pub url: Option<String>,
 }
@@ -1926,6 +2480,8 @@ pub struct MatchingCommunicationItem {
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct CommitteesResponse {
     pub committees: Vec<CommitteeItem>,
+    #[serde(flatten)]
+    pub unknown: Option<Value>,
 }
 
 /// Represents an individual committee or subcommittee.
@@ -1964,6 +2520,8 @@ pub struct SubcommitteeItem {
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct CommitteeDetailsResponse {
     pub committee: CommitteeDetails,
+    #[serde(flatten)]
+    pub unknown: Option<Value>,
 }
 
 /// Represents detailed information about a committee or subcommittee.
@@ -2037,32 +2595,94 @@ pub struct CommitteeHistoryItem {
     pub nara_id: Option<String>,
 }
 
-/// Represents a response containing a list of committee meetings.
+/// Response model for the `/committee/{chamber}/{committeeCode}/bills` endpoint.
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+pub struct CommitteeBillsResponse {
+    #[serde(rename = "committee-bills")]
+    pub committee_bills: CommitteeBillsBlock,
+    #[serde(flatten)]
+    pub unknown: Option<Value>,
+}
+
+/// Wrapper around the bills referred to a committee, along with their total count.
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+pub struct CommitteeBillsBlock {
+    pub bills: Vec<CommitteeBillItem>,
+    pub count: Option<u32>,
+    pub url: Option<String>,
+    #[serde(flatten)]
+    pub unknown: Option<Value>,
+}
+
+/// Represents a bill referred to, or reported by, a committee.
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+pub struct CommitteeBillItem {
+    #[serde(rename = "actionDate")]
+    pub action_date: Option<String>,
+    #[serde(rename = "billType")]
+    pub bill_type: Option<String>,
+    #[serde(default, deserialize_with = "string_or_number")]
+    pub congress: Option<u32>,
+    pub number: Option<String>,
+    #[serde(rename = "relationshipType")]
+    pub relationship_type: Option<String>,
+    #[serde(rename = "updateDate")]
+    pub update_date: Option<String>,
+    pub url: Option<String>,
+    #[serde(flatten)]
+    pub unknown: Option<Value>,
+}
+
+/// Response model for the `/committee/{chamber}/{committeeCode}/nominations` endpoint.
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+pub struct CommitteeNominationsResponse {
+    pub nominations: Vec<NominationItem>,
+    #[serde(flatten)]
+    pub unknown: Option<Value>,
+}
+
+/// Response model for the `/committee-meeting`, `/committee-meeting/{congress}`, and
+/// `/committee-meeting/{congress}/{chamber}` endpoints.
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct CommitteeMeetingsResponse {
     #[serde(rename = "committeeMeetings")]
     pub committee_meetings: Vec<CommitteeMeetingItem>,
+    #[serde(flatten)]
+    pub unknown: Option<Value>,
 }
 
 /// Represents an individual committee meeting item.
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct CommitteeMeetingItem {
-    #[serde(rename = "eventId")]
+    #[serde(rename = "eventId", default, deserialize_with = "string_or_number")]
     pub event_id: Option<u32>,
     pub url: Option<String>,
     #[serde(rename = "updateDate")]
     pub update_date: Option<String>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub congress: Option<u32>,
     pub chamber: Option<String>,
+    #[serde(flatten)]
+    pub unknown: Option<Value>,
 }
 
-/// Represents detailed information about a committee meeting.
+/// Response model for the `/committee-meeting/{congress}/{chamber}/{eventId}` endpoint.
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct CommitteeMeetingDetailsResponse {
-    #[serde(rename = "eventId")]
-    pub event_id: u32,
+    #[serde(rename = "committeeMeeting")]
+    pub committee_meeting: CommitteeMeetingDetail,
+    #[serde(flatten)]
+    pub unknown: Option<Value>,
+}
+
+/// Represents detailed information about a committee meeting.
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+pub struct CommitteeMeetingDetail {
+    #[serde(rename = "eventId", default, deserialize_with = "string_or_number")]
+    pub event_id: Option<u32>,
     #[serde(rename = "updateDate")]
     pub update_date: Option<String>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub congress: Option<u32>,
     #[serde(rename = "type")]
     pub meeting_type: Option<String>,
@@ -2080,9 +2700,11 @@ pub struct CommitteeMeetingDetailsResponse {
     #[serde(rename = "meetingDocuments")]
     pub meeting_documents: Option<Vec<MeetingDocumentItem>>,
     #[serde(rename = "hearingTranscript")]
-    pub hearing_transcript: Option<HearingTranscript>,
+    pub hearing_transcript: Option<Vec<HearingTranscript>>,
     #[serde(rename = "relatedItems")]
     pub related_items: Option<RelatedItems>,
+    #[serde(flatten)]
+    pub unknown: Option<Value>,
 }
 
 /// Represents the location of a committee meeting.
@@ -2147,9 +2769,11 @@ pub struct RelatedItems {
 /// Represents a bill or resolution associated with a committee meeting.
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct BillItem {
-    #[serde(rename = "billType")]
+    #[serde(rename = "type", alias = "billType")]
     pub bill_type: Option<String>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub number: Option<u32>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub congress: Option<u32>,
     pub url: Option<String>,
 }
@@ -2159,6 +2783,8 @@ pub struct BillItem {
 pub struct CommitteePrintsResponse {
     #[serde(rename = "committeePrints")]
     pub committee_prints: Vec<CommitteePrintItem>,
+    #[serde(flatten)]
+    pub unknown: Option<Value>,
 }
 
 /// Represents an individual committee print item.
@@ -2169,17 +2795,32 @@ pub struct CommitteePrintItem {
     pub url: Option<String>,
     #[serde(rename = "updateDate")]
     pub update_date: Option<String>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub congress: Option<u32>,
     pub chamber: Option<String>,
 }
 
-/// Represents detailed information about a committee print.
+/// Response model for the `/committee-print/{congress}/{chamber}/{jacketNumber}` endpoint.
+///
+/// The API returns the print as a single-element `committeePrint` array. Like the other
+/// committee models, the chamber is kept as the string the API sends, e.g. `House`.
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct CommitteePrintDetailsResponse {
+    #[serde(rename = "committeePrint")]
+    pub committee_prints: Vec<CommitteePrintDetail>,
+    #[serde(flatten)]
+    pub unknown: Option<Value>,
+}
+
+/// Represents detailed information about a committee print.
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+pub struct CommitteePrintDetail {
     #[serde(rename = "jacketNumber")]
-    pub jacket_number: u32,
+    pub jacket_number: Option<u32>,
     pub citation: Option<String>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub congress: Option<u32>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub number: Option<u32>,
     pub title: Option<String>,
     pub chamber: Option<String>,
@@ -2187,14 +2828,20 @@ pub struct CommitteePrintDetailsResponse {
     #[serde(rename = "associatedBills")]
     pub associated_bills: Option<Vec<AssociatedBillItem>>,
     pub text: Option<CommitteePrintText>,
+    #[serde(rename = "updateDate")]
+    pub update_date: Option<String>,
+    #[serde(flatten)]
+    pub unknown: Option<Value>,
 }
 
 /// Represents a bill associated with a committee print.
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct AssociatedBillItem {
+    #[serde(default, deserialize_with = "string_or_number")]
     pub congress: Option<u32>,
     #[serde(rename = "type")]
     pub bill_type: Option<String>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub number: Option<u32>,
     pub url: Option<String>,
 }
@@ -2215,10 +2862,15 @@ pub struct CommitteePrintTextItem {
 }
 
 /// Represents a response containing a list of committee reports.
+///
+/// Returned by both the `/committee-report` and `/committee/{chamber}/{committeeCode}/reports`
+/// endpoints.
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct CommitteeReportsResponse {
     #[serde(rename = "reports")]
     pub reports: Vec<CommitteeReportItem>,
+    #[serde(flatten)]
+    pub unknown: Option<Value>,
 }
 
 /// Represents an individual committee report item.
@@ -2228,10 +2880,12 @@ pub struct CommitteeReportItem {
     pub url: Option<String>,
     #[serde(rename = "updateDate")]
     pub update_date: Option<String>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub congress: Option<u32>,
     pub chamber: Option<String>,
     #[serde(rename = "type")]
     pub report_type: Option<String>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub number: Option<u32>,
     pub part: Option<u32>,
 }
@@ -2240,11 +2894,13 @@ pub struct CommitteeReportItem {
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct CommitteeReportDetailsResponse {
     pub committees: Vec<CommitteeItem>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub congress: Option<u32>,
     pub chamber: Option<String>,
     #[serde(rename = "sessionNumber")]
     pub session_number: Option<u32>,
     pub citation: Option<String>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub number: Option<u32>,
     pub part: Option<u32>,
     #[serde(rename = "type")]
@@ -2263,12 +2919,16 @@ pub struct CommitteeReportDetailsResponse {
     pub associated_treaties: Option<Vec<AssociatedTreatyItem>>,
     #[serde(rename = "associatedBill")]
     pub associated_bills: Option<Vec<AssociatedBillItem>>,
+    #[serde(flatten)]
+    pub unknown: Option<Value>,
 }
 
 /// Represents a treaty associated with an executive report.
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct AssociatedTreatyItem {
+    #[serde(default, deserialize_with = "string_or_number")]
     pub congress: Option<u32>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub number: Option<u32>,
     pub part: Option<u32>,
     pub url: Option<String>,
@@ -0,0 +1,170 @@
+//! # `streaming` Module
+//!
+//! Available with the `streaming` feature. Deserializes the items of a list response one at a
+//! time as they are read, instead of buffering the whole body and building the full response
+//! model first. Memory stays flat no matter how many items a single page holds.
+//!
+//! Two response shapes are supported:
+//!
+//! - A top-level array of items: `[{...}, {...}]`.
+//! - An object holding the items in an array field, as every congress.gov list endpoint
+//!   returns: `{"bills": [{...}, {...}], "pagination": {...}, "request": {...}}`. The first
+//!   array-valued field is streamed; every other field, including later arrays, is skipped.
+//!
+//! Detail responses (a single object such as `{"bill": {...}}`) hold no array of items and
+//! yield nothing.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use cdg_api::response_models::BillSummary;
+//! use cdg_api::streaming::stream_items;
+//!
+//! let body = r#"{"bills": [{"number": "1"}, {"number": "2"}], "pagination": {"count": 2}}"#;
+//! let mut numbers = Vec::new();
+//! let count = stream_items(body.as_bytes(), |bill: BillSummary| numbers.push(bill.number))
+//!     .expect("Failed to stream bills");
+//! assert_eq!(count, 2);
+//! ```
+
+use crate::client::cdg_client::ApiClientError;
+use serde::de::{self, DeserializeOwned, DeserializeSeed, IgnoredAny, MapAccess, SeqAccess};
+use serde::Deserializer as _;
+use std::fmt;
+use std::io::Read;
+use std::marker::PhantomData;
+
+/// Reads a JSON list response from `reader`, passing each item to `on_item` as soon as it is
+/// parsed.
+///
+/// # Parameters
+///
+/// - [`reader`]: The response body. Wrap unbuffered sources in a [`std::io::BufReader`].
+/// - [`on_item`]: Called with every item, in order.
+///
+/// # Returns
+///
+/// - `Ok(usize)`: The number of items passed to `on_item`.
+/// - `Err(ApiClientError::Deserialization)`: If the body is not valid JSON, an item does not
+///   match `T`, or the body is neither an array nor an object. Items parsed before the error
+///   have already been passed to `on_item`.
+pub fn stream_items<T, R, F>(reader: R, mut on_item: F) -> Result<usize, ApiClientError>
+where
+    T: DeserializeOwned,
+    R: Read,
+    F: FnMut(T),
+{
+    let mut deserializer = serde_json::Deserializer::from_reader(reader);
+    let mut stream = Stream {
+        on_item: &mut on_item,
+        count: 0,
+        found: false,
+        item: PhantomData,
+    };
+    deserializer
+        .deserialize_any(&mut stream)
+        .and_then(|()| deserializer.end())
+        .map_err(ApiClientError::Deserialization)?;
+
+    Ok(stream.count)
+}
+
+/// Streams the items of the first array it finds, at the top level or one field deep.
+struct Stream<'f, T, F> {
+    on_item: &'f mut F,
+    count: usize,
+    found: bool,
+    item: PhantomData<T>,
+}
+
+impl<'de, T, F> de::Visitor<'de> for &mut Stream<'_, T, F>
+where
+    T: DeserializeOwned,
+    F: FnMut(T),
+{
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a JSON array of items or an object holding one")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        self.found = true;
+        while let Some(item) = seq.next_element::<T>()? {
+            (self.on_item)(item);
+            self.count += 1;
+        }
+        Ok(())
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        while map.next_key::<IgnoredAny>()?.is_some() {
+            if self.found {
+                map.next_value::<IgnoredAny>()?;
+            } else {
+                map.next_value_seed(Field(&mut *self))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A field of the top-level object: streamed if it is an array, skipped otherwise.
+struct Field<'s, 'f, T, F>(&'s mut Stream<'f, T, F>);
+
+impl<'de, T, F> DeserializeSeed<'de> for Field<'_, '_, T, F>
+where
+    T: DeserializeOwned,
+    F: FnMut(T),
+{
+    type Value = ();
+
+    fn deserialize<D: de::Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de, T, F> de::Visitor<'de> for Field<'_, '_, T, F>
+where
+    T: DeserializeOwned,
+    F: FnMut(T),
+{
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("any JSON value")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<(), A::Error> {
+        self.0.visit_seq(seq)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        while map.next_entry::<IgnoredAny, IgnoredAny>()?.is_some() {}
+        Ok(())
+    }
+
+    fn visit_bool<E: de::Error>(self, _: bool) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_i64<E: de::Error>(self, _: i64) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_u64<E: de::Error>(self, _: u64) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_f64<E: de::Error>(self, _: f64) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_str<E: de::Error>(self, _: &str) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<(), E> {
+        Ok(())
+    }
+}
@@ -0,0 +1,1813 @@
+use cdg_api::cache::{fixture_file_name, DiskCache};
+use cdg_api::cdg_types::{BillType, Congress, FormatType, SortType};
+use cdg_api::client::cdg_client::{
+    parse_body, parse_body_lenient, truncate_error_body, ApiClientError, ApiKeyLocation,
+    ResponseMeta, MAX_ERROR_BODY_BYTES,
+};
+use cdg_api::endpoints::{Endpoints, NewEndpoint};
+use cdg_api::param_models::{
+    BillAmendmentsParams, BillByCongressParams, BillByTypeParams, BillDetailsParams,
+    BillListParams, BillTextParams, GenericParams, LawParams, MemberDetailsParams,
+};
+use cdg_api::rate_limit::RateLimiter;
+use cdg_api::response_models::{BillAmendmentsResponse, BillsResponse, GenericResponse};
+use cdg_api::retry::{ExponentialBackoff, RetryPolicy, Sleeper};
+use cdg_api::CongressApiClient;
+use reqwest::StatusCode;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+#[test]
+fn test_status_codes_map_to_named_errors() {
+    assert!(matches!(
+        ApiClientError::from_status(StatusCode::UNAUTHORIZED),
+        ApiClientError::Unauthorized
+    ));
+    assert!(matches!(
+        ApiClientError::from_status(StatusCode::FORBIDDEN),
+        ApiClientError::Unauthorized
+    ));
+    assert!(matches!(
+        ApiClientError::from_status(StatusCode::NOT_FOUND),
+        ApiClientError::NotFound
+    ));
+    assert!(matches!(
+        ApiClientError::from_status(StatusCode::TOO_MANY_REQUESTS),
+        ApiClientError::RateLimited { retry_after: None }
+    ));
+    assert!(matches!(
+        ApiClientError::from_status(StatusCode::BAD_GATEWAY),
+        ApiClientError::ServerError { status: 502, .. }
+    ));
+    assert!(matches!(
+        ApiClientError::from_status(StatusCode::BAD_REQUEST),
+        ApiClientError::Other { status: 400, .. }
+    ));
+}
+
+#[test]
+fn test_exponential_backoff_delays() {
+    let policy = ExponentialBackoff::new(3, Duration::from_millis(100));
+
+    assert_eq!(
+        policy.should_retry(
+            1,
+            &ApiClientError::ServerError {
+                status: 503,
+                body: String::new(),
+            }
+        ),
+        Some(Duration::from_millis(100))
+    );
+    assert_eq!(
+        policy.should_retry(
+            3,
+            &ApiClientError::ServerError {
+                status: 503,
+                body: String::new(),
+            }
+        ),
+        Some(Duration::from_millis(400))
+    );
+    assert_eq!(
+        policy.should_retry(
+            4,
+            &ApiClientError::ServerError {
+                status: 503,
+                body: String::new(),
+            }
+        ),
+        None
+    );
+
+    // Retry-After takes precedence over the computed backoff.
+    let rate_limited = ApiClientError::RateLimited {
+        retry_after: Some(Duration::from_secs(2)),
+    };
+    assert_eq!(
+        policy.should_retry(1, &rate_limited),
+        Some(Duration::from_secs(2))
+    );
+
+    // A Retry-After beyond max_delay is not shortened; the policy gives up instead.
+    let rate_limited = ApiClientError::RateLimited {
+        retry_after: Some(Duration::from_secs(120)),
+    };
+    assert_eq!(policy.should_retry(1, &rate_limited), None);
+    assert_eq!(
+        policy
+            .max_delay(Duration::from_secs(180))
+            .should_retry(1, &rate_limited),
+        Some(Duration::from_secs(120))
+    );
+
+    // Client errors are never retried.
+    assert_eq!(policy.should_retry(1, &ApiClientError::NotFound), None);
+}
+
+#[test]
+fn test_request_hook_runs_before_send() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&calls);
+    let (base_url, requests) = stub_server(200, "{}", Duration::ZERO);
+
+    let client = CongressApiClient::new(Some("TEST_KEY".to_string()))
+        .expect("Failed to create client")
+        .with_base_url(base_url)
+        .on_build_request(move |request| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            request.header("X-Request-Id", "test")
+        });
+
+    client
+        .fetch::<GenericResponse>(Endpoints::new_generic(
+            "congress/current".to_string(),
+            Default::default(),
+        ))
+        .expect("Stubbed fetch failed");
+
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].header("X-Request-Id"), Some("test"));
+}
+
+#[test]
+fn test_bills_by_type_empty_range_sends_nothing() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&calls);
+
+    let client = CongressApiClient::new(Some("TEST_KEY".to_string()))
+        .expect("Failed to create client")
+        .on_build_request(move |request| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            request
+        });
+
+    #[allow(clippy::reversed_empty_ranges)]
+    let bills = client
+        .list_bills_by_type_all_congresses(BillType::Hr, 118..=117, BillByTypeParams::default())
+        .expect("An empty range must not fail");
+
+    assert!(bills.is_empty());
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn test_bills_by_type_pages_every_congress_in_order() {
+    let page = |congress: u32, offset: u32| {
+        Endpoints::new_bill_by_type(
+            congress,
+            BillType::Hr,
+            BillByTypeParams::default()
+                .format(FormatType::Json)
+                .offset(offset)
+                .limit(2),
+        )
+        .cache_key()
+        .unwrap()
+    };
+    let dir = seeded_cache(
+        "by_type",
+        [
+            (
+                page(117, 0),
+                r#"{"bills": [{"congress": 117, "number": "1"}]}"#,
+            ),
+            (
+                page(118, 0),
+                r#"{"bills": [{"congress": 118, "number": "1"}, {"congress": 118, "number": "2"}],
+                "pagination": {"next": "https://api.congress.gov/v3/bill/118/hr?offset=2&limit=2"}}"#,
+            ),
+            (
+                page(118, 2),
+                r#"{"bills": [{"congress": 118, "number": "3"}]}"#,
+            ),
+        ],
+    );
+
+    let client = CongressApiClient::new(Some("TEST_KEY".to_string()))
+        .expect("Failed to create client")
+        .with_response_cache_dir(&dir)
+        .expect("Failed to open cache");
+    let bills = client
+        .list_bills_by_type_all_congresses(
+            BillType::Hr,
+            117..=118,
+            BillByTypeParams::default()
+                .format(FormatType::Json)
+                .limit(2),
+        )
+        .expect("Cached pages failed");
+
+    let bills: Vec<_> = bills
+        .iter()
+        .map(|bill| (bill.congress, bill.number.as_deref()))
+        .collect();
+    assert_eq!(
+        bills,
+        vec![
+            (Some(117), Some("1")),
+            (Some(118), Some("1")),
+            (Some(118), Some("2")),
+            (Some(118), Some("3")),
+        ]
+    );
+    assert_eq!(client.requests_sent(), 0);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_parse_body_tolerates_bom_and_whitespace() {
+    let body = include_str!("fixtures/bom_bills.json");
+    assert!(body.starts_with('\u{feff}'));
+
+    let response: BillsResponse = parse_body(body).expect("Failed to parse BOM-prefixed body");
+    assert_eq!(response.bills[0].number.as_deref(), Some("1"));
+
+    assert!(matches!(
+        parse_body::<BillsResponse>("\u{feff} \n"),
+        Err(ApiClientError::EmptyResponse)
+    ));
+}
+
+/// Retries every error on a fixed schedule.
+struct FixedSchedule(Vec<Duration>);
+
+impl RetryPolicy for FixedSchedule {
+    fn should_retry(&self, attempt: u32, _err: &ApiClientError) -> Option<Duration> {
+        self.0.get(attempt as usize - 1).copied()
+    }
+}
+
+/// Records requested delays instead of sleeping.
+#[derive(Clone, Default)]
+struct RecordingSleeper(Arc<Mutex<Vec<Duration>>>);
+
+impl Sleeper for RecordingSleeper {
+    fn sleep(&self, delay: Duration) {
+        self.0.lock().unwrap().push(delay);
+    }
+}
+
+/// Creates a cache dir named after the test and seeds it with `(cache key, body)` entries, as
+/// if a previous run had fetched them.
+fn seeded_cache<K, B>(name: &str, entries: impl IntoIterator<Item = (K, B)>) -> PathBuf
+where
+    K: AsRef<str>,
+    B: AsRef<str>,
+{
+    let dir = std::env::temp_dir().join(format!("cdg_api_{}_test_{}", name, std::process::id()));
+    let cache = DiskCache::new(&dir).expect("Failed to create cache");
+    for (key, body) in entries {
+        cache
+            .put(key.as_ref(), body.as_ref())
+            .expect("Failed to seed cache");
+    }
+    dir
+}
+
+/// A request received by [`stub_server`].
+#[derive(Debug, Clone)]
+struct StubRequest {
+    /// The path and query, e.g. `/v3/bill?format=json`.
+    target: String,
+    headers: Vec<(String, String)>,
+}
+
+impl StubRequest {
+    /// Returns the value of the header `name`, compared case-insensitively.
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Starts a local HTTP server answering every request with `status` and `body` after
+/// `delay`. Returns the base URL to pass to [`CongressApiClient::with_base_url`] and the
+/// requests received so far.
+fn stub_server(
+    status: u16,
+    body: &'static str,
+    delay: Duration,
+) -> (String, Arc<Mutex<Vec<StubRequest>>>) {
+    stub_server_with_headers(status, &[], body, delay)
+}
+
+/// Like [`stub_server`], but also sends the extra response `headers`.
+fn stub_server_with_headers(
+    status: u16,
+    headers: &[(&str, &str)],
+    body: impl Into<Vec<u8>>,
+    delay: Duration,
+) -> (String, Arc<Mutex<Vec<StubRequest>>>) {
+    let body = body.into();
+    let extra_headers: String = headers
+        .iter()
+        .map(|(name, value)| format!("{}: {}\r\n", name, value))
+        .collect();
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to bind stub server");
+    let base_url = format!("http://{}/v3/", listener.local_addr().unwrap());
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let recorder = Arc::clone(&requests);
+
+    std::thread::spawn(move || {
+        for mut stream in listener.incoming().flatten() {
+            // Read the request head; the client sends no body.
+            let mut lines = BufReader::new(&stream)
+                .lines()
+                .map_while(Result::ok)
+                .take_while(|line| !line.is_empty());
+            let Some(request_line) = lines.next() else {
+                continue;
+            };
+            let target = request_line
+                .split(' ')
+                .nth(1)
+                .unwrap_or_default()
+                .to_string();
+            let headers = lines
+                .filter_map(|line| {
+                    let (name, value) = line.split_once(':')?;
+                    Some((name.trim().to_string(), value.trim().to_string()))
+                })
+                .collect();
+
+            recorder
+                .lock()
+                .unwrap()
+                .push(StubRequest { target, headers });
+            std::thread::sleep(delay);
+            let _ = write!(
+                stream,
+                "HTTP/1.1 {} Stub\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\
+                 {}Connection: close\r\n\r\n",
+                status,
+                body.len(),
+                extra_headers,
+            )
+            .and_then(|()| stream.write_all(&body));
+        }
+    });
+
+    (base_url, requests)
+}
+
+/// Returns the targets of the requests received by [`stub_server`], in order.
+fn targets(requests: &Mutex<Vec<StubRequest>>) -> Vec<String> {
+    requests
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|request| request.target.clone())
+        .collect()
+}
+
+#[test]
+fn test_retries_use_injected_sleeper() {
+    let schedule = vec![Duration::from_secs(30), Duration::from_secs(60)];
+    let sleeper = RecordingSleeper::default();
+    let (base_url, requests) = stub_server(500, "", Duration::ZERO);
+
+    let client = CongressApiClient::new(Some("TEST_KEY".to_string()))
+        .expect("Failed to create client")
+        .with_base_url(base_url)
+        .retry_policy(FixedSchedule(schedule.clone()))
+        .sleeper(sleeper.clone());
+
+    let result = client.fetch::<GenericResponse>(Endpoints::new_generic(
+        "congress/current".to_string(),
+        Default::default(),
+    ));
+
+    assert!(matches!(
+        result,
+        Err(ApiClientError::ServerError { status: 500, .. })
+    ));
+    assert_eq!(requests.lock().unwrap().len(), 3);
+    assert_eq!(*sleeper.0.lock().unwrap(), schedule);
+}
+
+#[test]
+fn test_schema_error_reports_path_and_value() {
+    let body = r#"{"bills": [{"number": "1"}, {"number": ["not", "a", "string"]}]}"#;
+
+    match parse_body::<BillsResponse>(body) {
+        Err(ApiClientError::Schema { path, value, .. }) => {
+            assert_eq!(path, "bills[1].number");
+            assert_eq!(value, Some(serde_json::json!(["not", "a", "string"])));
+        }
+        other => panic!("expected a schema error, got {:?}", other.map(|_| ())),
+    }
+
+    assert!(matches!(
+        parse_body::<BillsResponse>("{\"bills\": ["),
+        Err(ApiClientError::Deserialization(_))
+    ));
+}
+
+#[test]
+fn test_response_cache_dir_replays_offline() {
+    let endpoint = Endpoints::new_bill_list(BillListParams::default().format(FormatType::Json));
+    let dir = seeded_cache(
+        "cache",
+        [(
+            endpoint.cache_key().unwrap(),
+            r#"{"bills": [{"congress": 118, "number": "1"}]}"#,
+        )],
+    );
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&calls);
+    let client = CongressApiClient::new(Some("TEST_KEY".to_string()))
+        .expect("Failed to create client")
+        .with_response_cache_dir(&dir)
+        .expect("Failed to open cache")
+        .on_build_request(move |request| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            request
+        });
+
+    let response: BillsResponse = client.fetch(endpoint).expect("Cached fetch failed");
+    assert_eq!(response.bills[0].number.as_deref(), Some("1"));
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_empty_body_yields_default_response() {
+    let endpoint = Endpoints::new_bill_amendments(
+        118,
+        BillType::Hr,
+        1,
+        BillAmendmentsParams::default().format(FormatType::Json),
+    );
+
+    let (base_url, requests) = stub_server(200, "", Duration::ZERO);
+    let dir = std::env::temp_dir().join(format!("cdg_api_empty_test_{}", std::process::id()));
+    let client = CongressApiClient::new(Some("TEST_KEY".to_string()))
+        .expect("Failed to create client")
+        .with_base_url(base_url)
+        .with_response_cache_dir(&dir)
+        .expect("Failed to open cache");
+
+    let response: BillAmendmentsResponse = client
+        .fetch(endpoint.clone())
+        .expect("Empty body must not fail");
+    assert!(response.amendments.is_empty());
+
+    // The empty body is not cached, so it is fetched again.
+    let _: BillAmendmentsResponse = client.fetch(endpoint).expect("Empty body must not fail");
+    assert_eq!(requests.lock().unwrap().len(), 2);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_truncated_cache_entry_is_refetched() {
+    let endpoint = Endpoints::new_bill_list(BillListParams::default().format(FormatType::Json));
+
+    // A process killed mid-write, or a reader racing the writer, would see these.
+    for (name, stale) in [("truncated", r#"{"bills": [{"num"#), ("blank", "")] {
+        let dir = seeded_cache(name, [(endpoint.cache_key().unwrap(), stale)]);
+        let (base_url, requests) =
+            stub_server(200, r#"{"bills": [{"number": "1"}]}"#, Duration::ZERO);
+        let client = CongressApiClient::new(Some("TEST_KEY".to_string()))
+            .expect("Failed to create client")
+            .with_base_url(base_url)
+            .with_response_cache_dir(&dir)
+            .expect("Failed to open cache");
+
+        for _ in 0..2 {
+            let response: BillsResponse = client.fetch(endpoint.clone()).expect("Fetch failed");
+            assert_eq!(response.bills[0].number.as_deref(), Some("1"));
+        }
+        // The bad entry was replaced by the refetched body.
+        assert_eq!(requests.lock().unwrap().len(), 1);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
+
+#[test]
+fn test_client_is_send_sync_and_clone() {
+    fn _assert_send_sync<T: Send + Sync>() {}
+    fn _assert_clone<T: Clone>() {}
+    _assert_send_sync::<CongressApiClient>();
+    _assert_clone::<CongressApiClient>();
+
+    let client = Arc::new(
+        CongressApiClient::new(Some("TEST_KEY".to_string())).expect("Failed to create client"),
+    );
+    let shared = Arc::clone(&client);
+    std::thread::spawn(move || drop(shared.clone()))
+        .join()
+        .expect("client must be usable from another thread");
+}
+
+#[test]
+fn test_error_body_is_truncated() {
+    let page = "<html>".to_string() + &"x".repeat(2 * 1024 * 1024) + "</html>";
+    let err = ApiClientError::from_status(StatusCode::SERVICE_UNAVAILABLE).with_body(page.clone());
+
+    match err {
+        ApiClientError::ServerError { status, body } => {
+            assert_eq!(status, 503);
+            assert!(body.starts_with("<html>"));
+            assert!(body.len() < MAX_ERROR_BODY_BYTES + 64);
+            assert!(body.ends_with(&format!(
+                "... [truncated {} bytes]",
+                page.len() - MAX_ERROR_BODY_BYTES
+            )));
+        }
+        other => panic!("expected a server error, got {:?}", other),
+    }
+
+    // Short bodies, and cuts inside multi-byte characters, are handled without panicking.
+    assert_eq!(truncate_error_body("short".to_string()), "short");
+    let multibyte = "é".repeat(MAX_ERROR_BODY_BYTES);
+    assert!(truncate_error_body(multibyte).contains("[truncated"));
+}
+
+#[test]
+fn test_retry_after_accepts_seconds_and_http_dates() {
+    let retry_after = |value: &str| {
+        let (base_url, _) =
+            stub_server_with_headers(429, &[("Retry-After", value)], "", Duration::ZERO);
+        let client = CongressApiClient::new(Some("TEST_KEY".to_string()))
+            .expect("Failed to create client")
+            .with_base_url(base_url);
+        match client.fetch::<BillsResponse>(Endpoints::new_bill_list(
+            BillListParams::default().format(FormatType::Json),
+        )) {
+            Err(ApiClientError::RateLimited { retry_after }) => retry_after,
+            other => panic!("expected a rate limit error, got {:?}", other),
+        }
+    };
+
+    assert_eq!(retry_after("120"), Some(Duration::from_secs(120)));
+    // A date in the past asks for no delay.
+    assert_eq!(
+        retry_after("Sun, 06 Nov 1994 08:49:37 GMT"),
+        Some(Duration::ZERO)
+    );
+    // A future date is converted to the time remaining until it.
+    let delay = retry_after("Fri, 01 Jan 2100 00:00:00 GMT").expect("date was not parsed");
+    assert!(delay > Duration::from_secs(60 * 60 * 24 * 365 * 50));
+    assert!(delay < Duration::from_secs(60 * 60 * 24 * 365 * 80));
+    assert_eq!(retry_after("soon"), None);
+    assert_eq!(retry_after("Sun, 31 Nov 1994 25:00:00 GMT"), None);
+}
+
+#[test]
+fn test_error_messages_are_actionable() {
+    assert_eq!(
+        ApiClientError::EnvVar("CDG_API_KEY".to_string()).to_string(),
+        "Missing API key: set CDG_API_KEY or pass a key to CongressApiClient::new"
+    );
+    assert_eq!(
+        ApiClientError::RateLimited {
+            retry_after: Some(Duration::from_secs(120)),
+        }
+        .to_string(),
+        "Rate limited: hourly quota exceeded; retry after 120 seconds"
+    );
+    assert_eq!(
+        ApiClientError::RateLimited { retry_after: None }.to_string(),
+        "Rate limited: hourly quota exceeded; retry later"
+    );
+
+    let err = parse_body::<BillsResponse>(r#"{"bills": [{"number": []}]}"#)
+        .map(|_| ())
+        .unwrap_err()
+        .with_url("https://api.congress.gov/v3/bill?format=json".to_string());
+    assert_eq!(
+        err.to_string(),
+        "Schema error at `bills[0].number` (found []): invalid type: sequence, \
+         expected a string at line 1 column 22 \
+         while fetching https://api.congress.gov/v3/bill?format=json"
+    );
+}
+
+#[test]
+fn test_fetch_many_preserves_order() {
+    // Six endpoints span two concurrent chunks. The last one is left uncached and fails
+    // with the stub's server error.
+    let endpoints: Vec<Endpoints> = (1..=6)
+        .map(|offset| {
+            Endpoints::new_bill_list(
+                BillListParams::default()
+                    .format(FormatType::Json)
+                    .offset(offset),
+            )
+        })
+        .collect();
+    let dir = seeded_cache(
+        "many",
+        endpoints.iter().take(5).enumerate().map(|(i, endpoint)| {
+            (
+                endpoint.cache_key().unwrap(),
+                format!(r#"{{"bills": [{{"number": "{}"}}]}}"#, i + 1),
+            )
+        }),
+    );
+
+    let (base_url, requests) = stub_server(500, "", Duration::ZERO);
+
+    let client = CongressApiClient::new(Some("TEST_KEY".to_string()))
+        .expect("Failed to create client")
+        .with_base_url(base_url)
+        .with_response_cache_dir(&dir)
+        .expect("Failed to open cache");
+
+    let results = client.fetch_many::<BillsResponse>(endpoints);
+    assert_eq!(results.len(), 6);
+    for (i, result) in results.iter().take(5).enumerate() {
+        let bills = &result.as_ref().expect("cached fetch failed").bills;
+        assert_eq!(
+            bills[0].number.as_deref(),
+            Some((i + 1).to_string().as_str())
+        );
+    }
+    assert!(matches!(
+        results[5],
+        Err(ApiClientError::ServerError { status: 500, .. })
+    ));
+    assert_eq!(
+        targets(&requests),
+        vec!["/v3/bill?format=json&offset=6&api_key=TEST_KEY"]
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_fetch_reports_nested_schema_path() {
+    let endpoint = Endpoints::new_bill_list(BillListParams::default().format(FormatType::Json));
+    let dir = seeded_cache(
+        "schema",
+        [(
+            endpoint.cache_key().unwrap(),
+            include_str!("fixtures/bills_type_mismatch.json"),
+        )],
+    );
+
+    let client = CongressApiClient::new(Some("TEST_KEY".to_string()))
+        .expect("Failed to create client")
+        .with_response_cache_dir(&dir)
+        .expect("Failed to open cache");
+
+    match client.fetch::<BillsResponse>(endpoint.clone()) {
+        Err(ApiClientError::Schema {
+            path, value, url, ..
+        }) => {
+            assert_eq!(path, "bills[3].latestAction.actionDate");
+            assert_eq!(value, Some(serde_json::json!(20240113)));
+            assert_eq!(url, Some(endpoint.cache_key().unwrap()));
+        }
+        other => panic!("expected a schema error, got {:?}", other.map(|_| ())),
+    }
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_api_key_location_header() {
+    let (base_url, requests) = stub_server(200, r#"{"bills": []}"#, Duration::ZERO);
+    let client = CongressApiClient::new(Some("TEST_KEY".to_string()))
+        .expect("Failed to create client")
+        .with_base_url(base_url)
+        .api_key_location(ApiKeyLocation::Header("X-Api-Key".to_string()));
+
+    client
+        .fetch::<BillsResponse>(Endpoints::new_bill_list(
+            BillListParams::default().format(FormatType::Json),
+        ))
+        .expect("Stubbed fetch failed");
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert!(!requests[0].target.contains("api_key="));
+    assert_eq!(requests[0].header("X-Api-Key"), Some("TEST_KEY"));
+}
+
+#[test]
+fn test_parse_body_lenient_drops_mismatched_fields() {
+    let body = r#"{"bills": [
+        {"number": "1", "latestAction": {"actionDate": 20240110}},
+        {"number": ["not", "a", "string"], "title": "Kept"}
+    ]}"#;
+
+    let (response, dropped) =
+        parse_body_lenient::<BillsResponse>(body).expect("lenient parse failed");
+    assert_eq!(
+        dropped,
+        vec!["bills[0].latestAction.actionDate", "bills[1].number"]
+    );
+    assert_eq!(response.bills.len(), 2);
+    assert_eq!(response.bills[0].number.as_deref(), Some("1"));
+    assert!(response.bills[0].latest_action.is_some());
+    assert_eq!(response.bills[1].number, None);
+    assert_eq!(response.bills[1].title.as_deref(), Some("Kept"));
+
+    // A required field cannot be recovered and still reports the raw value.
+    match parse_body_lenient::<BillsResponse>(r#"{"bills": 5}"#) {
+        Err(ApiClientError::Schema { .. }) => {}
+        other => panic!("expected a schema error, got {:?}", other.map(|_| ())),
+    }
+}
+
+#[test]
+fn test_zero_limit_is_rejected_before_sending() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&calls);
+    let client = CongressApiClient::new(Some("TEST_KEY".to_string()))
+        .expect("Failed to create client")
+        .on_build_request(move |request| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            request
+        });
+
+    let result = client.fetch::<BillsResponse>(Endpoints::new_bill_list(
+        BillListParams::default().format(FormatType::Json).limit(0),
+    ));
+
+    assert!(matches!(result, Err(ApiClientError::InvalidParam(_))));
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn test_fetch_all_items_follows_next_offset() {
+    let page = |offset: usize, limit: usize| {
+        Endpoints::new_bill_list(
+            BillListParams::default()
+                .format(FormatType::Json)
+                .offset(offset as u32)
+                .limit(limit as u32),
+        )
+    };
+
+    let dir = seeded_cache(
+        "pages",
+        [
+            (
+                page(0, 2).cache_key().unwrap(),
+                r#"{"bills": [{"number": "1"}, {"number": "2"}],
+                "pagination": {"next": "https://api.congress.gov/v3/bill?offset=2&limit=2"}}"#,
+            ),
+            (
+                page(2, 2).cache_key().unwrap(),
+                r#"{"bills": [{"number": "3"}], "pagination": {"count": 3}}"#,
+            ),
+        ],
+    );
+
+    let client = CongressApiClient::new(Some("TEST_KEY".to_string()))
+        .expect("Failed to create client")
+        .with_response_cache_dir(&dir)
+        .expect("Failed to open cache");
+
+    let bills = client
+        .fetch_all_items::<BillsResponse, _>(page, 10, 2)
+        .expect("Cached pages failed");
+    let numbers: Vec<_> = bills
+        .iter()
+        .filter_map(|bill| bill.number.clone())
+        .collect();
+    assert_eq!(numbers, vec!["1", "2", "3"]);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_fetch_into_minimal_struct() {
+    #[derive(serde::Deserialize)]
+    struct Titles {
+        bills: Vec<Title>,
+    }
+
+    #[derive(serde::Deserialize)]
+    struct Title {
+        title: String,
+    }
+
+    let endpoint = Endpoints::new_bill_list(BillListParams::default().format(FormatType::Json));
+    let body = r#"{
+        "bills": [
+            {"number": "1", "title": "A bill", "latestAction": {"text": "Introduced"}},
+            {"number": "2", "title": "Another bill"}
+        ],
+        "pagination": {"count": 2}
+    }"#;
+    let dir = seeded_cache("into", [(endpoint.cache_key().unwrap(), body)]);
+
+    let client = CongressApiClient::new(Some("TEST_KEY".to_string()))
+        .expect("Failed to create client")
+        .with_response_cache_dir(&dir)
+        .expect("Failed to open cache");
+    let titles: Titles = client
+        .fetch_into(endpoint.clone())
+        .expect("Cached fetch failed");
+    let titles: Vec<_> = titles.bills.into_iter().map(|bill| bill.title).collect();
+    assert_eq!(titles, vec!["A bill", "Another bill"]);
+
+    // Schema errors name the offending field and the request, as with `fetch`.
+    #[derive(Debug, serde::Deserialize)]
+    struct Numbers {
+        #[allow(dead_code)]
+        bills: Vec<u32>,
+    }
+    let err = client.fetch_into::<Numbers>(endpoint.clone()).unwrap_err();
+    assert!(matches!(
+        err,
+        ApiClientError::Schema { ref path, url: Some(ref url), .. }
+            if path == "bills[0]" && *url == endpoint.cache_key().unwrap()
+    ));
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_fetch_with_raw_returns_parsed_body() {
+    let endpoint = Endpoints::new_bill_list(BillListParams::default().format(FormatType::Json));
+    let body = r#"{"bills": [{"number": "1", "title": "A bill"}], "request": {"format": "json"}}"#;
+    let dir = seeded_cache("raw", [(endpoint.cache_key().unwrap(), body)]);
+
+    let client = CongressApiClient::new(Some("TEST_KEY".to_string()))
+        .expect("Failed to create client")
+        .with_response_cache_dir(&dir)
+        .expect("Failed to open cache");
+    let (bills, raw) = client
+        .fetch_with_raw::<BillsResponse>(endpoint)
+        .expect("Cached fetch failed");
+
+    assert_eq!(raw, body);
+    let reparsed: BillsResponse = parse_body(&raw).expect("Failed to parse raw body");
+    assert_eq!(bills.bills.len(), 1);
+    assert_eq!(bills.bills[0].title, reparsed.bills[0].title);
+    assert_eq!(bills.unknown, reparsed.unknown);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_fetch_with_warnings_reports_unknown_top_level_keys() {
+    let list = Endpoints::new_bill_list(BillListParams::default().format(FormatType::Json));
+    let mismatched = Endpoints::new_bill_list(BillListParams::default().format(FormatType::Xml));
+    let dir = seeded_cache(
+        "warnings",
+        [
+            (
+                list.cache_key().unwrap(),
+                r#"{"bills": [{"number": "1"}], "pagination": {"count": 1},
+                "request": {"format": "json"}, "newKey": true}"#,
+            ),
+            (
+                mismatched.cache_key().unwrap(),
+                r#"{"bills": {"number": "1"}}"#,
+            ),
+        ],
+    );
+
+    let client = CongressApiClient::new(Some("TEST_KEY".to_string()))
+        .expect("Failed to create client")
+        .with_response_cache_dir(&dir)
+        .expect("Failed to open cache");
+    let (bills, warnings) = client
+        .fetch_with_warnings::<BillsResponse>(list.clone())
+        .expect("Cached fetch failed");
+    assert_eq!(bills.bills.len(), 1);
+    assert_eq!(warnings, vec!["newKey"]);
+
+    // The catch-all model defines no keys to miss, even though it skips empty fields when
+    // serialized.
+    let (_, warnings) = client
+        .fetch_with_warnings::<GenericResponse>(list)
+        .expect("Cached fetch failed");
+    assert!(warnings.is_empty(), "{:?}", warnings);
+
+    let err = client
+        .fetch_with_warnings::<BillsResponse>(mismatched.clone())
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        ApiClientError::Schema { ref path, url: Some(ref url), .. }
+            if path == "bills" && *url == mismatched.cache_key().unwrap()
+    ));
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_fetch_with_meta_reports_cached_body_size() {
+    let endpoint = Endpoints::new_bill_list(BillListParams::default().format(FormatType::Json));
+    let body = r#"{"bills": [{"number": "1"}]}"#;
+    let dir = seeded_cache("meta", [(endpoint.cache_key().unwrap(), body)]);
+
+    let client = CongressApiClient::new(Some("TEST_KEY".to_string()))
+        .expect("Failed to create client")
+        .with_response_cache_dir(&dir)
+        .expect("Failed to open cache");
+    let (bills, meta) = client
+        .fetch_with_meta::<BillsResponse>(endpoint.clone())
+        .expect("Cached fetch failed");
+
+    assert_eq!(bills.bills.len(), 1);
+    assert_eq!(
+        meta,
+        ResponseMeta {
+            url: endpoint.cache_key().unwrap(),
+            from_cache: true,
+            body_bytes: body.len(),
+            ..ResponseMeta::default()
+        }
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+#[cfg(feature = "gzip")]
+fn test_fetch_with_meta_reports_compressed_size() {
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+
+    let body = format!(
+        r#"{{"bills": [{}]}}"#,
+        vec![r#"{"number": "1", "title": "A bill"}"#; 50].join(",")
+    );
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(body.as_bytes()).unwrap();
+    let compressed = encoder.finish().unwrap();
+    let compressed_len = compressed.len();
+
+    let (base_url, requests) = stub_server_with_headers(
+        200,
+        &[("Content-Encoding", "gzip")],
+        compressed,
+        Duration::ZERO,
+    );
+    let client = CongressApiClient::new(Some("TEST_KEY".to_string()))
+        .expect("Failed to create client")
+        .with_base_url(base_url);
+    let (bills, meta) = client
+        .fetch_with_meta::<BillsResponse>(Endpoints::new_bill_list(
+            BillListParams::default().format(FormatType::Json),
+        ))
+        .expect("Compressed fetch failed");
+
+    assert_eq!(bills.bills.len(), 50);
+    assert_eq!(
+        requests.lock().unwrap()[0].header("Accept-Encoding"),
+        Some("gzip")
+    );
+    assert_eq!(meta.content_encoding.as_deref(), Some("gzip"));
+    assert_eq!(meta.wire_bytes, Some(compressed_len as u64));
+    assert_eq!(meta.body_bytes, body.len());
+    assert!(compressed_len < body.len());
+
+    // Streamed responses are decompressed as they are read.
+    #[cfg(feature = "streaming")]
+    {
+        let count = client
+            .fetch_streaming::<BillsResponse, _>(
+                Endpoints::new_bill_list(BillListParams::default().format(FormatType::Json)),
+                |_| {},
+            )
+            .expect("Compressed stream failed");
+        assert_eq!(count, 50);
+    }
+}
+
+#[test]
+fn test_fetch_with_meta_reports_uncompressed_size() {
+    let body = r#"{"bills": [{"number": "1"}]}"#;
+    let (base_url, _) = stub_server(200, body, Duration::ZERO);
+    let client = CongressApiClient::new(Some("TEST_KEY".to_string()))
+        .expect("Failed to create client")
+        .with_base_url(base_url);
+    let (_, meta) = client
+        .fetch_with_meta::<BillsResponse>(Endpoints::new_bill_list(
+            BillListParams::default().format(FormatType::Json),
+        ))
+        .expect("Fetch failed");
+
+    assert_eq!(meta.content_encoding, None);
+    assert_eq!(meta.wire_bytes, Some(body.len() as u64));
+    assert_eq!(meta.body_bytes, body.len());
+}
+
+#[test]
+fn test_fetch_as_overrides_params_format() {
+    let endpoint = Endpoints::new_bill_list(BillListParams::default().format(FormatType::Xml));
+    let json_key = "https://api.congress.gov/v3/bill?format=json";
+    let xml_key = "https://api.congress.gov/v3/bill?format=xml";
+    let dir = seeded_cache(
+        "format",
+        [
+            (json_key, r#"{"bills": [{"number": "1"}]}"#),
+            (xml_key, "<api-root><bills/></api-root>"),
+        ],
+    );
+
+    let client = CongressApiClient::new(Some("TEST_KEY".to_string()))
+        .expect("Failed to create client")
+        .with_response_cache_dir(&dir)
+        .expect("Failed to open cache");
+
+    assert_eq!(endpoint.cache_key().unwrap(), xml_key);
+    let bills: BillsResponse = client
+        .fetch_as(endpoint.clone(), FormatType::Json)
+        .expect("Cached JSON fetch failed");
+    assert_eq!(bills.bills.len(), 1);
+
+    let xml = client
+        .fetch_text_as(endpoint, FormatType::Xml)
+        .expect("Cached XML fetch failed");
+    assert_eq!(xml, "<api-root><bills/></api-root>");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_clients_share_rate_limiter() {
+    let limiter = Arc::new(RateLimiter::new(2, Duration::from_secs(7200)));
+    let (base_url, requests) = stub_server(200, "{}", Duration::ZERO);
+    let client = |sleeper: &RecordingSleeper| {
+        CongressApiClient::new(Some("TEST_KEY".to_string()))
+            .expect("Failed to create client")
+            .with_base_url(base_url.clone())
+            .sleeper(sleeper.clone())
+            .with_rate_limiter(Arc::clone(&limiter))
+    };
+    let (sleeper_a, sleeper_b) = (RecordingSleeper::default(), RecordingSleeper::default());
+    let (client_a, client_b) = (client(&sleeper_a), client(&sleeper_b));
+    let endpoint = || Endpoints::new_generic("congress/current".to_string(), Default::default());
+
+    let fetch = |client: &CongressApiClient| {
+        client
+            .fetch::<GenericResponse>(endpoint())
+            .expect("Stubbed fetch failed");
+    };
+
+    fetch(&client_a);
+    fetch(&client_b);
+    assert!(sleeper_a.0.lock().unwrap().is_empty());
+    assert!(sleeper_b.0.lock().unwrap().is_empty());
+
+    // The bucket is empty, so the third request waits for the next token (one per hour),
+    // and the fourth for the one after it.
+    fetch(&client_b);
+    fetch(&client_a);
+    assert_eq!(requests.lock().unwrap().len(), 4);
+    let waited_b = sleeper_b.0.lock().unwrap().clone();
+    let waited_a = sleeper_a.0.lock().unwrap().clone();
+    assert_eq!(waited_b.len(), 1);
+    assert_eq!(waited_a.len(), 1);
+    assert!(waited_b[0] > Duration::from_secs(3500) && waited_b[0] <= Duration::from_secs(3600));
+    assert!(waited_a[0] > Duration::from_secs(7100) && waited_a[0] <= Duration::from_secs(7200));
+}
+
+#[test]
+fn test_on_page_reports_progress() {
+    let page = |offset: usize, limit: usize| {
+        Endpoints::new_bill_list(
+            BillListParams::default()
+                .format(FormatType::Json)
+                .offset(offset as u32)
+                .limit(limit as u32),
+        )
+    };
+
+    let dir = seeded_cache(
+        "on_page",
+        [
+            (
+                page(0, 2).cache_key().unwrap(),
+                r#"{"bills": [{"number": "1"}, {"number": "2"}],
+                "pagination": {"next": "https://api.congress.gov/v3/bill?offset=2&limit=2"}}"#,
+            ),
+            (
+                page(2, 2).cache_key().unwrap(),
+                r#"{"bills": [{"number": "3"}]}"#,
+            ),
+        ],
+    );
+
+    let stats = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&stats);
+    let client = CongressApiClient::new(Some("TEST_KEY".to_string()))
+        .expect("Failed to create client")
+        .with_response_cache_dir(&dir)
+        .expect("Failed to open cache")
+        .on_page(move |page| recorded.lock().unwrap().push(page));
+
+    let bills = client
+        .fetch_all_items::<BillsResponse, _>(page, 10, 2)
+        .expect("Cached pages failed");
+    assert_eq!(bills.len(), 3);
+
+    let stats = stats.lock().unwrap();
+    let progress: Vec<_> = stats
+        .iter()
+        .map(|page| (page.offset, page.fetched, page.total_so_far))
+        .collect();
+    assert_eq!(progress, vec![(0, 2, 2), (2, 1, 3)]);
+    assert!(stats[0].elapsed <= stats[1].elapsed);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_plan_urls_is_offline_and_redacted() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&calls);
+    let client = CongressApiClient::new(Some("SECRET_KEY".to_string()))
+        .expect("Failed to create client")
+        .on_build_request(move |request| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            request
+        });
+
+    let urls = client
+        .plan_urls(&[
+            Endpoints::new_bill_list(
+                BillListParams::default()
+                    .format(FormatType::Json)
+                    .limit(250),
+            ),
+            Endpoints::new_bill_amendments(
+                118,
+                BillType::Hr,
+                3076,
+                BillAmendmentsParams::default().format(FormatType::Json),
+            ),
+        ])
+        .expect("Failed to plan URLs");
+
+    assert_eq!(
+        urls,
+        vec![
+            "https://api.congress.gov/v3/bill?format=json&limit=250",
+            "https://api.congress.gov/v3/bill/118/hr/3076/amendments?format=json",
+        ]
+    );
+    assert!(urls.iter().all(|url| !url.contains("SECRET_KEY")));
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn test_contradictory_params_are_rejected_before_sending() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&calls);
+    let client = CongressApiClient::new(Some("TEST_KEY".to_string()))
+        .expect("Failed to create client")
+        .on_build_request(move |request| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            request
+        });
+
+    let result = client.fetch::<GenericResponse>(Endpoints::new_generic(
+        "congressional-record".to_string(),
+        GenericParams::default()
+            .year(2024)
+            .to_date_time("2024-01-01T00:00:00Z".to_string()),
+    ));
+
+    assert!(matches!(result, Err(ApiClientError::InvalidParam(_))));
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn test_request_id_is_reused_across_retries() {
+    let generated = Arc::new(AtomicUsize::new(0));
+    let generator_calls = Arc::clone(&generated);
+    let (base_url, requests) = stub_server(500, "", Duration::ZERO);
+
+    let client = CongressApiClient::new(Some("TEST_KEY".to_string()))
+        .expect("Failed to create client")
+        .with_base_url(base_url)
+        .retry_policy(FixedSchedule(vec![Duration::ZERO, Duration::ZERO]))
+        .sleeper(RecordingSleeper::default())
+        .with_request_id_header("X-Request-Id")
+        .with_request_id_generator(move || {
+            format!("id-{}", generator_calls.fetch_add(1, Ordering::SeqCst))
+        });
+
+    let result = client.fetch::<GenericResponse>(Endpoints::new_generic(
+        "congress/current".to_string(),
+        Default::default(),
+    ));
+
+    assert!(result.is_err());
+    assert_eq!(generated.load(Ordering::SeqCst), 1);
+    let ids: Vec<_> = requests
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|request| request.header("X-Request-Id").map(str::to_string))
+        .collect();
+    assert_eq!(ids, vec![Some("id-0".to_string()); 3]);
+}
+
+#[test]
+fn test_accept_language_header_is_opt_in() {
+    let (base_url, requests) = stub_server(200, "{}", Duration::ZERO);
+
+    for tag in [None, Some("es, en;q=0.8")] {
+        let mut client = CongressApiClient::new(Some("TEST_KEY".to_string()))
+            .expect("Failed to create client")
+            .with_base_url(base_url.clone());
+        if let Some(tag) = tag {
+            client = client.with_accept_language(tag);
+        }
+
+        client
+            .fetch::<GenericResponse>(Endpoints::new_generic(
+                "congress/current".to_string(),
+                Default::default(),
+            ))
+            .expect("Stubbed fetch failed");
+    }
+
+    let seen: Vec<_> = requests
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|request| request.header("Accept-Language").map(str::to_string))
+        .collect();
+    assert_eq!(seen, vec![None, Some("es, en;q=0.8".to_string())]);
+}
+
+#[test]
+fn test_resource_budget_refuses_requests_once_spent() {
+    let (base_url, requests) = stub_server(200, "{}", Duration::ZERO);
+    let client = CongressApiClient::new(Some("TEST_KEY".to_string()))
+        .expect("Failed to create client")
+        .with_base_url(base_url)
+        .with_resource_budget("member", 2);
+    let member =
+        || Endpoints::new_member_details("A000360".to_string(), MemberDetailsParams::default());
+    let bills = || Endpoints::new_bill_list(BillListParams::default());
+
+    for _ in 0..2 {
+        client
+            .fetch::<GenericResponse>(member())
+            .expect("Stubbed fetch failed");
+    }
+    let result = client.clone().fetch::<GenericResponse>(member());
+    assert!(matches!(
+        result,
+        Err(ApiClientError::BudgetExceeded { ref resource, max_per_hour: 2 }) if resource == "member"
+    ));
+
+    // Resources without a budget are unaffected.
+    client
+        .fetch::<GenericResponse>(bills())
+        .expect("Stubbed fetch failed");
+
+    let paths: Vec<_> = targets(&requests)
+        .iter()
+        .map(|target| target.split('?').next().unwrap().to_string())
+        .collect();
+    assert_eq!(
+        paths,
+        vec!["/v3/member/A000360", "/v3/member/A000360", "/v3/bill"]
+    );
+}
+
+#[test]
+fn test_default_request_ids_are_uuids() {
+    let (base_url, requests) = stub_server(200, r#"{"congress": {}}"#, Duration::ZERO);
+    let client = CongressApiClient::new(Some("TEST_KEY".to_string()))
+        .expect("Failed to create client")
+        .with_base_url(base_url)
+        .with_request_id_header("X-Request-Id");
+
+    for _ in 0..2 {
+        client
+            .fetch::<GenericResponse>(Endpoints::new_generic(
+                "congress/current".to_string(),
+                Default::default(),
+            ))
+            .expect("Stubbed fetch failed");
+    }
+
+    let seen: Vec<String> = requests
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|request| request.header("X-Request-Id").unwrap().to_string())
+        .collect();
+    assert_eq!(seen.len(), 2);
+    assert_ne!(seen[0], seen[1]);
+    for id in seen.iter() {
+        let groups: Vec<usize> = id.split('-').map(str::len).collect();
+        assert_eq!(groups, vec![8, 4, 4, 4, 12]);
+        assert!(id.chars().all(|c| c == '-' || c.is_ascii_hexdigit()));
+        assert_eq!(&id[14..15], "4");
+        assert!("89ab".contains(&id[19..20]));
+    }
+}
+
+#[test]
+fn test_concurrent_cache_misses_send_one_request() {
+    let endpoint = Endpoints::new_bill_list(BillListParams::default().format(FormatType::Json));
+    let dir = std::env::temp_dir().join(format!("cdg_api_flight_test_{}", std::process::id()));
+
+    // The slow response gives the other threads time to pile up behind the first request.
+    let (base_url, requests) = stub_server(
+        200,
+        r#"{"bills": [{"number": "1"}]}"#,
+        Duration::from_millis(200),
+    );
+    let client = CongressApiClient::new(Some("TEST_KEY".to_string()))
+        .expect("Failed to create client")
+        .with_base_url(base_url)
+        .with_response_cache_dir(&dir)
+        .expect("Failed to open cache");
+
+    let results = client.fetch_many::<BillsResponse>(vec![endpoint; 4]);
+
+    assert_eq!(requests.lock().unwrap().len(), 1);
+    for result in results {
+        let bills = result.expect("Fetch failed").bills;
+        assert_eq!(bills[0].number.as_deref(), Some("1"));
+    }
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_format_param_is_never_duplicated() {
+    let (base_url, requests) = stub_server(200, r#"{"bills": []}"#, Duration::ZERO);
+    let client = CongressApiClient::new(Some("TEST_KEY".to_string()))
+        .expect("Failed to create client")
+        .with_base_url(base_url);
+
+    let endpoints = vec![
+        Endpoints::new_bill_list(BillListParams::default().format(FormatType::Json)),
+        Endpoints::new_bill_list(BillListParams::default()),
+    ];
+    for endpoint in endpoints.clone() {
+        client
+            .fetch::<BillsResponse>(endpoint)
+            .expect("Stubbed fetch failed");
+    }
+
+    let sent = targets(&requests);
+    assert_eq!(sent.len(), 2);
+    let planned = client.plan_urls(&endpoints).expect("Failed to plan URLs");
+    assert_eq!(
+        planned,
+        vec!["https://api.congress.gov/v3/bill?format=json"; 2]
+    );
+    for url in sent.iter().chain(&planned) {
+        assert_eq!(url.matches("format=").count(), 1, "{}", url);
+        assert!(url.contains("format=json"), "{}", url);
+    }
+}
+
+#[test]
+fn test_health_check_bypasses_cache() {
+    let dir = seeded_cache(
+        "health",
+        [(
+            "https://api.congress.gov/v3/congress/current?format=json&limit=1",
+            r#"{"congress": {"number": 118}}"#,
+        )],
+    );
+    let client = |base_url: String| {
+        CongressApiClient::new(Some("TEST_KEY".to_string()))
+            .expect("Failed to create client")
+            .with_base_url(base_url)
+            .with_response_cache_dir(&dir)
+            .expect("Failed to open cache")
+    };
+
+    let (base_url, requests) = stub_server(200, r#"{"congress": {"number": 118}}"#, Duration::ZERO);
+    client(base_url)
+        .health_check()
+        .expect("Health check failed");
+    assert_eq!(
+        targets(&requests),
+        vec!["/v3/congress/current?format=json&api_key=TEST_KEY&limit=1"]
+    );
+
+    // A rejected key is reported as such, even though the cache holds a good answer.
+    let (base_url, requests) = stub_server(403, r#"{"error": "API_KEY_INVALID"}"#, Duration::ZERO);
+    assert!(matches!(
+        client(base_url).health_check(),
+        Err(ApiClientError::Unauthorized)
+    ));
+    assert_eq!(requests.lock().unwrap().len(), 1);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_fetch_url_follows_api_links_only() {
+    let url = "https://api.congress.gov/v3/bill/118/hr/3076/amendments?format=json";
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&calls);
+    let dir = seeded_cache("url", [(url, r#"{"amendments": [{"number": "5"}]}"#)]);
+
+    let client = CongressApiClient::new(Some("TEST_KEY".to_string()))
+        .expect("Failed to create client")
+        .with_response_cache_dir(&dir)
+        .expect("Failed to open cache")
+        .on_build_request(move |request| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            request
+        });
+
+    let response: BillAmendmentsResponse = client.fetch_url(url).expect("Cached fetch failed");
+    assert_eq!(response.amendments[0].number.as_deref(), Some("5"));
+
+    for foreign in [
+        "https://example.com/v3/bill/118/hr/3076/amendments?format=json",
+        "https://api.congress.gov.example.com/v3/bill?format=json",
+        "http://api.congress.gov/v3/bill?format=json",
+    ] {
+        let result = client.fetch_url::<GenericResponse>(foreign);
+        assert!(
+            matches!(result, Err(ApiClientError::InvalidParam(_))),
+            "{}",
+            foreign
+        );
+    }
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_fetch_updated_since_sorts_and_advances_watermark() {
+    let (base_url, requests) = stub_server(
+        200,
+        r#"{"bills": [
+            {"number": "1", "updateDate": "2024-01-10"},
+            {"number": "2", "updateDate": "2024-02-03T09:30:00Z"}
+        ]}"#,
+        Duration::ZERO,
+    );
+    let client = CongressApiClient::new(Some("TEST_KEY".to_string()))
+        .expect("Failed to create client")
+        .with_base_url(base_url);
+    let endpoint_fn = |offset: usize, limit: usize| {
+        Endpoints::new_bill_list(
+            BillListParams::default()
+                .format(FormatType::Json)
+                .offset(offset as u32)
+                .limit(limit as u32)
+                .sort(SortType::UpdateDateDesc),
+        )
+    };
+
+    let (bills, watermark) = client
+        .fetch_updated_since::<BillsResponse, _>(endpoint_fn, Some("2024-01-10"), 10, 250)
+        .expect("Stubbed sync failed");
+
+    assert_eq!(bills.len(), 2);
+    assert_eq!(watermark.as_deref(), Some("2024-02-03T09:30:00Z"));
+
+    let sent = targets(&requests);
+    assert_eq!(sent.len(), 1);
+    let url = &sent[0];
+    assert!(url.contains("fromDateTime=2024-01-10T00:00:00Z"), "{}", url);
+    assert!(url.contains("sort=updateDate+asc"), "{}", url);
+    assert!(!url.contains("desc"), "{}", url);
+}
+
+#[test]
+fn test_fetch_updated_since_resolves_current_congress() {
+    // The same body answers the `congress/current` lookup and the bill list.
+    let (base_url, requests) = stub_server(
+        200,
+        r#"{"congress": {"number": 119}, "bills": [{"number": "1", "updateDate": "2025-02-01"}]}"#,
+        Duration::ZERO,
+    );
+    let client = CongressApiClient::new(Some("TEST_KEY".to_string()))
+        .expect("Failed to create client")
+        .with_base_url(base_url)
+        .with_congress_range(110, 119);
+    let endpoint_fn = |offset: usize, limit: usize| {
+        Endpoints::new_bill_by_type(
+            Congress::Current,
+            BillType::Hr,
+            BillByTypeParams::default()
+                .format(FormatType::Json)
+                .offset(offset as u32)
+                .limit(limit as u32),
+        )
+    };
+
+    let (bills, _) = client
+        .fetch_updated_since::<BillsResponse, _>(endpoint_fn, Some("2025-01-01"), 10, 250)
+        .expect("Stubbed sync failed");
+    assert_eq!(bills.len(), 1);
+
+    let sent = targets(&requests);
+    assert_eq!(sent.len(), 2);
+    assert!(sent[1].starts_with("/v3/bill/119/hr?"), "{}", sent[1]);
+    assert!(sent[1].contains("sort=updateDate+asc"), "{}", sent[1]);
+    assert!(
+        sent[1].contains("fromDateTime=2025-01-01T00:00:00Z"),
+        "{}",
+        sent[1]
+    );
+
+    // Endpoints whose params cannot be sorted are rejected before anything is sent.
+    let result = client.fetch_updated_since::<BillsResponse, _>(
+        |_, _| Endpoints::new_law_by_congress(118, LawParams::default()),
+        None,
+        10,
+        250,
+    );
+    assert!(matches!(result, Err(ApiClientError::InvalidParam(_))));
+    assert_eq!(targets(&requests).len(), 2);
+}
+
+#[test]
+fn test_congress_range_rejects_before_sending() {
+    let (base_url, requests) = stub_server(200, r#"{"bills": []}"#, Duration::ZERO);
+    let client = CongressApiClient::new(Some("TEST_KEY".to_string()))
+        .expect("Failed to create client")
+        .with_base_url(base_url)
+        .with_congress_range(110, 118);
+    let bills = |congress| {
+        Endpoints::new_bill_by_congress(
+            congress,
+            BillByCongressParams::default().format(FormatType::Json),
+        )
+    };
+
+    for congress in [109, 119] {
+        let result = client.fetch::<BillsResponse>(bills(congress));
+        assert!(
+            matches!(result, Err(ApiClientError::InvalidParam(_))),
+            "{}",
+            congress
+        );
+    }
+    assert!(matches!(
+        client.count(bills(100)),
+        Err(ApiClientError::InvalidParam(_))
+    ));
+    assert!(requests.lock().unwrap().is_empty());
+
+    client
+        .fetch::<BillsResponse>(bills(118))
+        .expect("Stubbed fetch failed");
+    assert_eq!(
+        targets(&requests),
+        vec!["/v3/bill/118?format=json&api_key=TEST_KEY"]
+    );
+}
+
+#[test]
+fn test_plan_urls_uses_only_a_cached_current_congress() {
+    let (base_url, requests) = stub_server(200, r#"{"congress": {"number": 119}}"#, Duration::ZERO);
+    let client = CongressApiClient::new(Some("TEST_KEY".to_string()))
+        .expect("Failed to create client")
+        .with_base_url(base_url);
+    let laws = [Endpoints::new_law_by_congress(
+        Congress::Current,
+        LawParams::default().format(FormatType::Json),
+    )];
+
+    assert!(matches!(
+        client.plan_urls(&laws),
+        Err(ApiClientError::InvalidParam(_))
+    ));
+    assert!(requests.lock().unwrap().is_empty());
+
+    assert_eq!(
+        client.current_congress().expect("Stubbed lookup failed"),
+        119
+    );
+    assert_eq!(
+        client.plan_urls(&laws).expect("Failed to plan URLs"),
+        vec!["https://api.congress.gov/v3/law/119?format=json"]
+    );
+    assert_eq!(requests.lock().unwrap().len(), 1);
+}
+
+#[test]
+fn test_congress_zero_is_rejected_before_sending() {
+    let (base_url, requests) = stub_server(200, r#"{"bills": []}"#, Duration::ZERO);
+    let client = CongressApiClient::new(Some("TEST_KEY".to_string()))
+        .expect("Failed to create client")
+        .with_base_url(base_url);
+
+    let result = client.fetch::<BillsResponse>(Endpoints::new_bill_by_congress(
+        0,
+        BillByCongressParams::default().format(FormatType::Json),
+    ));
+    assert!(matches!(result, Err(ApiClientError::InvalidParam(_))));
+    assert!(requests.lock().unwrap().is_empty());
+}
+
+#[test]
+fn test_requests_sent_counts_attempts_not_cache_hits() {
+    let cached = Endpoints::new_bill_list(BillListParams::default().format(FormatType::Json));
+    let dir = seeded_cache("sent", [(cached.cache_key().unwrap(), r#"{"bills": []}"#)]);
+    let (base_url, requests) = stub_server(500, "", Duration::ZERO);
+
+    let client = CongressApiClient::new(Some("TEST_KEY".to_string()))
+        .expect("Failed to create client")
+        .with_base_url(base_url)
+        .with_response_cache_dir(&dir)
+        .expect("Failed to open cache")
+        .retry_policy(FixedSchedule(vec![Duration::ZERO]))
+        .sleeper(RecordingSleeper::default());
+    let clone = client.clone();
+
+    client
+        .fetch::<BillsResponse>(cached)
+        .expect("Cached fetch failed");
+    assert_eq!(client.requests_sent(), 0);
+
+    let result = clone.fetch::<GenericResponse>(Endpoints::new_generic(
+        "congress/current".to_string(),
+        Default::default(),
+    ));
+    assert!(matches!(
+        result,
+        Err(ApiClientError::ServerError { status: 500, .. })
+    ));
+    assert_eq!(client.requests_sent(), 2);
+    assert_eq!(requests.lock().unwrap().len(), 2);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[cfg(feature = "logging")]
+#[test]
+fn test_rate_limited_response_is_logged() {
+    /// Keeps every log message for the assertions below.
+    struct CapturingLogger(Mutex<Vec<String>>);
+
+    impl log::Log for CapturingLogger {
+        fn enabled(&self, _: &log::Metadata) -> bool {
+            true
+        }
+
+        fn log(&self, record: &log::Record) {
+            self.0
+                .lock()
+                .unwrap()
+                .push(format!("{} {}", record.level(), record.args()));
+        }
+
+        fn flush(&self) {}
+    }
+
+    static LOGGER: CapturingLogger = CapturingLogger(Mutex::new(Vec::new()));
+    log::set_logger(&LOGGER).expect("Failed to install logger");
+    log::set_max_level(log::LevelFilter::Warn);
+
+    let (base_url, _) = stub_server(429, "", Duration::ZERO);
+    let client = CongressApiClient::new(Some("TEST_KEY".to_string()))
+        .expect("Failed to create client")
+        .with_base_url(base_url);
+
+    let result = client.fetch::<BillsResponse>(Endpoints::new_bill_list(
+        BillListParams::default().format(FormatType::Json),
+    ));
+    assert!(matches!(
+        result,
+        Err(ApiClientError::RateLimited { retry_after: None })
+    ));
+
+    let messages = LOGGER.0.lock().unwrap();
+    let warning = messages
+        .iter()
+        .find(|message| message.contains("(429)"))
+        .expect("429 was not logged");
+    assert!(warning.starts_with("WARN"), "{}", warning);
+    assert!(warning.contains("on `bill`"), "{}", warning);
+    assert!(warning.contains("an unspecified delay"), "{}", warning);
+    assert!(warning.contains("1 requests sent"), "{}", warning);
+}
+
+#[test]
+fn test_default_limit_applies_to_unlimited_lists_only() {
+    let client = CongressApiClient::new(Some("TEST_KEY".to_string()))
+        .expect("Failed to create client")
+        .default_limit(1000);
+    let json = FormatType::Json;
+
+    let urls = client
+        .plan_urls(&[
+            Endpoints::new_bill_list(BillListParams::default().format(json)),
+            Endpoints::new_bill_list(BillListParams::default().format(json).limit(10)),
+            Endpoints::new_bill_details(
+                118,
+                BillType::Hr,
+                3076,
+                BillDetailsParams::default().format(json),
+            ),
+        ])
+        .expect("Failed to plan URLs");
+
+    assert_eq!(
+        urls,
+        vec![
+            "https://api.congress.gov/v3/bill?format=json&limit=250",
+            "https://api.congress.gov/v3/bill?format=json&limit=10",
+            "https://api.congress.gov/v3/bill/118/hr/3076?format=json",
+        ]
+    );
+}
+
+#[test]
+fn test_record_to_dir_saves_readable_fixtures() {
+    let endpoint = Endpoints::new_bill_amendments(
+        118,
+        BillType::Hr,
+        3076,
+        BillAmendmentsParams::default().format(FormatType::Json),
+    );
+    let body = r#"{"amendments": [{"number": "5"}]}"#;
+    let cache_dir = seeded_cache("record", [(endpoint.cache_key().unwrap(), body)]);
+    let record_dir = cache_dir.join("fixtures");
+
+    let client = CongressApiClient::new(Some("SECRET_KEY".to_string()))
+        .expect("Failed to create client")
+        .with_response_cache_dir(&cache_dir)
+        .expect("Failed to open cache")
+        .record_to_dir(&record_dir)
+        .expect("Failed to create recording dir");
+    client
+        .fetch::<BillAmendmentsResponse>(endpoint.clone())
+        .expect("Cached fetch failed");
+
+    let name = fixture_file_name(&endpoint.cache_key().unwrap());
+    assert_eq!(name, "bill_118_hr_3076_amendments_format_json.json");
+    let recorded = std::fs::read_to_string(record_dir.join(&name)).expect("missing fixture");
+    assert_eq!(recorded, body);
+
+    std::fs::remove_dir_all(&cache_dir).ok();
+}
+
+#[test]
+fn test_category_default_formats() {
+    let client = CongressApiClient::new(Some("TEST_KEY".to_string()))
+        .expect("Failed to create client")
+        .detail_format(FormatType::Xml)
+        .text_format(FormatType::Xml);
+
+    let urls = client
+        .plan_urls(&[
+            // List endpoints keep the JSON default.
+            Endpoints::new_bill_list(BillListParams::default()),
+            // Detail and text endpoints take the client's category default...
+            Endpoints::new_bill_details(118, BillType::Hr, 3076, BillDetailsParams::default()),
+            Endpoints::new_bill_text(118, BillType::Hr, 3076, BillTextParams::default()),
+            // ...unless the params set a format.
+            Endpoints::new_bill_text(
+                118,
+                BillType::Hr,
+                3076,
+                BillTextParams::default().format(FormatType::Json),
+            ),
+        ])
+        .expect("Failed to plan URLs");
+
+    assert_eq!(
+        urls,
+        vec![
+            "https://api.congress.gov/v3/bill?format=json",
+            "https://api.congress.gov/v3/bill/118/hr/3076?format=xml",
+            "https://api.congress.gov/v3/bill/118/hr/3076/text?format=xml",
+            "https://api.congress.gov/v3/bill/118/hr/3076/text?format=json",
+        ]
+    );
+
+    // A text default wins over the detail default.
+    let client = CongressApiClient::new(Some("TEST_KEY".to_string()))
+        .expect("Failed to create client")
+        .detail_format(FormatType::Xml)
+        .text_format(FormatType::Json);
+    let urls = client
+        .plan_urls(&[Endpoints::new_bill_text(
+            118,
+            BillType::Hr,
+            3076,
+            BillTextParams::default(),
+        )])
+        .expect("Failed to plan URLs");
+    assert_eq!(
+        urls,
+        vec!["https://api.congress.gov/v3/bill/118/hr/3076/text?format=json"]
+    );
+}
@@ -0,0 +1,1625 @@
+use cdg_api::client::cdg_client::ApiClientError;
+use cdg_api::{
+    cdg_types::{
+        AmendmentType, BillType, ChamberType, CommitteeReportType, CommunicationType, Congress,
+        FormatType, LawType, SortType, TreatySuffix,
+    },
+    endpoints::{Endpoints, NewEndpoint},
+    param_models::*,
+    url_builders::generate_url,
+};
+use reqwest::Method;
+
+#[test]
+fn test_cache_key_ignores_api_key() {
+    // Define an endpoint to be requested by two clients using different keys.
+    let endpoint =
+        Endpoints::new_bill_list(BillListParams::default().format(FormatType::Json).limit(10));
+
+    let first = generate_url(endpoint.clone(), "FIRST_KEY").unwrap();
+    let second = generate_url(endpoint.clone(), "SECOND_KEY").unwrap();
+
+    // The request URLs differ, but the cache key must not.
+    assert_ne!(first, second);
+    assert!(!endpoint.cache_key().unwrap().contains("api_key"));
+    assert!(first.starts_with(&endpoint.cache_key().unwrap()));
+    assert!(second.starts_with(&endpoint.cache_key().unwrap()));
+}
+
+#[test]
+fn test_bill_by_type_url_separator() {
+    let endpoint = Endpoints::new_bill_by_type(
+        118,
+        BillType::Hr,
+        BillByTypeParams::default()
+            .format(FormatType::Json)
+            .limit(10),
+    );
+
+    assert_eq!(endpoint.to_string(), "bill/118/hr?format=json&limit=10");
+}
+
+#[test]
+fn test_bill_sub_resource_urls() {
+    let cases = vec![
+        (
+            Endpoints::new_bill_actions(
+                118,
+                BillType::Hr,
+                1,
+                BillActionsParams::default().format(FormatType::Json),
+            ),
+            "bill/118/hr/1/actions?format=json",
+        ),
+        (
+            Endpoints::new_bill_cosponsors(
+                118,
+                BillType::Hr,
+                1,
+                BillCosponsorsParams::default().format(FormatType::Json),
+            ),
+            "bill/118/hr/1/cosponsors?format=json",
+        ),
+        (
+            Endpoints::new_bill_committees(
+                118,
+                BillType::Hr,
+                1,
+                BillCommitteesParams::default().format(FormatType::Json),
+            ),
+            "bill/118/hr/1/committees?format=json",
+        ),
+        (
+            Endpoints::new_bill_related(
+                118,
+                BillType::Hr,
+                1,
+                BillRelatedParams::default().format(FormatType::Json),
+            ),
+            "bill/118/hr/1/relatedbills?format=json",
+        ),
+        (
+            Endpoints::new_bill_subjects(
+                118,
+                BillType::Hr,
+                1,
+                BillSubjectsParams::default().format(FormatType::Json),
+            ),
+            "bill/118/hr/1/subjects?format=json",
+        ),
+        (
+            Endpoints::new_bill_summaries(
+                118,
+                BillType::Hr,
+                1,
+                BillSummariesParams::default().format(FormatType::Json),
+            ),
+            "bill/118/hr/1/summaries?format=json",
+        ),
+        (
+            Endpoints::new_bill_text(
+                118,
+                BillType::Hr,
+                1,
+                BillTextParams::default().format(FormatType::Json),
+            ),
+            "bill/118/hr/1/text?format=json",
+        ),
+        (
+            Endpoints::new_bill_titles(
+                118,
+                BillType::Hr,
+                1,
+                BillTitlesParams::default().format(FormatType::Json),
+            ),
+            "bill/118/hr/1/titles?format=json",
+        ),
+    ];
+
+    for (endpoint, expected) in cases {
+        assert_eq!(endpoint.to_string(), expected);
+    }
+}
+
+#[test]
+fn test_treaty_suffix_normalization() {
+    assert_eq!(TreatySuffix::new("a").unwrap().to_string(), "A");
+    assert_eq!("B".parse::<TreatySuffix>().unwrap().as_char(), 'B');
+    assert!(TreatySuffix::new("AB").is_err());
+    assert!(TreatySuffix::new("1").is_err());
+    assert!(TreatySuffix::new("").is_err());
+
+    let endpoint = Endpoints::new_treaty_partitioned(
+        114,
+        13,
+        TreatySuffix::new("a").unwrap(),
+        TreatyPartitionedParams::default().format(FormatType::Json),
+    );
+    assert!(endpoint.to_string().starts_with("treaty/114/13/A"));
+
+    let endpoint = Endpoints::new_treaty_actions_by_suffix(
+        114,
+        13,
+        TreatySuffix::new("b").unwrap(),
+        TreatyActionsParams::default().format(FormatType::Json),
+    );
+    assert!(endpoint.to_string().starts_with("treaty/114/13/B/actions"));
+}
+
+#[test]
+fn test_invalid_treaty_suffix_is_refused() {
+    fn partitioned(suffix: &str) -> Result<Endpoints, ApiClientError> {
+        Ok(Endpoints::treaty(114, 13).partitioned(suffix.parse()?, Default::default()))
+    }
+
+    assert!(partitioned("a").is_ok());
+    for suffix in ["AB", "1", "", "A/actions"] {
+        assert!(
+            matches!(partitioned(suffix), Err(ApiClientError::InvalidParam(_))),
+            "{:?}",
+            suffix
+        );
+    }
+}
+
+#[test]
+fn test_committee_print_paths_are_distinct() {
+    let by_chamber = Endpoints::new_committee_print_by_congress_chamber(
+        117,
+        ChamberType::House,
+        CommitteePrintByCongressChamberParams::default().format(FormatType::Json),
+    );
+    let details = Endpoints::new_committee_print_details(
+        117,
+        ChamberType::House,
+        48144,
+        CommitteePrintByJacketNumberParams::default().format(FormatType::Json),
+    );
+    let text = Endpoints::new_committee_print_text(
+        117,
+        "house".to_string(),
+        48144,
+        CommitteePrintDetailsParams::default().format(FormatType::Json),
+    );
+
+    assert_eq!(
+        by_chamber.to_string(),
+        "committee-print/117/house?format=json"
+    );
+    assert_eq!(
+        details.to_string(),
+        "committee-print/117/house/48144?format=json"
+    );
+    assert_eq!(
+        text.to_string(),
+        "committee-print/117/house/48144/text?format=json"
+    );
+    assert!(!details.is_list());
+
+    // The deprecated jacket-number variant renders a chamber listing path, so it is not
+    // treated as a detail endpoint.
+    #[allow(deprecated)]
+    let by_jacket = Endpoints::new_committee_print_by_jacket_number(
+        117,
+        48144,
+        CommitteePrintByJacketNumberParams::default().format(FormatType::Json),
+    );
+    assert_eq!(
+        by_jacket.to_string(),
+        "committee-print/117/48144?format=json"
+    );
+    assert!(by_jacket.is_list());
+}
+
+#[test]
+fn test_current_congress_placeholder() {
+    let mut endpoint = Endpoints::new_law_by_congress(
+        Congress::Current,
+        LawParams::default().format(FormatType::Json),
+    );
+    assert_eq!(endpoint.congress(), Some(Congress::Current));
+    let congress = endpoint
+        .congress_mut()
+        .expect("law endpoints have a congress");
+    *congress = Congress::Number(118);
+    assert!(endpoint.to_string().starts_with("law/118"));
+
+    let mut endpoint = Endpoints::new_member_list(MemberListParams::default());
+    assert!(endpoint.congress_mut().is_none());
+}
+
+#[test]
+fn test_current_congress_is_not_rendered_unresolved() {
+    let endpoint = Endpoints::new_law_by_congress(Congress::Current, LawParams::default());
+    assert!(matches!(
+        endpoint.cache_key(),
+        Err(ApiClientError::InvalidParam(_))
+    ));
+    assert!(matches!(
+        generate_url(endpoint, "TEST_KEY"),
+        Err(ApiClientError::InvalidParam(_))
+    ));
+}
+
+#[test]
+fn test_congress_conversions() {
+    assert_eq!(Congress::from(118), Congress::Number(118));
+    assert_eq!(Congress::from(u32::MAX), Congress::Number(u32::MAX));
+    assert_eq!(Congress::Number(118).number(), Some(118));
+    assert_eq!(Congress::Current.number(), None);
+    assert_eq!("118".parse(), Ok(Congress::Number(118)));
+
+    // Serde uses the same form as `Display` and `FromStr`.
+    let json = serde_json::to_string(&[Congress::Number(118), Congress::Current]).unwrap();
+    assert_eq!(json, r#"[118,"current"]"#);
+    let congresses: Vec<Congress> = serde_json::from_str(&json).unwrap();
+    assert_eq!(congresses, [Congress::Number(118), Congress::Current]);
+    assert!(serde_json::from_str::<Congress>("null").is_err());
+    assert!(serde_json::from_str::<Congress>(r#""next""#).is_err());
+}
+
+#[test]
+fn test_house_requirement_matching_communications_path() {
+    let endpoint = Endpoints::new_house_requirement_matching(
+        8070,
+        RequirementParams::default().format(FormatType::Json),
+    );
+
+    assert_eq!(
+        endpoint.to_string(),
+        "house-requirement/8070/matching-communications?format=json"
+    );
+}
+
+#[test]
+fn test_bill_type_tokens() {
+    let expected = [
+        "hr", "s", "hjres", "sjres", "hconres", "sconres", "hres", "sres",
+    ];
+
+    let tokens: Vec<String> = BillType::all().iter().map(|t| t.to_string()).collect();
+    assert_eq!(tokens, expected);
+
+    for bill_type in BillType::all() {
+        let token = bill_type.to_string();
+        let parsed = BillType::from_str(&token).expect("token must parse back");
+        assert_eq!(parsed.to_string(), token);
+    }
+}
+
+#[test]
+fn test_summaries_by_type_date_filters() {
+    let endpoint = Endpoints::new_summaries_by_type(
+        118,
+        BillType::Hr,
+        SummariesByTypeParams::default()
+            .format(FormatType::Json)
+            .limit(20)
+            .from_date_time("2024-01-01T00:00:00Z".to_string())
+            .to_date_time("2024-02-01T00:00:00Z".to_string())
+            .sort(SortType::UpdateDateDesc),
+    );
+
+    assert!(endpoint.to_string().ends_with(
+        "/118/hr?format=json&limit=20&fromDateTime=2024-01-01T00:00:00Z\
+         &toDateTime=2024-02-01T00:00:00Z&sort=updateDate+desc"
+    ));
+}
+
+#[test]
+fn test_member_by_congress_state_district_fluent() {
+    let endpoint = Endpoints::member().by_congress_state_district(
+        118,
+        "ca",
+        12,
+        MemberByCongressStateDistrictParams::default()
+            .format(FormatType::Json)
+            .current_member(true),
+    );
+    assert_eq!(
+        endpoint.to_string(),
+        "member/congress/118/CA/12?format=json&currentMember=true"
+    );
+
+    // The fluent builder and the constructors normalize state codes the same way.
+    let by_state =
+        Endpoints::new_member_by_state(" ny ".to_string(), MemberByStateParams::default());
+    assert!(by_state.to_string().starts_with("member/NY"));
+    let by_district = Endpoints::member().by_state_district(
+        "tx",
+        7,
+        MemberByStateDistrictParams::default().format(FormatType::Json),
+    );
+    assert_eq!(by_district.to_string(), "member/TX/7?format=json");
+}
+
+#[test]
+fn test_committee_congress_and_chamber_paths() {
+    let by_congress = Endpoints::new_committee_by_congress(
+        118,
+        CommitteeByCongressParams::default().format(FormatType::Json),
+    );
+    let details = Endpoints::new_committee_details(
+        ChamberType::House,
+        "hsag00".to_string(),
+        CommitteeDetailsParams::default().format(FormatType::Json),
+    );
+    let list =
+        Endpoints::new_committee_list(CommitteeListParams::default().format(FormatType::Json));
+
+    assert_eq!(by_congress.to_string(), "committee/118?format=json");
+    assert_eq!(details.to_string(), "committee/house/hsag00?format=json");
+    assert_eq!(list.to_string(), "committee?format=json");
+
+    // Details parameters carry only a format, and must still start the query string.
+    let treaty = Endpoints::new_treaty_details(
+        114,
+        13,
+        TreatyDetailsParams::default().format(FormatType::Json),
+    );
+    assert_eq!(treaty.to_string(), "treaty/114/13?format=json");
+}
+
+#[test]
+fn test_generic_params_default_to_json() {
+    let endpoint = Endpoints::new_generic(
+        "daily-congressional-record".to_string(),
+        GenericParams::default(),
+    );
+    assert_eq!(
+        endpoint.to_string(),
+        "daily-congressional-record?format=json"
+    );
+
+    let endpoint = Endpoints::new_generic(
+        "bound-congressional-record".to_string(),
+        GenericParams::default().format(FormatType::Xml).limit(5),
+    );
+    assert!(endpoint.to_string().ends_with("?format=xml&limit=5"));
+}
+
+/// Asserts that `endpoint` renders to `expected` and is requested with `GET`, naming the
+/// variant on failure.
+fn assert_url(endpoint: Endpoints, expected: &str) {
+    assert_eq!(endpoint.to_string(), expected, "for {:?}", endpoint);
+    assert_eq!(endpoint.method(), Method::GET, "for {:?}", endpoint);
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_all_endpoint_urls() {
+    let json = FormatType::Json;
+
+    // Bills
+    assert_url(
+        Endpoints::new_bill_list(BillListParams::default().format(json)),
+        "bill?format=json",
+    );
+    assert_url(
+        Endpoints::new_bill_by_congress(118, BillByCongressParams::default().format(json)),
+        "bill/118?format=json",
+    );
+    assert_url(
+        Endpoints::new_bill_by_type(118, BillType::Hr, BillByTypeParams::default().format(json)),
+        "bill/118/hr?format=json",
+    );
+    assert_url(
+        Endpoints::new_bill_details(
+            118,
+            BillType::Hr,
+            1,
+            BillDetailsParams::default().format(json),
+        ),
+        "bill/118/hr/1?format=json",
+    );
+    assert_url(
+        Endpoints::new_bill_amendments(
+            118,
+            BillType::Hr,
+            1,
+            BillAmendmentsParams::default().format(json),
+        ),
+        "bill/118/hr/1/amendments?format=json",
+    );
+
+    // Laws
+    assert_url(
+        Endpoints::new_law_type(118, LawType::Pub, LawParams::default().format(json)),
+        "law/118/pub?format=json",
+    );
+    assert_url(
+        Endpoints::new_law_by_congress(118, LawParams::default().format(json)),
+        "law/118?format=json",
+    );
+    assert_url(
+        Endpoints::new_law_details(118, LawType::Pub, 108, LawParams::default().format(json)),
+        "law/118/pub/108?format=json",
+    );
+
+    // Amendments
+    assert_url(
+        Endpoints::new_amendment_list(AmendmentListParams::default().format(json)),
+        "amendment?format=json",
+    );
+    assert_url(
+        Endpoints::new_amendment_by_congress(
+            118,
+            AmendmentByCongressParams::default().format(json),
+        ),
+        "amendment/118?format=json",
+    );
+    assert_url(
+        Endpoints::new_amendment_by_type(
+            118,
+            AmendmentType::Samdt,
+            AmendmentByTypeParams::default().format(json),
+        ),
+        "amendment/118/samdt?format=json",
+    );
+    assert_url(
+        Endpoints::new_amendment_details(
+            118,
+            AmendmentType::Samdt,
+            2137,
+            AmendmentDetailsParams::default().format(json),
+        ),
+        "amendment/118/samdt/2137?format=json",
+    );
+    assert_url(
+        Endpoints::new_amendment_actions(
+            118,
+            AmendmentType::Samdt,
+            "2137".to_string(),
+            AmendmentActionsParams::default().format(json),
+        ),
+        "amendment/118/samdt/2137/actions?format=json",
+    );
+    assert_url(
+        Endpoints::new_amendment_cosponsors(
+            118,
+            AmendmentType::Samdt,
+            "2137".to_string(),
+            AmendmentCosponsorsParams::default().format(json),
+        ),
+        "amendment/118/samdt/2137/cosponsors?format=json",
+    );
+    assert_url(
+        Endpoints::new_amendment_amendments(
+            118,
+            AmendmentType::Samdt,
+            "2137".to_string(),
+            AmendmentAmendmentsParams::default().format(json),
+        ),
+        "amendment/118/samdt/2137/amendments?format=json",
+    );
+    assert_url(
+        Endpoints::new_amendment_text(
+            118,
+            AmendmentType::Samdt,
+            "2137".to_string(),
+            AmendmentTextParams::default().format(json),
+        ),
+        "amendment/118/samdt/2137/text?format=json",
+    );
+
+    // Summaries
+    assert_url(
+        Endpoints::new_summaries_list(SummariesListParams::default().format(json)),
+        "summaries?format=json",
+    );
+    assert_url(
+        Endpoints::new_summaries_by_congress(
+            118,
+            SummariesByCongressParams::default().format(json),
+        ),
+        "summaries/118?format=json",
+    );
+    assert_url(
+        Endpoints::new_summaries_by_type(
+            118,
+            BillType::Hr,
+            SummariesByTypeParams::default().format(json),
+        ),
+        "summaries/118/hr?format=json",
+    );
+
+    // Congresses
+    assert_url(
+        Endpoints::new_congress_list(CongressListParams::default().format(json)),
+        "congress?format=json",
+    );
+    assert_url(
+        Endpoints::new_congress_details(118, CongressDetailsParams::default().format(json)),
+        "congress/118?format=json",
+    );
+    assert_url(
+        Endpoints::new_congress_current(CongressCurrentParams::default().format(json)),
+        "congress/current?format=json",
+    );
+
+    // Members
+    assert_url(
+        Endpoints::new_member_list(MemberListParams::default().format(json)),
+        "member?format=json",
+    );
+    assert_url(
+        Endpoints::new_member_by_congress(118, MemberByCongressParams::default().format(json)),
+        "member/congress/118?format=json",
+    );
+    assert_url(
+        Endpoints::new_member_by_state(
+            "MI".to_string(),
+            MemberByStateParams::default().format(json),
+        ),
+        "member/MI?format=json",
+    );
+    assert_url(
+        Endpoints::new_member_by_state_district(
+            "MI".to_string(),
+            10,
+            MemberByStateDistrictParams::default().format(json),
+        ),
+        "member/MI/10?format=json",
+    );
+    assert_url(
+        Endpoints::new_member_details(
+            "L000174".to_string(),
+            MemberDetailsParams::default().format(json),
+        ),
+        "member/L000174?format=json",
+    );
+    assert_url(
+        Endpoints::new_sponsorship_list(
+            "L000174".to_string(),
+            SponsorshipListParams::default().format(json),
+        ),
+        "member/L000174/sponsored-legislation?format=json",
+    );
+    assert_url(
+        Endpoints::new_cosponsorship_list(
+            "L000174".to_string(),
+            CosponsorshipListParams::default().format(json),
+        ),
+        "member/L000174/cosponsored-legislation?format=json",
+    );
+
+    // Committees
+    assert_url(
+        Endpoints::new_committee_by_chamber(
+            ChamberType::House,
+            CommitteeByChamberParams::default().format(json),
+        ),
+        "committee/house?format=json",
+    );
+    assert_url(
+        Endpoints::new_committee_by_congress_chamber(
+            118,
+            ChamberType::House,
+            CommitteeByCongressChamberParams::default().format(json),
+        ),
+        "committee/118/house?format=json",
+    );
+    assert_url(
+        Endpoints::new_committee_bills(
+            ChamberType::House,
+            "hspw00".to_string(),
+            CommitteeBillsParams::default().format(json),
+        ),
+        "committee/house/hspw00/bills?format=json",
+    );
+    assert_url(
+        Endpoints::new_committee_reports(
+            ChamberType::House,
+            "hspw00".to_string(),
+            CommitteeReportsParams::default().format(json),
+        ),
+        "committee/house/hspw00/reports?format=json",
+    );
+    assert_url(
+        Endpoints::new_committee_nominations(
+            ChamberType::Senate,
+            "ssju00".to_string(),
+            CommitteeNominationsParams::default().format(json),
+        ),
+        "committee/senate/ssju00/nominations?format=json",
+    );
+    assert_url(
+        Endpoints::new_committee_house_communication(
+            ChamberType::House,
+            "hspw00".to_string(),
+            CommitteeHouseCommunicationParams::default().format(json),
+        ),
+        "committee/house/hspw00/house-communication?format=json",
+    );
+    assert_url(
+        Endpoints::new_committee_senate_communication(
+            ChamberType::Senate,
+            "ssas00".to_string(),
+            CommitteeSenateCommunicationParams::default().format(json),
+        ),
+        "committee/senate/ssas00/senate-communication?format=json",
+    );
+
+    // Nominations
+    assert_url(
+        Endpoints::new_nomination_list(NominationListParams::default().format(json)),
+        "nomination?format=json",
+    );
+    assert_url(
+        Endpoints::new_nomination_by_congress(
+            118,
+            NominationByCongressParams::default().format(json),
+        ),
+        "nomination/118?format=json",
+    );
+    assert_url(
+        Endpoints::new_nomination_details(
+            118,
+            "2005".to_string(),
+            NominationDetailsParams::default().format(json),
+        ),
+        "nomination/118/2005?format=json",
+    );
+    assert_url(
+        Endpoints::new_nominees(
+            118,
+            "2005".to_string(),
+            1,
+            NomineesParams::default().format(json),
+        ),
+        "nomination/118/2005/1?format=json",
+    );
+    assert_url(
+        Endpoints::new_nomination_actions(
+            118,
+            "2005".to_string(),
+            NominationActionsParams::default().format(json),
+        ),
+        "nomination/118/2005/actions?format=json",
+    );
+    assert_url(
+        Endpoints::new_nomination_committees(
+            118,
+            "2005".to_string(),
+            NominationCommitteesParams::default().format(json),
+        ),
+        "nomination/118/2005/committees?format=json",
+    );
+    assert_url(
+        Endpoints::new_nomination_hearings(
+            118,
+            "2005".to_string(),
+            NominationHearingsParams::default().format(json),
+        ),
+        "nomination/118/2005/hearings?format=json",
+    );
+
+    // Treaties
+    assert_url(
+        Endpoints::new_treaty_list(TreatyListParams::default().format(json).limit(10)),
+        "treaty?format=json&limit=10",
+    );
+    assert_url(
+        Endpoints::new_treaty_by_congress(114, TreatyByCongressParams::default().format(json)),
+        "treaty/114?format=json",
+    );
+    assert_url(
+        Endpoints::new_treaty_committees(114, 13, TreatyCommitteesParams::default().format(json)),
+        "treaty/114/13/committees?format=json",
+    );
+    assert_url(
+        Endpoints::new_treaty_actions(114, 13, TreatyActionsParams::default().format(json)),
+        "treaty/114/13/actions?format=json",
+    );
+
+    // Committee reports
+    assert_url(
+        Endpoints::new_committee_report_list(CommitteeReportListParams::default().format(json)),
+        "committee-report?format=json",
+    );
+    assert_url(
+        Endpoints::new_committee_report_by_congress(
+            116,
+            CommitteeReportByCongressParams::default().format(json),
+        ),
+        "committee-report/116?format=json",
+    );
+    assert_url(
+        Endpoints::new_committee_report_by_type(
+            116,
+            CommitteeReportType::Hrpt,
+            CommitteeReportByTypeParams::default().format(json),
+        ),
+        "committee-report/116/hrpt?format=json",
+    );
+    assert_url(
+        Endpoints::new_committee_report_details(
+            116,
+            CommitteeReportType::Hrpt,
+            617,
+            CommitteeReportDetailsParams::default().format(json),
+        ),
+        "committee-report/116/hrpt/617?format=json",
+    );
+    assert_url(
+        Endpoints::new_committee_report_text(
+            116,
+            CommitteeReportType::Hrpt,
+            617,
+            CommitteeReportTextParams::default().format(json),
+        ),
+        "committee-report/116/hrpt/617/text?format=json",
+    );
+
+    // Committee prints
+    assert_url(
+        Endpoints::new_committee_print_list(CommitteePrintListParams::default().format(json)),
+        "committee-print?format=json",
+    );
+    assert_url(
+        Endpoints::new_committee_print_by_congress(
+            117,
+            CommitteePrintByCongressParams::default().format(json),
+        ),
+        "committee-print/117?format=json",
+    );
+    assert_url(
+        Endpoints::new_committee_print_by_jacket_number(
+            117,
+            48144,
+            CommitteePrintByJacketNumberParams::default().format(json),
+        ),
+        "committee-print/117/48144?format=json",
+    );
+
+    // Committee meetings
+    assert_url(
+        Endpoints::new_committee_meeting_list(CommitteeMeetingListParams::default().format(json)),
+        "committee-meeting?format=json",
+    );
+    assert_url(
+        Endpoints::new_committee_meeting_by_congress(
+            118,
+            CommitteeMeetingByCongressParams::default().format(json),
+        ),
+        "committee-meeting/118?format=json",
+    );
+    assert_url(
+        Endpoints::new_committee_meeting_by_chamber(
+            118,
+            ChamberType::House,
+            CommitteeMeetingByChamberParams::default().format(json),
+        ),
+        "committee-meeting/118/house?format=json",
+    );
+    assert_url(
+        Endpoints::new_committee_meeting_by_event(
+            118,
+            ChamberType::House,
+            "115538".to_string(),
+            CommitteeMeetingByEventParams::default().format(json),
+        ),
+        "committee-meeting/118/house/115538?format=json",
+    );
+
+    // Hearings
+    assert_url(
+        Endpoints::new_hearing_list(HearingListParams::default().format(json)),
+        "hearing?format=json",
+    );
+    assert_url(
+        Endpoints::new_hearing_by_congress(116, HearingByCongressParams::default().format(json)),
+        "hearing/116?format=json",
+    );
+    assert_url(
+        Endpoints::new_hearing_by_chamber(
+            116,
+            ChamberType::House,
+            HearingByChamberParams::default().format(json),
+        ),
+        "hearing/116/house?format=json",
+    );
+    assert_url(
+        Endpoints::new_hearing_by_jacket_number(
+            116,
+            ChamberType::House,
+            41365,
+            HearingByJacketNumberParams::default().format(json),
+        ),
+        "hearing/116/house/41365?format=json",
+    );
+
+    // Congressional records
+    assert_url(
+        Endpoints::new_congressional_record_list(
+            CongressionalRecordListParams::default().format(json),
+        ),
+        "congressional-record?format=json",
+    );
+    assert_url(
+        Endpoints::new_daily_congressional_record_list(
+            DailyCongressionalRecordListParams::default().format(json),
+        ),
+        "daily-congressional-record?format=json",
+    );
+    assert_url(
+        Endpoints::new_daily_congressional_record_volume(
+            166,
+            DailyCongressionalVolumeNumberParams::default().format(json),
+        ),
+        "daily-congressional-record/166?format=json",
+    );
+    assert_url(
+        Endpoints::new_daily_congressional_record_volume_issue(
+            166,
+            153,
+            DailyCongressionalVolumeNumberIssueNumberParams::default().format(json),
+        ),
+        "daily-congressional-record/166/153?format=json",
+    );
+    assert_url(
+        Endpoints::new_daily_congressional_record_articles(
+            166,
+            153,
+            DailyCongressionalVolumeNumberIssueNumberParams::default().format(json),
+        ),
+        "daily-congressional-record/166/153/articles?format=json",
+    );
+    assert_url(
+        Endpoints::new_bound_congressional_record_list(
+            BoundCongressionalRecordParams::default().format(json),
+        ),
+        "bound-congressional-record?format=json",
+    );
+    assert_url(
+        Endpoints::new_bound_congressional_record_by_year(
+            1990,
+            BoundCongressionalRecordParams::default().format(json),
+        ),
+        "bound-congressional-record/1990?format=json",
+    );
+    assert_url(
+        Endpoints::new_bound_congressional_record_by_year_month(
+            1990,
+            5,
+            BoundCongressionalRecordParams::default().format(json),
+        ),
+        "bound-congressional-record/1990/5?format=json",
+    );
+    assert_url(
+        Endpoints::new_bound_congressional_record_by_year_month_day(
+            1990,
+            5,
+            18,
+            BoundCongressionalRecordParams::default().format(json),
+        ),
+        "bound-congressional-record/1990/5/18?format=json",
+    );
+
+    // Communications
+    assert_url(
+        Endpoints::new_house_communication_list(CommunicationParams::default().format(json)),
+        "house-communication?format=json",
+    );
+    assert_url(
+        Endpoints::new_house_communication_by_congress(
+            117,
+            CommunicationParams::default().format(json),
+        ),
+        "house-communication/117?format=json",
+    );
+    assert_url(
+        Endpoints::new_house_communication_by_type(
+            117,
+            CommunicationType::Ec,
+            CommunicationParams::default().format(json),
+        ),
+        "house-communication/117/ec?format=json",
+    );
+    assert_url(
+        Endpoints::new_house_communication_details(
+            117,
+            CommunicationType::Ec,
+            3324,
+            CommunicationDetailsParams::default().format(json),
+        ),
+        "house-communication/117/ec/3324?format=json",
+    );
+    assert_url(
+        Endpoints::new_senate_communication_list(CommunicationParams::default().format(json)),
+        "senate-communication?format=json",
+    );
+    assert_url(
+        Endpoints::new_senate_communication_by_congress(
+            117,
+            CommunicationParams::default().format(json),
+        ),
+        "senate-communication/117?format=json",
+    );
+    assert_url(
+        Endpoints::new_senate_communication_by_type(
+            117,
+            CommunicationType::Ec,
+            CommunicationParams::default().format(json),
+        ),
+        "senate-communication/117/ec?format=json",
+    );
+    assert_url(
+        Endpoints::new_senate_communication_details(
+            117,
+            CommunicationType::Ec,
+            2561,
+            CommunicationDetailsParams::default().format(json),
+        ),
+        "senate-communication/117/ec/2561?format=json",
+    );
+
+    // House requirements
+    assert_url(
+        Endpoints::new_house_requirement_list(RequirementParams::default().format(json)),
+        "house-requirement?format=json",
+    );
+    assert_url(
+        Endpoints::new_house_requirement_details(
+            8070,
+            RequirementDetailsParams::default().format(json),
+        ),
+        "house-requirement/8070?format=json",
+    );
+}
+
+#[test]
+fn test_list_endpoints_have_no_trailing_slash() {
+    let json = FormatType::Json;
+    let cases = vec![
+        (
+            Endpoints::new_bill_list(BillListParams::default().format(json)),
+            "bill?format=json",
+        ),
+        (
+            Endpoints::new_amendment_list(AmendmentListParams::default().format(json)),
+            "amendment?format=json",
+        ),
+        (
+            Endpoints::new_member_list(MemberListParams::default().format(json)),
+            "member?format=json",
+        ),
+        (
+            Endpoints::new_committee_list(CommitteeListParams::default().format(json)),
+            "committee?format=json",
+        ),
+        (
+            Endpoints::new_nomination_list(NominationListParams::default().format(json)),
+            "nomination?format=json",
+        ),
+        (
+            Endpoints::new_generic(
+                "/daily-congressional-record/".to_string(),
+                GenericParams::default(),
+            ),
+            "daily-congressional-record?format=json",
+        ),
+    ];
+
+    for (endpoint, expected) in cases {
+        assert_url(endpoint, expected);
+    }
+}
+
+#[test]
+fn test_from_api_url_round_trip() {
+    let urls = [
+        "bill/118/hr/3076?format=json",
+        "amendment/117/samdt/2137?format=json",
+        "member/P000197?format=json",
+        "congress/118?format=json",
+        "committee/house/hsag00?format=json",
+        "nomination/118/2183?format=json",
+        "treaty/117/3?format=json",
+        "bill/118/hr/3076/actions?format=json&offset=0&limit=250",
+    ];
+
+    for path in urls {
+        let url = format!("https://api.congress.gov/v3/{}", path);
+        let (endpoint, api_key) = Endpoints::from_api_url(&url).expect("Failed to parse URL");
+        assert_eq!(endpoint.cache_key().unwrap(), url);
+        assert_eq!(api_key, None);
+    }
+}
+
+#[test]
+fn test_from_api_url_typed_variants_and_key() {
+    let (endpoint, api_key) = Endpoints::from_api_url(
+        "https://api.congress.gov/v3/bill/118/hr/3076?format=json&api_key=SECRET",
+    )
+    .expect("Failed to parse URL");
+
+    assert!(matches!(
+        endpoint,
+        Endpoints::BillDetails(Congress::Number(118), BillType::Hr, 3076, _)
+    ));
+    assert_eq!(api_key.as_deref(), Some("SECRET"));
+    assert!(!endpoint.cache_key().unwrap().contains("SECRET"));
+
+    let (endpoint, _) = Endpoints::from_api_url("https://api.congress.gov/v3/bill/118/hr")
+        .expect("Failed to parse URL");
+    assert!(matches!(endpoint, Endpoints::Generic(ref path, _) if path == "bill/118/hr"));
+
+    assert!(Endpoints::from_api_url("https://www.congress.gov/bill/118th-congress").is_err());
+    assert!(
+        Endpoints::from_api_url("https://api.congress.gov/v3/bill?format=json&bogus=1").is_err()
+    );
+}
+
+#[test]
+fn test_treaty_list_query_separators() {
+    let params = TreatyListParams::default()
+        .format(FormatType::Json)
+        .offset(20)
+        .limit(10)
+        .from_date_time("2023-01-01T00:00:00Z".to_string())
+        .to_date_time("2023-12-31T00:00:00Z".to_string())
+        .sort(SortType::UpdateDateDesc);
+
+    assert_eq!(
+        Endpoints::new_treaty_list(params).to_string(),
+        "treaty?format=json&offset=20&limit=10&fromDateTime=2023-01-01T00:00:00Z\
+         &toDateTime=2023-12-31T00:00:00Z&sort=updateDate+desc"
+    );
+}
+
+#[test]
+fn test_summaries_paths_single_query_separator() {
+    let cases = [
+        (
+            Endpoints::new_summaries_list(
+                SummariesListParams::default()
+                    .format(FormatType::Json)
+                    .limit(5),
+            ),
+            "summaries?format=json&limit=5",
+        ),
+        (
+            Endpoints::new_summaries_by_congress(
+                118,
+                SummariesByCongressParams::default()
+                    .format(FormatType::Json)
+                    .limit(5),
+            ),
+            "summaries/118?format=json&limit=5",
+        ),
+        (
+            Endpoints::new_summaries_by_type(
+                118,
+                BillType::Hr,
+                SummariesByTypeParams::default()
+                    .format(FormatType::Json)
+                    .limit(5),
+            ),
+            "summaries/118/hr?format=json&limit=5",
+        ),
+    ];
+
+    for (endpoint, expected) in cases {
+        assert_url(endpoint.clone(), expected);
+        assert_eq!(
+            generate_url(endpoint, "KEY").unwrap().matches('?').count(),
+            1
+        );
+    }
+}
+
+#[test]
+fn test_bill_summaries_path_is_plural() {
+    let endpoint = Endpoints::new_bill_summaries(
+        118,
+        BillType::Hr,
+        3076,
+        BillSummariesParams::default().format(FormatType::Json),
+    );
+
+    assert_eq!(
+        endpoint.cache_key().unwrap(),
+        "https://api.congress.gov/v3/bill/118/hr/3076/summaries?format=json"
+    );
+}
+
+#[test]
+fn test_params_validate_conflicts() {
+    let record = |year, month, day| {
+        Endpoints::new_congressional_record_list(CongressionalRecordListParams {
+            year,
+            month,
+            day,
+            ..CongressionalRecordListParams::default()
+        })
+        .validate()
+    };
+    assert!(record(Some(2024), Some(2), Some(29)).is_ok());
+    assert!(record(None, Some(2), None).is_err());
+    assert!(record(Some(2024), None, Some(1)).is_err());
+    assert!(record(Some(2024), Some(13), None).is_err());
+
+    let generic = |params: GenericParams| {
+        Endpoints::new_generic("congressional-record".to_string(), params).validate()
+    };
+    assert!(generic(GenericParams::default().year(2024).month(2)).is_ok());
+    assert!(generic(
+        GenericParams::default()
+            .year(2024)
+            .from_date_time("2024-01-01T00:00:00Z".to_string())
+    )
+    .is_err());
+    assert!(generic(
+        GenericParams::default()
+            .from_date_time("2024-02-01T00:00:00Z".to_string())
+            .to_date_time("2024-01-01T00:00:00Z".to_string())
+    )
+    .is_err());
+}
+
+#[test]
+fn test_small_types_are_copy() {
+    fn assert_copy<T: Copy>() {}
+
+    assert_copy::<FormatType>();
+    assert_copy::<SortType>();
+    assert_copy::<BillType>();
+    assert_copy::<AmendmentType>();
+    assert_copy::<ChamberType>();
+    assert_copy::<CommunicationType>();
+    assert_copy::<LawType>();
+    assert_copy::<CommitteeReportType>();
+    assert_copy::<TreatySuffix>();
+}
+
+#[test]
+fn test_treaty_and_nomination_fluent() {
+    let json = FormatType::Json;
+    let treaty = Endpoints::treaty(114, 13);
+    let nomination = Endpoints::nomination(118, "2");
+
+    assert_url(
+        treaty.details(TreatyDetailsParams::default().format(json)),
+        "treaty/114/13?format=json",
+    );
+    assert_url(
+        treaty.actions(TreatyActionsParams::default().format(json)),
+        "treaty/114/13/actions?format=json",
+    );
+    assert_url(
+        treaty.committees(TreatyCommitteesParams::default().format(json)),
+        "treaty/114/13/committees?format=json",
+    );
+    assert_url(
+        treaty.partitioned(
+            TreatySuffix::new("a").unwrap(),
+            TreatyPartitionedParams::default().format(json),
+        ),
+        "treaty/114/13/A?format=json",
+    );
+    assert_url(
+        treaty.actions_by_suffix(
+            TreatySuffix::new("b").unwrap(),
+            TreatyActionsParams::default().format(json),
+        ),
+        "treaty/114/13/B/actions?format=json",
+    );
+
+    assert_url(
+        nomination
+            .clone()
+            .details(NominationDetailsParams::default().format(json)),
+        "nomination/118/2?format=json",
+    );
+    assert_url(
+        nomination
+            .clone()
+            .actions(NominationActionsParams::default().format(json)),
+        "nomination/118/2/actions?format=json",
+    );
+    assert_url(
+        nomination
+            .clone()
+            .committees(NominationCommitteesParams::default().format(json)),
+        "nomination/118/2/committees?format=json",
+    );
+    assert_url(
+        nomination
+            .clone()
+            .hearings(NominationHearingsParams::default().format(json)),
+        "nomination/118/2/hearings?format=json",
+    );
+    assert_url(
+        nomination.nominees(1, NomineesParams::default().format(json)),
+        "nomination/118/2/1?format=json",
+    );
+}
+
+#[test]
+fn test_committee_report_list_conference_only() {
+    assert_url(
+        Endpoints::new_committee_report_list(
+            CommitteeReportListParams::conference_only()
+                .format(FormatType::Json)
+                .limit(10),
+        ),
+        "committee-report?format=json&conference=true&limit=10",
+    );
+}
+
+#[test]
+fn test_api_key_appended_exactly_once() {
+    let endpoints = vec![
+        // List, with and without query parameters.
+        Endpoints::new_bill_list(BillListParams::default()),
+        Endpoints::new_bill_list(
+            BillListParams::default()
+                .format(FormatType::Json)
+                .limit(10)
+                .sort(SortType::UpdateDateDesc),
+        ),
+        // By congress and by type.
+        Endpoints::new_bill_by_congress(118, BillByCongressParams::default().offset(20)),
+        Endpoints::new_law_type(118, LawType::Pub, LawParams::default()),
+        // Detail.
+        Endpoints::new_bill_details(118, BillType::Hr, 1, BillDetailsParams::default()),
+        Endpoints::new_member_details(
+            "A000360".to_string(),
+            MemberDetailsParams::default().format(FormatType::Json),
+        ),
+        Endpoints::new_treaty_partitioned(
+            114,
+            13,
+            TreatySuffix::new("a").unwrap(),
+            TreatyPartitionedParams::default(),
+        ),
+        // Sub-resource.
+        Endpoints::new_bill_actions(
+            118,
+            BillType::Hr,
+            1,
+            BillActionsParams::default()
+                .format(FormatType::Json)
+                .limit(5),
+        ),
+        Endpoints::new_committee_bills(
+            ChamberType::House,
+            "hsag00".to_string(),
+            CommitteeBillsParams::default(),
+        ),
+        // Generic.
+        Endpoints::new_generic("bill/118/hr/1/text".to_string(), GenericParams::default()),
+    ];
+
+    for endpoint in endpoints {
+        let url = generate_url(endpoint, "KEY").unwrap();
+
+        assert_eq!(url.matches("api_key=").count(), 1, "{}", url);
+        assert_eq!(url.matches('?').count(), 1, "{}", url);
+        assert!(url.ends_with("api_key=KEY"), "{}", url);
+        let separator = url[..url.len() - "api_key=KEY".len()].chars().last();
+        assert!(matches!(separator, Some('?' | '&')), "{}", url);
+        assert!(!url.contains("&&") && !url.contains("?&"), "{}", url);
+    }
+}
+
+/// Returns the resource every path of the endpoint's variant starts with.
+///
+/// The match is exhaustive on purpose: a new variant fails to compile here until its
+/// prefix is listed, and must then be added to `test_every_variant_renders_its_prefix`.
+#[allow(deprecated)]
+fn resource_prefix(endpoint: &Endpoints) -> &str {
+    match endpoint {
+        Endpoints::Generic(path, _) => path,
+        Endpoints::BillList(..)
+        | Endpoints::BillByCongress(..)
+        | Endpoints::BillByType(..)
+        | Endpoints::BillDetails(..)
+        | Endpoints::BillActions(..)
+        | Endpoints::BillAmendments(..)
+        | Endpoints::BillCommittees(..)
+        | Endpoints::BillCosponsors(..)
+        | Endpoints::BillRelated(..)
+        | Endpoints::BillSubjects(..)
+        | Endpoints::BillSummaries(..)
+        | Endpoints::BillText(..)
+        | Endpoints::BillTitles(..) => "bill",
+        Endpoints::LawByType(..) | Endpoints::LawByCongress(..) | Endpoints::LawDetails(..) => {
+            "law"
+        }
+        Endpoints::AmendmentList(..)
+        | Endpoints::AmendmentByCongress(..)
+        | Endpoints::AmendmentByType(..)
+        | Endpoints::AmendmentDetails(..)
+        | Endpoints::AmendmentActions(..)
+        | Endpoints::AmendmentCosponsors(..)
+        | Endpoints::AmendmentAmendments(..)
+        | Endpoints::AmendmentText(..) => "amendment",
+        Endpoints::SummariesList(..)
+        | Endpoints::SummariesByCongress(..)
+        | Endpoints::SummariesByType(..) => "summaries",
+        Endpoints::CongressList(..)
+        | Endpoints::CongressDetails(..)
+        | Endpoints::CongressCurrent(..) => "congress",
+        Endpoints::MemberList(..)
+        | Endpoints::MemberByCongress(..)
+        | Endpoints::MemberByState(..)
+        | Endpoints::MemberByStateDistrict(..)
+        | Endpoints::MemberByCongressStateDistrict(..)
+        | Endpoints::MemberDetails(..)
+        | Endpoints::SponsorshipList(..)
+        | Endpoints::CosponsorshipList(..) => "member",
+        Endpoints::CommitteeList(..)
+        | Endpoints::CommitteeByChamber(..)
+        | Endpoints::CommitteeByCongress(..)
+        | Endpoints::CommitteeByCongressChamber(..)
+        | Endpoints::CommitteeDetails(..)
+        | Endpoints::CommitteeBills(..)
+        | Endpoints::CommitteeReports(..)
+        | Endpoints::CommitteeNominations(..)
+        | Endpoints::CommitteeHouseCommunication(..)
+        | Endpoints::CommitteeSenateCommunication(..) => "committee",
+        Endpoints::CommitteeReportList(..)
+        | Endpoints::CommitteeReportByCongress(..)
+        | Endpoints::CommitteeReportByType(..)
+        | Endpoints::CommitteeReportDetails(..)
+        | Endpoints::CommitteeReportText(..) => "committee-report",
+        Endpoints::CommitteePrintList(..)
+        | Endpoints::CommitteePrintByCongress(..)
+        | Endpoints::CommitteePrintByCongressChamber(..)
+        | Endpoints::CommitteePrintByJacketNumber(..)
+        | Endpoints::CommitteePrintDetails(..)
+        | Endpoints::CommitteePrintText(..) => "committee-print",
+        Endpoints::CommitteeMeetingList(..)
+        | Endpoints::CommitteeMeetingByCongress(..)
+        | Endpoints::CommitteeMeetingByChamber(..)
+        | Endpoints::CommitteeMeetingByEvent(..) => "committee-meeting",
+        Endpoints::HearingList(..)
+        | Endpoints::HearingByCongress(..)
+        | Endpoints::HearingByChamber(..)
+        | Endpoints::HearingByJacketNumber(..) => "hearing",
+        Endpoints::CongressionalRecordList(..) => "congressional-record",
+        Endpoints::DailyCongressionalRecordList(..)
+        | Endpoints::DailyCongressionalRecordVolume(..)
+        | Endpoints::DailyCongressionalRecordVolumeIssue(..)
+        | Endpoints::DailyCongressionalRecordArticles(..) => "daily-congressional-record",
+        Endpoints::BoundCongressionalRecordList(..)
+        | Endpoints::BoundCongressionalRecordByYear(..)
+        | Endpoints::BoundCongressionalRecordByYearMonth(..)
+        | Endpoints::BoundCongressionalRecordByYearMonthDay(..) => "bound-congressional-record",
+        Endpoints::HouseCommunicationList(..)
+        | Endpoints::HouseCommunicationByCongress(..)
+        | Endpoints::HouseCommunicationByType(..)
+        | Endpoints::HouseCommunicationDetails(..) => "house-communication",
+        Endpoints::HouseRequirementList(..)
+        | Endpoints::HouseRequirementDetails(..)
+        | Endpoints::HouseRequirementMatching(..) => "house-requirement",
+        Endpoints::SenateCommunicationList(..)
+        | Endpoints::SenateCommunicationByCongress(..)
+        | Endpoints::SenateCommunicationByType(..)
+        | Endpoints::SenateCommunicationDetails(..) => "senate-communication",
+        Endpoints::NominationList(..)
+        | Endpoints::NominationByCongress(..)
+        | Endpoints::NominationDetails(..)
+        | Endpoints::Nominees(..)
+        | Endpoints::NominationActions(..)
+        | Endpoints::NominationCommittees(..)
+        | Endpoints::NominationHearings(..) => "nomination",
+        Endpoints::TreatyList(..)
+        | Endpoints::TreatyByCongress(..)
+        | Endpoints::TreatyDetails(..)
+        | Endpoints::TreatyPartitioned(..)
+        | Endpoints::TreatyCommittees(..)
+        | Endpoints::TreatyActions(..)
+        | Endpoints::TreatyActionsBySuffix(..) => "treaty",
+    }
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_every_variant_renders_its_prefix() {
+    let endpoints = vec![
+        Endpoints::Generic("bill".to_string(), Default::default()),
+        Endpoints::BillList(Default::default()),
+        Endpoints::BillByCongress(118.into(), Default::default()),
+        Endpoints::BillByType(118.into(), BillType::Hr, Default::default()),
+        Endpoints::BillDetails(118.into(), BillType::Hr, 3076, Default::default()),
+        Endpoints::BillActions(118.into(), BillType::Hr, 3076, Default::default()),
+        Endpoints::BillAmendments(118.into(), BillType::Hr, 3076, Default::default()),
+        Endpoints::BillCommittees(118.into(), BillType::Hr, 3076, Default::default()),
+        Endpoints::BillCosponsors(118.into(), BillType::Hr, 3076, Default::default()),
+        Endpoints::BillRelated(118.into(), BillType::Hr, 3076, Default::default()),
+        Endpoints::BillSubjects(118.into(), BillType::Hr, 3076, Default::default()),
+        Endpoints::BillSummaries(118.into(), BillType::Hr, 3076, Default::default()),
+        Endpoints::BillText(118.into(), BillType::Hr, 3076, Default::default()),
+        Endpoints::BillTitles(118.into(), BillType::Hr, 3076, Default::default()),
+        Endpoints::LawByType(118.into(), LawType::Pub, Default::default()),
+        Endpoints::LawByCongress(118.into(), Default::default()),
+        Endpoints::LawDetails(118.into(), LawType::Pub, 3076, Default::default()),
+        Endpoints::AmendmentList(Default::default()),
+        Endpoints::AmendmentByCongress(118.into(), Default::default()),
+        Endpoints::AmendmentByType(118.into(), AmendmentType::Hamdt, Default::default()),
+        Endpoints::AmendmentDetails(118.into(), AmendmentType::Hamdt, 3076, Default::default()),
+        Endpoints::AmendmentActions(
+            118.into(),
+            AmendmentType::Hamdt,
+            "1".to_string(),
+            Default::default(),
+        ),
+        Endpoints::AmendmentCosponsors(
+            118.into(),
+            AmendmentType::Hamdt,
+            "1".to_string(),
+            Default::default(),
+        ),
+        Endpoints::AmendmentAmendments(
+            118.into(),
+            AmendmentType::Hamdt,
+            "1".to_string(),
+            Default::default(),
+        ),
+        Endpoints::AmendmentText(
+            118.into(),
+            AmendmentType::Hamdt,
+            "1".to_string(),
+            Default::default(),
+        ),
+        Endpoints::SummariesList(Default::default()),
+        Endpoints::SummariesByCongress(118.into(), Default::default()),
+        Endpoints::SummariesByType(118.into(), BillType::Hr, Default::default()),
+        Endpoints::CongressList(Default::default()),
+        Endpoints::CongressDetails(118.into(), Default::default()),
+        Endpoints::CongressCurrent(Default::default()),
+        Endpoints::MemberList(Default::default()),
+        Endpoints::MemberByCongress(118.into(), Default::default()),
+        Endpoints::MemberByState("CA".to_string(), Default::default()),
+        Endpoints::MemberByStateDistrict("CA".to_string(), 12, Default::default()),
+        Endpoints::MemberByCongressStateDistrict(
+            118.into(),
+            "CA".to_string(),
+            12,
+            Default::default(),
+        ),
+        Endpoints::MemberDetails("A000360".to_string(), Default::default()),
+        Endpoints::SponsorshipList("A000360".to_string(), Default::default()),
+        Endpoints::CosponsorshipList("A000360".to_string(), Default::default()),
+        Endpoints::CommitteeList(Default::default()),
+        Endpoints::CommitteeByChamber(ChamberType::House, Default::default()),
+        Endpoints::CommitteeByCongress(118.into(), Default::default()),
+        Endpoints::CommitteeByCongressChamber(118.into(), ChamberType::House, Default::default()),
+        Endpoints::CommitteeDetails(ChamberType::House, "hsag00".to_string(), Default::default()),
+        Endpoints::CommitteeBills(ChamberType::House, "hsag00".to_string(), Default::default()),
+        Endpoints::CommitteeReports(ChamberType::House, "hsag00".to_string(), Default::default()),
+        Endpoints::CommitteeNominations(
+            ChamberType::House,
+            "hsag00".to_string(),
+            Default::default(),
+        ),
+        Endpoints::CommitteeHouseCommunication(
+            ChamberType::House,
+            "hsag00".to_string(),
+            Default::default(),
+        ),
+        Endpoints::CommitteeSenateCommunication(
+            ChamberType::House,
+            "hsag00".to_string(),
+            Default::default(),
+        ),
+        Endpoints::CommitteeReportList(Default::default()),
+        Endpoints::CommitteeReportByCongress(118.into(), Default::default()),
+        Endpoints::CommitteeReportByType(118.into(), CommitteeReportType::Hrpt, Default::default()),
+        Endpoints::CommitteeReportDetails(
+            118.into(),
+            CommitteeReportType::Hrpt,
+            3076,
+            Default::default(),
+        ),
+        Endpoints::CommitteeReportText(
+            118.into(),
+            CommitteeReportType::Hrpt,
+            3076,
+            Default::default(),
+        ),
+        Endpoints::CommitteePrintList(Default::default()),
+        Endpoints::CommitteePrintByCongress(118.into(), Default::default()),
+        Endpoints::CommitteePrintByCongressChamber(
+            118.into(),
+            ChamberType::House,
+            Default::default(),
+        ),
+        Endpoints::CommitteePrintByJacketNumber(118.into(), 48144, Default::default()),
+        Endpoints::CommitteePrintDetails(118.into(), ChamberType::House, 48144, Default::default()),
+        Endpoints::CommitteePrintText(118.into(), "house".to_string(), 48144, Default::default()),
+        Endpoints::CommitteeMeetingList(Default::default()),
+        Endpoints::CommitteeMeetingByCongress(118.into(), Default::default()),
+        Endpoints::CommitteeMeetingByChamber(118.into(), ChamberType::House, Default::default()),
+        Endpoints::CommitteeMeetingByEvent(
+            118.into(),
+            ChamberType::House,
+            "115538".to_string(),
+            Default::default(),
+        ),
+        Endpoints::HearingList(Default::default()),
+        Endpoints::HearingByCongress(118.into(), Default::default()),
+        Endpoints::HearingByChamber(118.into(), ChamberType::House, Default::default()),
+        Endpoints::HearingByJacketNumber(118.into(), ChamberType::House, 41365, Default::default()),
+        Endpoints::CongressionalRecordList(Default::default()),
+        Endpoints::DailyCongressionalRecordList(Default::default()),
+        Endpoints::DailyCongressionalRecordVolume(170, Default::default()),
+        Endpoints::DailyCongressionalRecordVolumeIssue(170, 1, Default::default()),
+        Endpoints::DailyCongressionalRecordArticles(170, 1, Default::default()),
+        Endpoints::BoundCongressionalRecordList(Default::default()),
+        Endpoints::BoundCongressionalRecordByYear(2024, Default::default()),
+        Endpoints::BoundCongressionalRecordByYearMonth(2024, 1, Default::default()),
+        Endpoints::BoundCongressionalRecordByYearMonthDay(2024, 1, 10, Default::default()),
+        Endpoints::HouseCommunicationList(Default::default()),
+        Endpoints::HouseCommunicationByCongress(118.into(), Default::default()),
+        Endpoints::HouseCommunicationByType(118.into(), CommunicationType::Ec, Default::default()),
+        Endpoints::HouseCommunicationDetails(
+            118.into(),
+            CommunicationType::Ec,
+            3076,
+            Default::default(),
+        ),
+        Endpoints::HouseRequirementList(Default::default()),
+        Endpoints::HouseRequirementDetails(118, Default::default()),
+        Endpoints::HouseRequirementMatching(118, Default::default()),
+        Endpoints::SenateCommunicationList(Default::default()),
+        Endpoints::SenateCommunicationByCongress(118.into(), Default::default()),
+        Endpoints::SenateCommunicationByType(118.into(), CommunicationType::Ec, Default::default()),
+        Endpoints::SenateCommunicationDetails(
+            118.into(),
+            CommunicationType::Ec,
+            3076,
+            Default::default(),
+        ),
+        Endpoints::NominationList(Default::default()),
+        Endpoints::NominationByCongress(118.into(), Default::default()),
+        Endpoints::NominationDetails(118.into(), "1".to_string(), Default::default()),
+        Endpoints::Nominees(118.into(), "1".to_string(), 1, Default::default()),
+        Endpoints::NominationActions(118.into(), "1".to_string(), Default::default()),
+        Endpoints::NominationCommittees(118.into(), "1".to_string(), Default::default()),
+        Endpoints::NominationHearings(118.into(), "1".to_string(), Default::default()),
+        Endpoints::TreatyList(Default::default()),
+        Endpoints::TreatyByCongress(118.into(), Default::default()),
+        Endpoints::TreatyDetails(114.into(), 13, Default::default()),
+        Endpoints::TreatyPartitioned(
+            114.into(),
+            13,
+            TreatySuffix::new("A").unwrap(),
+            Default::default(),
+        ),
+        Endpoints::TreatyCommittees(114.into(), 13, Default::default()),
+        Endpoints::TreatyActions(114.into(), 13, Default::default()),
+        Endpoints::TreatyActionsBySuffix(
+            114.into(),
+            13,
+            TreatySuffix::new("A").unwrap(),
+            Default::default(),
+        ),
+    ];
+
+    // One endpoint per variant.
+    let variants: std::collections::HashSet<_> =
+        endpoints.iter().map(std::mem::discriminant).collect();
+    assert_eq!(variants.len(), endpoints.len());
+
+    // Every variant is described, and no two alike.
+    let descriptions: std::collections::HashSet<_> =
+        endpoints.iter().map(Endpoints::describe).collect();
+    assert_eq!(descriptions.len(), endpoints.len());
+
+    for endpoint in &endpoints {
+        let path = endpoint.to_string();
+        assert!(!path.is_empty(), "{:?}", endpoint);
+        assert_eq!(
+            path.split(['/', '?']).next(),
+            Some(resource_prefix(endpoint)),
+            "{}",
+            path
+        );
+        assert_eq!(endpoint.resource_name(), resource_prefix(endpoint));
+        assert!(endpoint.describe().ends_with('.'), "{:?}", endpoint);
+    }
+}
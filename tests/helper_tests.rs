@@ -0,0 +1,27 @@
+use cdg_api::{unwrap_option, unwrap_or_na};
+
+#[test]
+fn test_unwrap_option_defaults() {
+    assert_eq!(unwrap_option(Some("HR".to_string())), "HR");
+    assert_eq!(unwrap_option::<String>(None), "");
+    assert_eq!(unwrap_option(Some(118u32)), 118);
+    assert_eq!(unwrap_option::<u32>(None), 0);
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_deprecated_unwrap_helpers_match_generic() {
+    use cdg_api::{unwrap_option_string, unwrap_option_u32};
+
+    assert_eq!(unwrap_option_string(None), unwrap_option::<String>(None));
+    assert_eq!(unwrap_option_u32(Some(7)), unwrap_option(Some(7u32)));
+}
+
+#[test]
+fn test_unwrap_or_na() {
+    assert_eq!(
+        unwrap_or_na(Some("Lower Energy Costs Act".to_string())),
+        "Lower Energy Costs Act"
+    );
+    assert_eq!(unwrap_or_na(None), "N/A");
+}
@@ -7,7 +7,7 @@ use cdg_api::{
     param_models::*,
     response_models::{
         BillsResponse, CommitteeDetailsResponse, CommitteeReportsResponse, CongressDetailsResponse,
-        HearingsResponse, LawDetailsResponse, LawsResponse, MembersResponse,
+        GenericResponse, HearingsResponse, LawDetailsResponse, LawsResponse, MembersResponse,
         NominationDetailsResponse, NominationsResponse, RelatedBillsResponse, TreatiesResponse,
         TreatyDetailsResponse,
     },
@@ -74,7 +74,7 @@ fn test_fetch_bills() {
         println!(
             "{}, {}, {}",
             bill.title.unwrap_or("".to_string()),
-            bill.bill_type.unwrap_or_default(),
+            bill.bill_type.unwrap_or_default().to_string(),
             bill.number.unwrap_or_default()
         );
     }
@@ -142,7 +142,7 @@ fn test_fetch_amendments() {
         println!(
             "{:#?}, {}, {}",
             amendment.latest_action,
-            amendment.amendment_type.unwrap_or_default(),
+            amendment.amendment_type.unwrap_or_default().to_string(),
             amendment.number.unwrap_or("".to_string())
         );
     }
@@ -477,3 +477,109 @@ fn test_fetch_daily_congressional_records() {
         );
     }
 }
+
+/// Returns whether the live API checks were requested by setting `CDG_LIVE_TESTS`.
+fn live_tests_enabled() -> bool {
+    std::env::var_os("CDG_LIVE_TESTS").is_some()
+}
+
+#[test]
+fn test_detail_endpoints_accept_format_param() {
+    if !live_tests_enabled() {
+        println!("Skipping: set CDG_LIVE_TESTS to query the live API");
+        return;
+    }
+
+    let client = CongressApiClient::new(None).expect("Failed to create CongressApiClient");
+    let json = FormatType::Json;
+
+    // Known items taken from the congress.gov API documentation.
+    let endpoints = vec![
+        Endpoints::new_bill_details(
+            117,
+            BillType::Hr,
+            3076,
+            BillDetailsParams::default().format(json),
+        ),
+        Endpoints::new_law_details(117, LawType::Pub, 108, LawParams::default().format(json)),
+        Endpoints::new_amendment_details(
+            117,
+            AmendmentType::Samdt,
+            2137,
+            AmendmentDetailsParams::default().format(json),
+        ),
+        Endpoints::new_congress_details(117, CongressDetailsParams::default().format(json)),
+        Endpoints::new_member_details(
+            "L000174".to_string(),
+            MemberDetailsParams::default().format(json),
+        ),
+        Endpoints::new_committee_details(
+            ChamberType::House,
+            "hspw00".to_string(),
+            CommitteeDetailsParams::default().format(json),
+        ),
+        Endpoints::new_nomination_details(
+            117,
+            "2467".to_string(),
+            NominationDetailsParams::default().format(json),
+        ),
+        Endpoints::new_treaty_details(112, 1, TreatyDetailsParams::default().format(json)),
+        Endpoints::new_committee_report_details(
+            116,
+            CommitteeReportType::Hrpt,
+            617,
+            CommitteeReportDetailsParams::default().format(json),
+        ),
+        Endpoints::new_committee_print_details(
+            117,
+            ChamberType::House,
+            48144,
+            CommitteePrintByJacketNumberParams::default().format(json),
+        ),
+        Endpoints::new_committee_meeting_by_event(
+            118,
+            ChamberType::House,
+            "115538".to_string(),
+            CommitteeMeetingByEventParams::default().format(json),
+        ),
+        Endpoints::new_hearing_by_jacket_number(
+            116,
+            ChamberType::House,
+            41365,
+            HearingByJacketNumberParams::default().format(json),
+        ),
+        Endpoints::new_house_communication_details(
+            117,
+            CommunicationType::Ec,
+            3324,
+            CommunicationDetailsParams::default().format(json),
+        ),
+        Endpoints::new_house_requirement_details(
+            8070,
+            RequirementDetailsParams::default().format(json),
+        ),
+        Endpoints::new_senate_communication_details(
+            117,
+            CommunicationType::Ec,
+            2561,
+            CommunicationDetailsParams::default().format(json),
+        ),
+    ];
+
+    let rejected: Vec<String> = endpoints
+        .into_iter()
+        .filter_map(|endpoint| {
+            let url = endpoint.cache_key().unwrap();
+            match client.fetch::<GenericResponse>(endpoint) {
+                Ok(_) => None,
+                Err(err) => Some(format!("{}: {}", url, err)),
+            }
+        })
+        .collect();
+
+    assert!(
+        rejected.is_empty(),
+        "Detail endpoints rejected by the live API:\n{}",
+        rejected.join("\n")
+    );
+}
@@ -0,0 +1,42 @@
+use cdg_api::pagination::paginate;
+use std::convert::Infallible;
+
+/// Serves `total` sequential records as 0-based `offset`/`limit` pages, recording each request.
+fn serve(
+    total: usize,
+    requests: &mut Vec<(usize, usize)>,
+) -> impl FnMut(usize, usize) -> Result<Vec<usize>, Infallible> + '_ {
+    move |offset, limit| {
+        requests.push((offset, limit));
+        Ok((offset..total.min(offset + limit)).collect())
+    }
+}
+
+#[test]
+fn test_offsets_follow_api_contract() {
+    let mut requests = Vec::new();
+    let items = paginate(serve(600, &mut requests), 1000, 250).unwrap();
+
+    // Page 1 starts at offset 0, page 2 at 250, page 3 at 500.
+    assert_eq!(requests, vec![(0, 250), (250, 250), (500, 250)]);
+    // Every record is returned exactly once, in order.
+    assert_eq!(items, (0..600).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_max_stops_paging_and_truncates() {
+    let mut requests = Vec::new();
+    let items = paginate(serve(10_000, &mut requests), 300, 250).unwrap();
+
+    assert_eq!(requests, vec![(0, 250), (250, 250)]);
+    assert_eq!(items, (0..300).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_exact_page_boundary_requests_one_empty_page() {
+    let mut requests = Vec::new();
+    let items = paginate(serve(500, &mut requests), 1000, 250).unwrap();
+
+    assert_eq!(requests, vec![(0, 250), (250, 250), (500, 250)]);
+    assert_eq!(items.len(), 500);
+}
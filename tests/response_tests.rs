@@ -0,0 +1,1024 @@
+use cdg_api::cdg_types::{AmendmentType, BillType, ChamberType, TreatySuffix};
+use cdg_api::endpoints::Endpoints;
+use cdg_api::response_models::{
+    AmendmentsResponse, BillDetailsResponse, BillsResponse, CommitteeBillsResponse,
+    CommitteeDetailsResponse, CommitteeMeetingDetailsResponse, CommitteeNominationsResponse,
+    CommitteePrintDetailsResponse, CommitteeReportsResponse, CommitteesResponse,
+    CongressDetailsResponse, CongressesResponse, Depiction, HouseRequirementDetailsResponse,
+    HouseRequirementsResponse, ItemRef, LawDetailsResponse, LawsResponse, ListResponse,
+    MatchingCommunicationsResponse, MembersResponse, NominationKind, NominationsResponse,
+    Paginated, SummariesResponse, TreatiesResponse,
+};
+
+#[test]
+fn test_bill_summary_dates() {
+    let json = r#"{
+        "bills": [
+            {
+                "congress": 118,
+                "introducedDate": "2023-01-09",
+                "number": "1",
+                "originChamber": "House",
+                "title": "Lower Energy Costs Act",
+                "type": "HR",
+                "updateDate": "2024-05-01",
+                "url": "https://api.congress.gov/v3/bill/118/hr/1?format=json"
+            }
+        ]
+    }"#;
+
+    let response: BillsResponse = serde_json::from_str(json).expect("Failed to parse bills");
+    let bill = &response.bills[0];
+
+    assert_eq!(bill.introduced_date.as_deref(), Some("2023-01-09"));
+    assert_eq!(bill.update_date.as_deref(), Some("2024-05-01"));
+}
+
+#[test]
+fn test_committee_bills_nested_block() {
+    let json = r#"{
+        "committee-bills": {
+            "bills": [
+                {
+                    "actionDate": "2012-04-19T13:01:00Z",
+                    "billType": "HCONRES",
+                    "congress": 112,
+                    "number": "117",
+                    "relationshipType": "Referred to",
+                    "updateDate": "2022-02-01T14:34:11Z",
+                    "url": "https://api.congress.gov/v3/bill/112/hconres/117?format=json"
+                }
+            ],
+            "count": 25384,
+            "url": "https://api.congress.gov/v3/committee/house/hspw00/bills?format=json"
+        },
+        "pagination": {
+            "count": 25384,
+            "next": "https://api.congress.gov/v3/committee/house/hspw00/bills?offset=20&limit=20&format=json"
+        }
+    }"#;
+
+    let response: CommitteeBillsResponse =
+        serde_json::from_str(json).expect("Failed to parse committee bills");
+    let block = &response.committee_bills;
+
+    assert_eq!(block.count, Some(25384));
+    assert_eq!(block.bills.len(), 1);
+    assert_eq!(block.bills[0].bill_type.as_deref(), Some("HCONRES"));
+    assert_eq!(
+        block.bills[0].relationship_type.as_deref(),
+        Some("Referred to")
+    );
+    assert!(response.unknown.is_some());
+}
+
+#[test]
+fn test_committee_reports_and_nominations() {
+    let reports = r#"{
+        "reports": [
+            {
+                "citation": "H. Rept. 109-570",
+                "congress": 109,
+                "number": 570,
+                "part": 1,
+                "type": "HRPT",
+                "updateDate": "2015-03-20T00:01:26Z",
+                "url": "https://api.congress.gov/v3/committee-report/109/HRPT/570?format=json"
+            }
+        ]
+    }"#;
+
+    let response: CommitteeReportsResponse =
+        serde_json::from_str(reports).expect("Failed to parse committee reports");
+    assert_eq!(response.reports[0].number, Some(570));
+    assert_eq!(response.reports[0].report_type.as_deref(), Some("HRPT"));
+
+    let nominations = r#"{
+        "nominations": [
+            {
+                "citation": "PN2477",
+                "congress": 117,
+                "description": "Pamela A. Thomas, of Tennessee, to be United States Marshal",
+                "latestAction": {
+                    "actionDate": "2022-08-03",
+                    "text": "Received in the Senate and referred to the Committee on the Judiciary."
+                },
+                "nominationType": {
+                    "isCivilian": true,
+                    "isMilitary": false
+                },
+                "number": 2477,
+                "partNumber": "00",
+                "receivedDate": "2022-08-03",
+                "updateDate": "2022-08-04T04:25:12Z",
+                "url": "https://api.congress.gov/v3/nomination/117/2477?format=json"
+            }
+        ]
+    }"#;
+
+    let response: CommitteeNominationsResponse =
+        serde_json::from_str(nominations).expect("Failed to parse committee nominations");
+    let nomination = &response.nominations[0];
+    assert_eq!(nomination.number, Some(2477));
+    assert_eq!(
+        nomination
+            .nomination_type
+            .as_ref()
+            .and_then(|t| t.is_civilian),
+        Some(true)
+    );
+}
+
+#[test]
+fn test_nomination_kinds() {
+    let json = r#"{
+        "nominations": [
+            {
+                "citation": "PN2477",
+                "congress": 117,
+                "nominationType": {
+                    "isCivilian": true,
+                    "isMilitary": false
+                },
+                "number": 2477
+            },
+            {
+                "citation": "PN2466",
+                "congress": 117,
+                "nominationType": {
+                    "isCivilian": false,
+                    "isMilitary": true
+                },
+                "number": 2466,
+                "organization": "Army"
+            },
+            {
+                "citation": "PN2400",
+                "congress": 117,
+                "nominationType": {},
+                "number": 2400
+            }
+        ]
+    }"#;
+
+    let response: NominationsResponse =
+        serde_json::from_str(json).expect("Failed to parse nominations");
+    let kinds: Vec<NominationKind> = response
+        .nominations
+        .iter()
+        .map(|n| n.nomination_type.clone().unwrap_or_default().kind())
+        .collect();
+
+    assert_eq!(
+        kinds,
+        vec![
+            NominationKind::Civilian,
+            NominationKind::Military,
+            NominationKind::Unknown
+        ]
+    );
+    assert_eq!(NominationKind::Military.to_string(), "Military");
+    assert_eq!(
+        serde_json::to_string(&NominationKind::Civilian).unwrap(),
+        "\"civilian\""
+    );
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn test_bills_to_csv() {
+    let json = r#"{
+        "bills": [
+            {
+                "congress": 118,
+                "latestAction": {
+                    "actionDate": "2023-03-30",
+                    "text": "Received in the Senate."
+                },
+                "number": "1",
+                "originChamber": "House",
+                "title": "Lower Energy Costs Act",
+                "type": "HR"
+            },
+            {
+                "congress": 118,
+                "number": "5",
+                "originChamber": "House",
+                "title": "Parents Bill of Rights Act, as amended",
+                "type": "HR"
+            }
+        ]
+    }"#;
+
+    let response: BillsResponse = serde_json::from_str(json).expect("Failed to parse bills");
+    let csv = response.to_csv().expect("Failed to export bills");
+
+    assert_eq!(csv, include_str!("fixtures/bills.csv"));
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn test_laws_to_csv() {
+    let response: LawsResponse =
+        serde_json::from_str(include_str!("fixtures/laws.json")).expect("Failed to parse laws");
+    let csv = response.to_csv().expect("Failed to export laws");
+
+    assert_eq!(csv, include_str!("fixtures/laws.csv"));
+}
+
+#[test]
+fn test_depiction_image_url_normalization() {
+    let depiction = |url: Option<&str>| Depiction {
+        image_url: url.map(str::to_string),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        depiction(Some("https://www.congress.gov/img/member/a000001.jpg"))
+            .image_url()
+            .as_deref(),
+        Some("https://www.congress.gov/img/member/a000001.jpg")
+    );
+    assert_eq!(
+        depiction(Some("/img/member/a000001.jpg"))
+            .image_url()
+            .as_deref(),
+        Some("https://www.congress.gov/img/member/a000001.jpg")
+    );
+    assert_eq!(
+        depiction(Some("//www.congress.gov/img/member/a000001.jpg"))
+            .image_url()
+            .as_deref(),
+        Some("https://www.congress.gov/img/member/a000001.jpg")
+    );
+    assert_eq!(
+        depiction(Some("http://www.congress.gov/img/member/a000001.jpg"))
+            .image_url()
+            .as_deref(),
+        Some("https://www.congress.gov/img/member/a000001.jpg")
+    );
+    assert_eq!(depiction(Some("  ")).image_url(), None);
+    assert_eq!(depiction(None).image_url(), None);
+    assert_eq!(Depiction::default().image_url(), None);
+}
+
+#[test]
+fn test_committee_print_details() {
+    let json = r#"{
+        "committeePrint": [
+            {
+                "associatedBills": [
+                    {
+                        "congress": 117,
+                        "number": 5768,
+                        "type": "HR",
+                        "url": "https://api.congress.gov/v3/bill/117/hr/5768?format=json"
+                    }
+                ],
+                "chamber": "House",
+                "citation": "117-62",
+                "congress": 117,
+                "jacketNumber": 48144,
+                "number": "62",
+                "text": {
+                    "count": 4,
+                    "url": "https://api.congress.gov/v3/committee-print/117/house/48144/text?format=json"
+                },
+                "title": "RULES COMMITTEE PRINT 117-62 TEXT OF H.R. 5768",
+                "updateDate": "2022-08-01 21:19:33+00:00"
+            }
+        ]
+    }"#;
+
+    let response: CommitteePrintDetailsResponse =
+        serde_json::from_str(json).expect("Failed to parse committee print details");
+    let print = &response.committee_prints[0];
+    assert_eq!(print.jacket_number, Some(48144));
+    assert_eq!(print.citation.as_deref(), Some("117-62"));
+    assert_eq!(print.number, Some(62));
+    assert_eq!(print.chamber.as_deref(), Some("House"));
+    assert_eq!(print.text.as_ref().and_then(|t| t.count), Some(4));
+}
+
+#[test]
+fn test_string_or_number_fields() {
+    let json = r#"{
+        "nominations": [
+            { "congress": 118, "number": 2477 },
+            { "congress": "118", "number": "2478" },
+            { "congress": null, "number": "" },
+            {}
+        ]
+    }"#;
+
+    let response: NominationsResponse =
+        serde_json::from_str(json).expect("Failed to parse mixed numeric fields");
+    let parsed: Vec<(Option<u32>, Option<u32>)> = response
+        .nominations
+        .iter()
+        .map(|n| (n.congress, n.number))
+        .collect();
+
+    assert_eq!(
+        parsed,
+        vec![
+            (Some(118), Some(2477)),
+            (Some(118), Some(2478)),
+            (None, None),
+            (None, None)
+        ]
+    );
+
+    let invalid = r#"{ "nominations": [{ "congress": "one hundred" }] }"#;
+    assert!(serde_json::from_str::<NominationsResponse>(invalid).is_err());
+}
+
+#[test]
+fn test_string_or_number_range_checks() {
+    let parse = |congress: &str| {
+        let json = format!(r#"{{ "nominations": [{{ "congress": {} }}] }}"#, congress);
+        serde_json::from_str::<NominationsResponse>(&json)
+            .map(|response| response.nominations[0].congress)
+            .map_err(|err| err.to_string())
+    };
+
+    assert_eq!(parse("118.0"), Ok(Some(118)));
+    assert_eq!(parse("4294967295"), Ok(Some(u32::MAX)));
+
+    let overflow = parse("4294967296").expect_err("Out-of-range number must not wrap");
+    assert!(overflow.starts_with(
+        "invalid value: integer `4294967296`, expected a whole number between 0 and 4294967295"
+    ));
+    assert!(parse("\"4294967296\"").is_err());
+    assert!(parse("-1").is_err());
+    assert!(parse("118.5").is_err());
+}
+
+#[test]
+fn test_house_requirement_models() {
+    let list = r#"{
+        "houseRequirements": [
+            {
+                "number": 8070,
+                "updateDate": "2021-08-13",
+                "url": "https://api.congress.gov/v3/house-requirement/8070?format=json"
+            }
+        ]
+    }"#;
+    let response: HouseRequirementsResponse =
+        serde_json::from_str(list).expect("Failed to parse house requirements");
+    assert_eq!(response.house_requirements[0].number, Some(8070));
+
+    let details = r#"{
+        "houseRequirement": {
+            "activeRecord": true,
+            "frequency": "Annually.",
+            "legalAuthority": "Public Law 111-203, section 1625",
+            "matchingCommunications": {
+                "count": 2,
+                "url": "https://api.congress.gov/v3/house-requirement/8070/matching-communications?format=json"
+            },
+            "nature": "Report on the implementation of the Consumer Financial Protection Act.",
+            "number": 8070,
+            "parentAgency": "Bureau of Consumer Financial Protection",
+            "updateDate": "2021-08-13"
+        }
+    }"#;
+    let response: HouseRequirementDetailsResponse =
+        serde_json::from_str(details).expect("Failed to parse house requirement details");
+    let requirement = &response.house_requirement;
+    assert_eq!(requirement.frequency.as_deref(), Some("Annually."));
+    assert_eq!(
+        requirement
+            .matching_communications_inner
+            .as_ref()
+            .and_then(|m| m.count),
+        Some(2)
+    );
+
+    let matching = r#"{
+        "matchingCommunications": [
+            {
+                "chamber": "House",
+                "communicationType": {
+                    "code": "EC",
+                    "name": "Executive Communication"
+                },
+                "congress": 112,
+                "number": 2263,
+                "url": "https://api.congress.gov/v3/house-communication/112/ec/2263?format=json"
+            }
+        ]
+    }"#;
+    let response: MatchingCommunicationsResponse =
+        serde_json::from_str(matching).expect("Failed to parse matching communications");
+    let communication = &response.matching_communications[0];
+    assert_eq!(communication.congress, Some(112));
+    assert_eq!(
+        communication
+            .communication_type
+            .as_ref()
+            .and_then(|t| t.code.as_deref()),
+        Some("EC")
+    );
+}
+
+#[test]
+fn test_chamber_type_round_trip() {
+    let cases = [
+        ("House", ChamberType::House),
+        ("Senate", ChamberType::Senate),
+        ("Joint", ChamberType::Joint),
+        ("NoChamber", ChamberType::NoChamber),
+    ];
+
+    for (api_name, chamber) in cases {
+        let from_api: ChamberType = serde_json::from_value(serde_json::json!(api_name))
+            .expect("Failed to parse API chamber name");
+        let from_token: ChamberType =
+            serde_json::from_value(serde_json::json!(chamber.to_string()))
+                .expect("Failed to parse URL chamber token");
+
+        assert_eq!(from_api, chamber);
+        assert_eq!(from_token, chamber);
+        assert_eq!(
+            serde_json::to_value(chamber).unwrap(),
+            serde_json::json!(api_name)
+        );
+    }
+}
+
+#[test]
+fn test_bill_details_laws_separate_from_related_bills() {
+    let json = r#"{
+        "bill": {
+            "congress": 117,
+            "laws": [
+                {
+                    "number": "117-169",
+                    "type": "Public Law"
+                }
+            ],
+            "number": "5376",
+            "originChamber": "House",
+            "relatedBills": {
+                "count": 3,
+                "url": "https://api.congress.gov/v3/bill/117/hr/5376/relatedbills?format=json"
+            },
+            "title": "Inflation Reduction Act of 2022",
+            "type": "HR"
+        }
+    }"#;
+
+    let response: BillDetailsResponse =
+        serde_json::from_str(json).expect("Failed to parse bill details");
+    let bill = &response.bill;
+
+    let laws = bill.laws.as_ref().expect("bill became law");
+    assert_eq!(laws.len(), 1);
+    assert_eq!(laws[0].number.as_deref(), Some("117-169"));
+    assert_eq!(laws[0].law_type.as_deref(), Some("Public Law"));
+    assert_eq!(bill.related_bills.as_ref().and_then(|r| r.count), Some(3));
+}
+
+#[test]
+fn test_list_response_projection() {
+    let json = r#"{
+        "members": [
+            { "bioguideId": "A000001", "name": "Doe, Jane", "state": "Ohio", "partyName": "Democratic" },
+            { "bioguideId": "B000002", "name": "Roe, John", "state": "Utah", "partyName": "Republican" }
+        ]
+    }"#;
+
+    let response: MembersResponse = serde_json::from_str(json).expect("Failed to parse members");
+    let rows = response.project(|member| (member.name.clone(), member.state.clone()));
+
+    assert_eq!(response.items().len(), 2);
+    assert_eq!(
+        rows,
+        vec![
+            (Some("Doe, Jane".to_string()), Some("Ohio".to_string())),
+            (Some("Roe, John".to_string()), Some("Utah".to_string())),
+        ]
+    );
+}
+
+#[test]
+fn test_amendment_summary_sponsors() {
+    let json = r#"{
+        "amendments": [
+            {
+                "congress": 118,
+                "number": "2",
+                "purpose": "Amendment to strike section 3.",
+                "sponsors": [
+                    {
+                        "bioguideId": "S000250",
+                        "firstName": "Pete",
+                        "fullName": "Rep. Sessions, Pete [R-TX-17]",
+                        "lastName": "Sessions",
+                        "url": "https://api.congress.gov/v3/member/S000250?format=json"
+                    }
+                ],
+                "type": "HAMDT",
+                "updateDate": "2024-01-10T15:26:39Z",
+                "url": "https://api.congress.gov/v3/amendment/118/hamdt/2?format=json"
+            },
+            {
+                "congress": 118,
+                "number": "3",
+                "type": "SAMDT"
+            }
+        ]
+    }"#;
+
+    let response: AmendmentsResponse =
+        serde_json::from_str(json).expect("Failed to parse amendments");
+
+    let sponsors = response.amendments[0]
+        .sponsors
+        .as_ref()
+        .expect("sponsors should be present");
+    assert_eq!(sponsors.len(), 1);
+    assert_eq!(sponsors[0].bioguide_id.as_deref(), Some("S000250"));
+    assert!(response.amendments[1].sponsors.is_none());
+}
+
+#[test]
+fn test_paginated_next_offset() {
+    let json = r#"{
+        "members": [{"bioguideId": "A000001"}, {"bioguideId": "A000002"}],
+        "pagination": {
+            "count": 2542,
+            "next": "https://api.congress.gov/v3/member?offset=2&limit=2&format=json"
+        }
+    }"#;
+
+    let response: MembersResponse = serde_json::from_str(json).expect("Failed to parse members");
+    assert_eq!(response.next_offset(), Some(2));
+    let ids: Vec<Option<String>> = response
+        .into_items()
+        .into_iter()
+        .map(|member| member.bioguide_id)
+        .collect();
+    assert_eq!(
+        ids,
+        vec![Some("A000001".to_string()), Some("A000002".to_string())]
+    );
+
+    // The last page has no `next` link.
+    let json = r#"{"members": [], "pagination": {"count": 2542}}"#;
+    let response: MembersResponse = serde_json::from_str(json).expect("Failed to parse members");
+    assert_eq!(response.next_offset(), None);
+}
+
+#[test]
+fn test_bill_summary_type_codes() {
+    let response: BillsResponse = serde_json::from_str(include_str!("fixtures/bill_types.json"))
+        .expect("Failed to parse bills");
+    let types: Vec<_> = response.bills.iter().map(|bill| bill.bill_type).collect();
+
+    assert_eq!(
+        types,
+        BillType::all()
+            .iter()
+            .copied()
+            .map(Some)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        serde_json::to_value(BillType::Hjres).unwrap(),
+        serde_json::json!("HJRES")
+    );
+    for bill_type in BillType::all() {
+        let from_token: BillType = serde_json::from_value(serde_json::json!(bill_type.to_string()))
+            .expect("Failed to parse URL bill type token");
+        assert_eq!(from_token, *bill_type);
+    }
+
+    // Earlier releases serialized the variant names.
+    let legacy: Vec<BillType> = serde_json::from_value(serde_json::json!([
+        "Hr", "S", "Hjres", "Sjres", "Hconres", "Sconres", "Hres", "Sres"
+    ]))
+    .expect("Failed to parse legacy bill types");
+    assert_eq!(legacy, BillType::all());
+}
+
+#[test]
+fn test_amendment_summary_type_codes() {
+    let response: AmendmentsResponse =
+        serde_json::from_str(include_str!("fixtures/amendment_types.json"))
+            .expect("Failed to parse amendments");
+    let types: Vec<_> = response
+        .amendments
+        .iter()
+        .map(|amendment| amendment.amendment_type)
+        .collect();
+
+    assert_eq!(
+        types,
+        vec![
+            Some(AmendmentType::Hamdt),
+            Some(AmendmentType::Samdt),
+            Some(AmendmentType::Suamdt),
+        ]
+    );
+
+    // Earlier releases serialized the variant names.
+    let legacy: Vec<AmendmentType> =
+        serde_json::from_value(serde_json::json!(["Hamdt", "Samdt", "Suamdt"]))
+            .expect("Failed to parse legacy amendment types");
+    assert_eq!(
+        legacy,
+        [
+            AmendmentType::Hamdt,
+            AmendmentType::Samdt,
+            AmendmentType::Suamdt
+        ]
+    );
+}
+
+#[test]
+fn test_members_dedup_by_bioguide() {
+    let json = r#"{
+        "members": [
+            {"bioguideId": "P000197", "name": "Pelosi, Nancy",
+             "terms": {"item": [{"chamber": "House of Representatives", "startYear": 1987}]}},
+            {"bioguideId": "S000148", "name": "Schumer, Charles E.",
+             "terms": {"item": [{"chamber": "Senate", "startYear": 1999}]}},
+            {"bioguideId": "P000197", "name": "Pelosi, Nancy", "district": 11,
+             "terms": {"item": [
+                 {"chamber": "House of Representatives", "startYear": 1987},
+                 {"chamber": "House of Representatives", "startYear": 2023}
+             ]}},
+            {"name": "Unknown Member"}
+        ]
+    }"#;
+
+    let response: MembersResponse = serde_json::from_str(json).expect("Failed to parse members");
+    let unique = response.dedup_by_bioguide();
+
+    let ids: Vec<_> = unique
+        .iter()
+        .map(|member| member.bioguide_id.as_deref())
+        .collect();
+    assert_eq!(ids, vec![Some("P000197"), Some("S000148"), None]);
+    assert_eq!(unique[0].district, Some(11));
+}
+
+#[test]
+fn test_law_details_sponsors() {
+    let response: LawDetailsResponse =
+        serde_json::from_str(include_str!("fixtures/law_details.json"))
+            .expect("Failed to parse law details");
+    let bill = &response.bill;
+
+    let sponsors = bill.sponsors.as_ref().expect("missing sponsors");
+    let ids: Vec<_> = sponsors
+        .iter()
+        .map(|sponsor| sponsor.bioguide_id.as_deref())
+        .collect();
+    assert_eq!(ids, vec![Some("M001156"), Some("S000148")]);
+    assert_eq!(sponsors[0].party.as_deref(), Some("R"));
+    assert_eq!(sponsors[0].state.as_deref(), Some("NC"));
+    assert_eq!(sponsors[0].district, Some(10));
+    assert_eq!(sponsors[0].is_by_request.as_deref(), Some("N"));
+    assert_eq!(sponsors[1].district, None);
+    assert!(sponsors[1].details_endpoint().is_some());
+
+    let cosponsors = bill.cosponsors.as_ref().expect("missing cosponsors");
+    assert_eq!(cosponsors.count, Some(12));
+    assert_eq!(cosponsors.count_including_withdrawn_cosponsors, Some(13));
+    assert!(cosponsors
+        .url
+        .as_deref()
+        .unwrap()
+        .ends_with("/cosponsors?format=json"));
+}
+
+#[test]
+fn test_member_details_endpoint() {
+    let response: MembersResponse = serde_json::from_str(include_str!("fixtures/members.json"))
+        .expect("Failed to parse members");
+
+    let member = &response.members[0];
+    assert_eq!(member.bioguide_id.as_deref(), Some("A000360"));
+    let endpoint = member.details_endpoint().expect("missing details endpoint");
+    assert!(matches!(&endpoint, Endpoints::MemberDetails(id, _) if id == "A000360"));
+    assert!(endpoint.to_string().starts_with("member/A000360"));
+
+    assert!(response.members[1].details_endpoint().is_none());
+}
+
+#[test]
+fn test_summary_parsed_ref() {
+    let json = r#"{
+        "bills": [
+            {"number": "3076", "type": "HR",
+             "url": "https://api.congress.gov/v3/bill/118/hr/3076?format=json"},
+            {"number": "1"}
+        ]
+    }"#;
+
+    let response: BillsResponse = serde_json::from_str(json).expect("Failed to parse bills");
+    let parsed = response.bills[0]
+        .parsed_ref()
+        .expect("Bill URL should parse");
+
+    assert_eq!(parsed.congress, Some(118));
+    assert_eq!(parsed.item_type.as_deref(), Some("hr"));
+    assert_eq!(parsed.number.as_deref(), Some("3076"));
+    assert!(response.bills[1].parsed_ref().is_none());
+
+    let member = ItemRef::parse("https://api.congress.gov/v3/member/P000197?format=json")
+        .expect("Member URL should parse");
+    assert_eq!(member.congress, None);
+    assert_eq!(member.number.as_deref(), Some("P000197"));
+}
+
+#[test]
+fn test_summaries_by_type_response() {
+    let json = r#"{
+        "pagination": {
+            "count": 2,
+            "next": "https://api.congress.gov/v3/summaries/118/hr?offset=1&limit=1&format=json"
+        },
+        "summaries": [
+            {
+                "actionDate": "2023-01-09",
+                "actionDesc": "Introduced in House",
+                "bill": {
+                    "congress": 118,
+                    "number": "21",
+                    "originChamber": "House",
+                    "originChamberCode": "H",
+                    "title": "Strategic Production Response Act",
+                    "type": "HR",
+                    "updateDateIncludingText": "2023-01-26T21:08:55Z",
+                    "url": "https://api.congress.gov/v3/bill/118/hr/21?format=json"
+                },
+                "currentChamber": "House",
+                "currentChamberCode": "H",
+                "lastSummaryUpdateDate": "2023-01-26T20:53:03Z",
+                "text": "<p><strong>Strategic Production Response Act</strong></p>",
+                "updateDate": "2023-01-26T20:53:03Z",
+                "versionCode": "00"
+            }
+        ]
+    }"#;
+
+    let response: SummariesResponse =
+        serde_json::from_str(json).expect("Failed to parse summaries");
+    let summary = &response.summaries[0];
+    let bill = summary
+        .bill
+        .as_ref()
+        .expect("Summary should reference a bill");
+
+    assert_eq!(summary.action_desc.as_deref(), Some("Introduced in House"));
+    assert_eq!(summary.version_code.as_deref(), Some("00"));
+    assert_eq!(bill.congress, Some(118));
+    assert_eq!(bill.bill_type.as_deref(), Some("HR"));
+    assert_eq!(response.next_offset(), Some(1));
+}
+
+#[test]
+fn test_bill_summary_origin_chamber() {
+    let response: BillsResponse = serde_json::from_str(include_str!("fixtures/bill_types.json"))
+        .expect("Failed to parse bills");
+    let chambers: Vec<_> = response
+        .bills
+        .iter()
+        .map(|bill| bill.origin_chamber)
+        .collect();
+
+    assert_eq!(chambers[0], Some(ChamberType::House));
+    assert_eq!(chambers[1], Some(ChamberType::Senate));
+    assert_eq!(ChamberType::House.label(), "House");
+    assert_eq!(ChamberType::Senate.label(), "Senate");
+}
+
+#[test]
+fn test_laws_response_carries_law_numbers() {
+    let response: LawsResponse =
+        serde_json::from_str(include_str!("fixtures/laws.json")).expect("Failed to parse laws");
+    let cited: Vec<Vec<String>> = response
+        .bills
+        .iter()
+        .map(|bill| {
+            bill.laws
+                .iter()
+                .flatten()
+                .map(|law| law.to_string())
+                .collect()
+        })
+        .collect();
+
+    assert_eq!(
+        cited,
+        vec![vec!["Public Law 118-5"], vec!["Private Law 118-1"]]
+    );
+    assert_eq!(response.bills[0].number.as_deref(), Some("3746"));
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_latest_action_datetime() {
+    use cdg_api::response_models::LatestAction;
+    use chrono::{NaiveDate, NaiveDateTime};
+
+    let parse = |json: &str| -> Option<NaiveDateTime> {
+        serde_json::from_str::<LatestAction>(json)
+            .expect("Failed to parse action")
+            .datetime()
+    };
+    let day = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+
+    assert_eq!(
+        parse(r#"{"actionDate": "2024-01-10", "actionTime": "14:05:33"}"#),
+        day.and_hms_opt(14, 5, 33)
+    );
+    assert_eq!(
+        parse(r#"{"actionDate": "2024-01-10"}"#),
+        day.and_hms_opt(0, 0, 0)
+    );
+    assert_eq!(parse(r#"{"actionTime": "14:05:33"}"#), None);
+}
+
+#[test]
+fn test_committee_meeting_details() {
+    let response: CommitteeMeetingDetailsResponse =
+        serde_json::from_str(include_str!("fixtures/committee_meeting.json"))
+            .expect("Failed to parse committee meeting");
+    let meeting = &response.committee_meeting;
+
+    assert_eq!(meeting.event_id, Some(115538));
+    assert_eq!(meeting.date.as_deref(), Some("2023-06-14T14:00:00Z"));
+    assert_eq!(meeting.chamber.as_deref(), Some("House"));
+    assert_eq!(meeting.meeting_type.as_deref(), Some("Hearing"));
+    assert_eq!(
+        meeting.title.as_deref(),
+        Some("Legislative hearing on H.R. 1282")
+    );
+    let location = meeting.location.as_ref().expect("missing location");
+    assert_eq!(location.room.as_deref(), Some("1324"));
+
+    let related = meeting
+        .related_items
+        .as_ref()
+        .expect("missing related items");
+    let bills = related.bills.as_ref().expect("missing bills");
+    assert_eq!(bills[0].bill_type.as_deref(), Some("HR"));
+    assert_eq!(bills[0].number, Some(1282));
+    assert!(related.nominations.as_ref().is_some_and(Vec::is_empty));
+    assert_eq!(meeting.witnesses.as_ref().map(Vec::len), Some(1));
+}
+
+#[test]
+fn test_congress_list() {
+    let response: CongressesResponse =
+        serde_json::from_str(include_str!("fixtures/congresses.json"))
+            .expect("Failed to parse congresses");
+
+    let numbers: Vec<_> = response
+        .congresses
+        .iter()
+        .map(|congress| congress.congress_number())
+        .collect();
+    assert_eq!(numbers, vec![Some(118), Some(117)]);
+    assert_eq!(response.congresses[0].number, None);
+    assert_eq!(response.congresses[0].start_year.as_deref(), Some("2023"));
+    assert_eq!(response.congresses[0].end_year.as_deref(), Some("2024"));
+    assert_eq!(
+        response.congresses[1].name.as_deref(),
+        Some("117th Congress")
+    );
+    assert_eq!(response.next_offset(), Some(2));
+}
+
+#[test]
+fn test_congress_sessions_ongoing() {
+    let response: CongressDetailsResponse =
+        serde_json::from_str(include_str!("fixtures/congress_details.json"))
+            .expect("Failed to parse congress");
+    let sessions = response.congress.sessions.expect("missing sessions");
+
+    assert!(!sessions[0].is_ongoing());
+    assert!(sessions[1].is_ongoing());
+}
+
+#[test]
+fn test_treaty_parts() {
+    let response: TreatiesResponse = serde_json::from_str(include_str!("fixtures/treaties.json"))
+        .expect("Failed to parse treaties");
+
+    let parts = response.treaties[0].parts.as_ref().expect("missing parts");
+    assert!(parts.is_partitioned());
+    let suffixes = parts.suffixes();
+    assert_eq!(
+        suffixes,
+        vec![
+            TreatySuffix::new("A").unwrap(),
+            TreatySuffix::new("B").unwrap()
+        ]
+    );
+
+    let parts = response.treaties[1].parts.as_ref().expect("missing parts");
+    assert!(!parts.is_partitioned());
+    assert!(parts.suffixes().is_empty());
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_congress_session_dates() {
+    use chrono::NaiveDate;
+
+    let response: CongressDetailsResponse =
+        serde_json::from_str(include_str!("fixtures/congress_details.json"))
+            .expect("Failed to parse congress");
+    let sessions = response.congress.sessions.expect("missing sessions");
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d);
+
+    assert_eq!(sessions[0].start(), date(2023, 1, 3));
+    assert_eq!(sessions[0].end(), date(2024, 1, 3));
+    let closed = sessions[0].end().unwrap() - sessions[0].start().unwrap();
+    assert_eq!(closed.num_days(), 365);
+    assert_eq!(sessions[1].start(), date(2024, 1, 3));
+    assert_eq!(sessions[1].end(), None);
+}
+
+#[test]
+fn test_committee_responses_keep_unexpected_fields() {
+    let json = r#"{
+        "committees": [{"name": "Committee on Agriculture", "systemCode": "hsag00"}],
+        "pagination": {"count": 1},
+        "request": {"format": "json"},
+        "newTopLevelField": {"added": "later"}
+    }"#;
+    let response: CommitteesResponse =
+        serde_json::from_str(json).expect("Failed to parse committees");
+
+    assert_eq!(response.committees.len(), 1);
+    let unknown = response.unknown.expect("missing unknown fields");
+    assert_eq!(unknown["newTopLevelField"]["added"], "later");
+
+    let json = r#"{"committee": {"systemCode": "hsag00"}, "request": {"format": "json"}}"#;
+    let response: CommitteeDetailsResponse =
+        serde_json::from_str(json).expect("Failed to parse committee");
+    assert!(response
+        .unknown
+        .is_some_and(|unknown| unknown.get("request").is_some()));
+}
+
+#[cfg(feature = "streaming")]
+#[test]
+fn test_stream_items_matches_buffered_parse() {
+    use cdg_api::response_models::LawSummary;
+    use cdg_api::streaming::stream_items;
+
+    let body = include_str!("fixtures/laws.json");
+    let buffered: LawsResponse = serde_json::from_str(body).expect("Failed to parse laws");
+
+    let mut numbers = Vec::new();
+    let count = stream_items(body.as_bytes(), |law: LawSummary| numbers.push(law.number))
+        .expect("Failed to stream laws");
+
+    assert_eq!(count, buffered.bills.len());
+    let expected: Vec<_> = buffered.bills.into_iter().map(|law| law.number).collect();
+    assert_eq!(numbers, expected);
+}
+
+#[cfg(feature = "streaming")]
+#[test]
+fn test_stream_items_shapes() {
+    use cdg_api::response_models::BillSummary;
+    use cdg_api::streaming::stream_items;
+
+    let stream = |body: &str| {
+        let mut numbers = Vec::new();
+        stream_items(body.as_bytes(), |bill: BillSummary| {
+            numbers.push(bill.number)
+        })
+        .map(|count| (count, numbers))
+    };
+
+    let (count, numbers) = stream(r#"[{"number": "1"}, {"number": "2"}]"#).unwrap();
+    assert_eq!(count, 2);
+    assert_eq!(numbers, vec![Some("1".to_string()), Some("2".to_string())]);
+
+    // Fields before the items, and later arrays, are skipped.
+    let body = r#"{
+        "request": {"format": "json", "flags": [1, 2.5, true, null, "x"]},
+        "bills": [{"number": "3"}],
+        "other": [{"number": "4"}]
+    }"#;
+    let (count, numbers) = stream(body).unwrap();
+    assert_eq!(count, 1);
+    assert_eq!(numbers, vec![Some("3".to_string())]);
+
+    let (count, _) = stream(r#"{"bill": {"number": "5"}}"#).unwrap();
+    assert_eq!(count, 0);
+
+    assert!(stream(r#"{"bills": [{"number": "6"}, {"number": []}]}"#).is_err());
+    assert!(stream(r#""not a list""#).is_err());
+}